@@ -1,28 +1,85 @@
+import asyncio
+import itertools
 import logging
+import math
 import os
+import time
 from decimal import Decimal
 from functools import partial
-from typing import AsyncIterator, Optional, Type, TypedDict, TypeVar
+from random import Random
+from typing import (
+    Any,
+    AsyncIterator,
+    Literal,
+    Optional,
+    Type,
+    TypedDict,
+    TypeVar,
+    cast,
+    get_args,
+    get_type_hints,
+)
 
 import aiohttp_cors
+import brotli
+import msgpack
 from aiohttp import web
 
 from juno import (
+    Advice,
     Asset,
     Candle,
+    CandleMeta,
     CandleType,
     ExchangeInfo,
+    Fees,
+    Filters,
     Interval,
+    Interval_,
+    Symbol_,
     Timestamp,
+    Timestamp_,
     json,
     serialization,
+    stop_loss,
+    strategies,
+    take_profit,
     yaml,
 )
-from juno.components import Chandler, Informant, Prices, Trades
+from juno.candle_csv import CandleCsvFormat, CandleRowError, parse_candles_csv
+from juno.components import (
+    CandleStoreReport,
+    CandleSyncEntry,
+    CandleSyncer,
+    CandleSyncStatus,
+    Chandler,
+    Informant,
+    Prices,
+    Trades,
+)
 from juno.components.prices import InsufficientPrices
+from juno.config import get_module_type_and_kwargs, get_module_type_constructor
 from juno.exchanges import Binance, Exchange
+from juno.indicators import registry as indicator_registry
 from juno.logging import create_handlers
-from juno.storages import SQLite
+from juno.metrics import Registry
+from juno.statistics import (
+    CoreStatistics,
+    CorrelationMethod,
+    EvaluationAggregation,
+    ExtendedStatistics,
+    aggregate,
+    block_shuffle,
+    correlation_matrix,
+    is_degenerate,
+    p_value,
+    period_returns,
+)
+from juno.storages import SQLite, Storage
+from juno.traders import AdviceTraceEntry, Basic, BasicConfig, BasicState, StrategySnapshot
+from juno.trading import CloseReason, Position, TradingSummary
+
+_log = logging.getLogger(__name__)
 
 T = TypeVar("T")
 
@@ -35,20 +92,64 @@ async def juno(app: web.Application) -> AsyncIterator[None]:
     exchanges: list[Exchange] = [
         binance,
     ]
-    storage = SQLite()
+    sqlite = SQLite()
+    storage: Storage = sqlite
+    if redis_url := os.environ.get("JUNO__REDIS__URL"):
+        from juno.storages.redis import Redis
+
+        storage = Redis(
+            storage=storage,
+            url=redis_url,
+            time_series_ttl=_parse_optional_interval(
+                os.environ.get("JUNO__REDIS__TIME_SERIES_TTL")
+            ),
+            item_ttl=_parse_optional_interval(os.environ.get("JUNO__REDIS__ITEM_TTL")),
+        )
     trades = Trades(storage=storage, exchanges=exchanges)
     chandler = Chandler(storage=storage, exchanges=exchanges, trades=trades)
     informant = Informant(storage=storage, exchanges=exchanges)
     prices = Prices(informant=informant, chandler=chandler)
-    async with binance, storage, trades, chandler, informant, prices:
+    candle_syncer = CandleSyncer(
+        chandler=chandler,
+        entries=_parse_candle_sync_entries(os.environ.get("JUNO__CANDLE_SYNC__ENTRIES", "")),
+        period=Interval_.parse(os.environ.get("JUNO__CANDLE_SYNC__PERIOD", "1h")),
+    )
+    basic = Basic(chandler=chandler, informant=informant)
+    async with binance, storage, trades, chandler, informant, prices, candle_syncer:
+        # Other shards are migrated on first use.
+        await sqlite.migrate(_PRESETS_SHARD)
+        app["storage"] = storage
+        app["presets_lock"] = asyncio.Lock()
+        app["basic"] = basic
         app["chandler"] = chandler
         app["trades"] = trades
         app["informant"] = informant
         app["prices"] = prices
         app["binance"] = binance
+        app["candle_syncer"] = candle_syncer
         yield
 
 
+# Entries are in the format of `exchange:symbol:interval`, separated by commas.
+# For example, `binance:eth-btc:1d,binance:btc-usdt:1h`.
+def _parse_candle_sync_entries(value: str) -> list[CandleSyncEntry]:
+    result = []
+    for entry in filter(None, (e.strip() for e in value.split(","))):
+        exchange, symbol, interval = entry.split(":")
+        result.append(
+            CandleSyncEntry(
+                exchange=exchange,
+                symbol=Symbol_.normalize(symbol),
+                interval=Interval_.parse(interval),
+            )
+        )
+    return result
+
+
+def _parse_optional_interval(value: Optional[str]) -> Optional[Interval]:
+    return None if value is None else Interval_.parse(value)
+
+
 async def body(request: web.Request, type_: Type[T]) -> T:
     content_type = request.headers.get("Content-Type")
     if content_type is None or content_type == "*/*":
@@ -81,12 +182,20 @@ def response(request: web.Request, result: T, type_: Type[T]) -> web.Response:
     accept = request.headers.get("Accept")
     if accept is None or accept == "*/*":
         accept = "application/json"
-    elif accept not in {"application/json", "application/yaml"}:
+    elif accept not in {"application/json", "application/yaml", "application/msgpack"}:
         raise_bad_request_response(f"Unsupported Accept header: {accept}")
 
     juno_serialize = (
         serialization.config.serialize if juno_accept == "config" else serialization.raw.serialize
     )
+    if accept == "application/msgpack":
+        # Compact binary format for large results. Decimals are packed as strings to keep their
+        # precision.
+        return web.Response(
+            body=msgpack.packb(juno_serialize(result, type_), default=str),
+            status=200,
+            content_type=accept,
+        )
     # We indent the response for easier debugging. Note that this is inefficient though.
     serialize = (
         partial(yaml.dump, indent=4)
@@ -100,6 +209,81 @@ def response(request: web.Request, result: T, type_: Type[T]) -> web.Response:
     )
 
 
+# Metrics.
+
+_START_TIME = time.monotonic()
+
+metrics = Registry()
+_uptime = metrics.gauge("juno_uptime_seconds", "Process uptime.")
+_requests = metrics.counter(
+    "juno_http_requests_total", "Handled HTTP requests by route, method and status."
+)
+_requests_in_progress = metrics.gauge(
+    "juno_http_requests_in_progress", "HTTP requests currently being processed by route."
+)
+_request_duration = metrics.histogram(
+    "juno_http_request_duration_seconds", "HTTP request latency by route."
+)
+_cache_hits = metrics.gauge("juno_storage_cache_hits", "Items read from the shared cache.")
+_cache_misses = metrics.gauge(
+    "juno_storage_cache_misses", "Items read from the backing storage due to a cache miss."
+)
+_candle_sync_errors = metrics.gauge(
+    "juno_candle_sync_errors", "Candle sync entries whose last sync attempt failed."
+)
+_degenerate_evaluations = metrics.counter(
+    "juno_degenerate_evaluations_total", "Evaluated symbols whose metric was NaN or infinite."
+)
+
+
+# Records request counts and latencies. Routes are labeled by their path template rather than the
+# actual path to keep the number of label values bounded.
+@web.middleware
+async def instrumentation(request: web.Request, handler: Any) -> web.StreamResponse:
+    resource = request.match_info.route.resource
+    route = resource.canonical if resource else "unmatched"
+    _requests_in_progress.inc(route=route)
+    start = time.monotonic()
+    status = 500
+    try:
+        resp = await handler(request)
+        status = resp.status
+        return resp
+    except web.HTTPException as exc:
+        status = exc.status
+        raise
+    finally:
+        _requests_in_progress.dec(route=route)
+        _request_duration.observe(time.monotonic() - start, route=route)
+        _requests.inc(route=route, method=request.method, status=str(status))
+
+
+# Responses smaller than this are not worth compressing.
+_MIN_COMPRESSION_SIZE = 1024
+
+
+# Compresses response bodies based on the Accept-Encoding header. Brotli is preferred when accepted
+# as it compresses repetitive JSON best. Otherwise falls back to gzip or deflate.
+@web.middleware
+async def compression(request: web.Request, handler: Any) -> web.StreamResponse:
+    resp = await handler(request)
+    if (
+        not isinstance(resp, web.Response)
+        or not isinstance(resp.body, bytes)
+        or len(resp.body) < _MIN_COMPRESSION_SIZE
+    ):
+        return resp
+
+    accept_encoding = request.headers.get("Accept-Encoding", "")
+    if "br" in (e.split(";")[0].strip() for e in accept_encoding.split(",")):
+        resp.body = brotli.compress(resp.body)
+        resp.headers["Content-Encoding"] = "br"
+        resp.headers["Vary"] = "Accept-Encoding"
+    else:
+        resp.enable_compression()
+    return resp
+
+
 def raise_bad_request_response(message: str) -> None:
     raise web.HTTPBadRequest(
         content_type="application/json",
@@ -112,6 +296,18 @@ def raise_bad_request_response(message: str) -> None:
     )
 
 
+def raise_service_unavailable_response(message: str) -> None:
+    raise web.HTTPServiceUnavailable(
+        content_type="application/json",
+        body=json.dumps(
+            {
+                "message": message,
+            },
+            indent=4,
+        ),
+    )
+
+
 # Routing.
 
 routes = web.RouteTableDef()
@@ -122,6 +318,59 @@ async def hello(request: web.Request) -> web.Response:
     return web.Response(text="Hello, world")
 
 
+class HealthResponse(TypedDict):
+    status: str
+    uptime: int  # Seconds.
+    storage_version: Optional[str]
+
+
+# Liveness. The process is up and serving requests.
+@routes.get("/health")
+async def health(request: web.Request) -> web.Response:
+    return response(request, _health_response(request), HealthResponse)
+
+
+# Readiness. Components are initialized and the candle store is reachable.
+@routes.get("/ready")
+async def ready(request: web.Request) -> web.Response:
+    storage: Optional[Storage] = request.app.get("storage")
+    if storage is None:
+        raise_service_unavailable_response("Not initialized")
+    assert storage
+    try:
+        await storage.get(_PRESETS_SHARD, _PRESETS_KEY, dict[str, Preset])
+    except Exception as exc:
+        _log.warning(f"storage not reachable: {exc}")
+        raise_service_unavailable_response(f"Storage not reachable: {exc}")
+
+    return response(request, _health_response(request), HealthResponse)
+
+
+def _health_response(request: web.Request) -> HealthResponse:
+    storage: Optional[Storage] = request.app.get("storage")
+    return HealthResponse(
+        status="ok",
+        uptime=int(time.monotonic() - _START_TIME),
+        storage_version=None if storage is None else storage.version,
+    )
+
+
+@routes.get("/metrics")
+async def metrics_(request: web.Request) -> web.Response:
+    _uptime.set(time.monotonic() - _START_TIME)
+    storage = request.app.get("storage")
+    if storage is not None and hasattr(storage, "hits"):
+        _cache_hits.set(storage.hits)
+        _cache_misses.set(storage.misses)
+    candle_syncer: Optional[CandleSyncer] = request.app.get("candle_syncer")
+    if candle_syncer is not None:
+        _candle_sync_errors.set(
+            sum(1 for s in candle_syncer.list_statuses() if s.error is not None)
+        )
+
+    return web.Response(text=metrics.render(), content_type="text/plain")
+
+
 class ExchangeRequest(TypedDict):
     exchange: str
 
@@ -139,6 +388,48 @@ async def exchange_info(request: web.Request) -> web.Response:
     return response(request, result, ExchangeInfo)
 
 
+def _get_exchange_name(request: web.Request) -> str:
+    informant: Informant = request.app["informant"]
+    exchange = request.match_info["exchange"]
+    if exchange not in informant.list_exchanges():
+        raise web.HTTPNotFound()
+    return exchange
+
+
+@routes.get("/exchange_info/{exchange}")
+async def get_stored_exchange_info(request: web.Request) -> web.Response:
+    exchange = _get_exchange_name(request)
+
+    informant: Informant = request.app["informant"]
+
+    result = informant.get_exchange_info(exchange)
+
+    return response(request, result, ExchangeInfo)
+
+
+@routes.put("/exchange_info/{exchange}")
+async def put_stored_exchange_info(request: web.Request) -> web.Response:
+    exchange = _get_exchange_name(request)
+    payload = await body(request, ExchangeInfo)
+
+    informant: Informant = request.app["informant"]
+
+    await informant.set_exchange_info(exchange, payload)
+
+    return response(request, payload, ExchangeInfo)
+
+
+@routes.post("/exchange_info/{exchange}/sync")
+async def sync_stored_exchange_info(request: web.Request) -> web.Response:
+    exchange = _get_exchange_name(request)
+
+    informant: Informant = request.app["informant"]
+
+    result = await informant.sync_exchange_info(exchange)
+
+    return response(request, result, ExchangeInfo)
+
+
 class CandlesRequest(TypedDict):
     exchange: str
     symbol: str
@@ -148,9 +439,17 @@ class CandlesRequest(TypedDict):
     type_: CandleType
 
 
+def _normalize_candles_request(payload: CandlesRequest) -> None:
+    try:
+        payload["symbol"] = Symbol_.normalize(payload["symbol"])
+    except ValueError as exc:
+        raise_bad_request_response(str(exc))
+
+
 @routes.post("/candles")
 async def candles(request: web.Request) -> web.Response:
     payload = await body(request, CandlesRequest)
+    _normalize_candles_request(payload)
 
     chandler: Chandler = request.app["chandler"]
 
@@ -159,9 +458,73 @@ async def candles(request: web.Request) -> web.Response:
     return response(request, result, list[Candle])
 
 
+# Charting variant of `/candles`. Candles are returned in pages of at most `limit` candles.
+# `next_cursor` is the start of the next page, or None when the range is exhausted. Pass it back
+# as `cursor` to continue. When `points` is given, the page is downsampled to at most that many
+# candles by merging consecutive candles into buckets.
+class CandlesChartRequest(CandlesRequest, total=False):
+    limit: int
+    cursor: Timestamp
+    points: int
+
+
+class CandlesChartResponse(TypedDict):
+    candles: list[Candle]
+    next_cursor: Optional[Timestamp]
+
+
+@routes.post("/candles/chart")
+async def candles_chart(request: web.Request) -> web.Response:
+    payload = await body(request, CandlesChartRequest)
+    _normalize_candles_request(payload)
+
+    limit = payload.get("limit")
+    points = payload.get("points")
+    if limit is not None and limit < 1:
+        raise_bad_request_response(f"Invalid limit ({limit})")
+    if points is not None and points < 1:
+        raise_bad_request_response(f"Invalid points ({points})")
+
+    interval = payload["interval"]
+    start = Timestamp_.floor(max(payload["start"], payload.get("cursor", 0)), interval)
+    end = payload["end"]
+    next_cursor: Optional[Timestamp] = None
+    if limit is not None:
+        # Step interval by interval since calendar intervals, such as months, vary in length.
+        limit_end = start
+        for _ in range(limit):
+            if limit_end >= end:
+                break
+            limit_end = Timestamp_.next(limit_end, interval)
+        if limit_end < end:
+            end = limit_end
+            next_cursor = end
+
+    chandler: Chandler = request.app["chandler"]
+
+    result = await chandler.list_candles(
+        exchange=payload["exchange"],
+        symbol=payload["symbol"],
+        interval=interval,
+        start=start,
+        end=end,
+        type_=payload["type_"],
+    )
+    if points is not None and len(result) > points:
+        bucket_size = math.ceil(len(result) / points)
+        result = [Candle.merge(b) for b in itertools.batched(result, bucket_size)]
+
+    return response(
+        request,
+        CandlesChartResponse(candles=result, next_cursor=next_cursor),
+        CandlesChartResponse,
+    )
+
+
 @routes.post("/candles_fill_missing_with_none")
 async def candles_fill_missing_with_none(request: web.Request) -> web.Response:
     payload = await body(request, CandlesRequest)
+    _normalize_candles_request(payload)
 
     chandler: Chandler = request.app["chandler"]
 
@@ -170,6 +533,72 @@ async def candles_fill_missing_with_none(request: web.Request) -> web.Response:
     return response(request, result, list[Optional[Candle]])
 
 
+class _CandlesImportRequestBase(TypedDict):
+    exchange: str
+    symbol: str
+    interval: Interval
+
+
+# Either `candles` or a third-party `csv` export in the given `format` must be provided. With
+# `dry_run`, nothing is stored; the response reports what would be stored and which CSV rows were
+# rejected.
+class CandlesImportRequest(_CandlesImportRequestBase, total=False):
+    candles: list[Candle]
+    csv: str
+    format: CandleCsvFormat
+    repair: bool
+    dry_run: bool
+
+
+class CandlesImportResponse(TypedDict):
+    report: CandleStoreReport
+    errors: list[CandleRowError]
+
+
+@routes.post("/candles/import")
+async def candles_import(request: web.Request) -> web.Response:
+    payload = await body(request, CandlesImportRequest)
+
+    if ("candles" in payload) == ("csv" in payload):
+        raise_bad_request_response("Either candles or csv must be provided")
+    if "csv" in payload and "format" not in payload:
+        raise_bad_request_response("CSV format must be provided")
+
+    chandler: Chandler = request.app["chandler"]
+
+    try:
+        candles, errors = (
+            parse_candles_csv(payload["csv"], payload["format"], payload["interval"])
+            if "csv" in payload
+            else (payload["candles"], [])
+        )
+        result = await chandler.store_candles(
+            exchange=payload["exchange"],
+            symbol=Symbol_.normalize(payload["symbol"]),
+            interval=payload["interval"],
+            candles=candles,
+            repair=payload.get("repair", False),
+            dry_run=payload.get("dry_run", False),
+        )
+    except ValueError as exc:
+        raise_bad_request_response(str(exc))
+
+    return response(
+        request,
+        CandlesImportResponse(report=result, errors=errors),
+        CandlesImportResponse,
+    )
+
+
+@routes.get("/candles/sync/status")
+async def candles_sync_status(request: web.Request) -> web.Response:
+    candle_syncer: CandleSyncer = request.app["candle_syncer"]
+
+    result = candle_syncer.list_statuses()
+
+    return response(request, result, list[CandleSyncStatus])
+
+
 @routes.post("/candle_intervals")
 async def candle_intervals(request: web.Request) -> web.Response:
     payload = await body(request, ExchangeRequest)
@@ -181,6 +610,44 @@ async def candle_intervals(request: web.Request) -> web.Response:
     return response(request, result, list[Interval])
 
 
+class IndicatorParameterSchema(TypedDict):
+    name: str
+    type: str
+    required: bool
+    default: Optional[str]
+
+
+class IndicatorSchema(TypedDict):
+    name: str
+    parameters: list[IndicatorParameterSchema]
+    inputs: list[str]
+    outputs: int
+    ma: bool
+
+
+@routes.get("/indicators")
+async def list_indicators(request: web.Request) -> web.Response:
+    result = [
+        IndicatorSchema(
+            name=info.name,
+            parameters=[
+                IndicatorParameterSchema(
+                    name=p.name,
+                    type=getattr(p.type_, "__name__", str(p.type_)),
+                    required=p.required,
+                    default=None if p.required or p.default is None else str(p.default),
+                )
+                for p in info.parameters
+            ],
+            inputs=info.inputs,
+            outputs=info.outputs,
+            ma=info.ma,
+        )
+        for info in indicator_registry.all_().values()
+    ]
+    return response(request, result, list[IndicatorSchema])
+
+
 class PricesRequest(TypedDict):
     exchange: str
     assets: list[Asset]
@@ -204,6 +671,1062 @@ async def prices(request: web.Request) -> web.Response:
     return response(request, result, dict[Asset, list[Decimal]])
 
 
+# Named trading parameters persisted in storage. Referenced from backtest variants by name.
+class Preset(TypedDict):
+    strategy: dict[str, Any]
+    stop_loss: Optional[dict[str, Any]]
+    take_profit: Optional[dict[str, Any]]
+
+
+class PresetRequest(Preset):
+    name: str
+
+
+_PRESETS_SHARD = "api"
+_PRESETS_KEY = "presets"
+
+
+async def _list_presets(request: web.Request) -> dict[str, Preset]:
+    storage: Storage = request.app["storage"]
+    return await storage.get(_PRESETS_SHARD, _PRESETS_KEY, dict[str, Preset]) or {}
+
+
+@routes.get("/presets")
+async def list_presets(request: web.Request) -> web.Response:
+    result = await _list_presets(request)
+    return response(request, result, dict[str, Preset])
+
+
+@routes.get("/presets/{name}")
+async def get_preset(request: web.Request) -> web.Response:
+    presets = await _list_presets(request)
+    preset = presets.get(request.match_info["name"])
+    if preset is None:
+        raise web.HTTPNotFound()
+    return response(request, preset, Preset)
+
+
+# Creates a new preset or replaces an existing one with the same name.
+@routes.post("/presets")
+async def put_preset(request: web.Request) -> web.Response:
+    payload = await body(request, PresetRequest)
+    if payload["name"] == "":
+        raise_bad_request_response("Preset name required")
+    preset = Preset(
+        strategy=payload["strategy"],
+        stop_loss=payload.get("stop_loss"),
+        take_profit=payload.get("take_profit"),
+    )
+
+    storage: Storage = request.app["storage"]
+    async with request.app["presets_lock"]:
+        presets = await _list_presets(request)
+        presets[payload["name"]] = preset
+        await storage.set(_PRESETS_SHARD, _PRESETS_KEY, presets)
+
+    return response(request, preset, Preset)
+
+
+@routes.delete("/presets/{name}")
+async def delete_preset(request: web.Request) -> web.Response:
+    name = request.match_info["name"]
+
+    storage: Storage = request.app["storage"]
+    async with request.app["presets_lock"]:
+        presets = await _list_presets(request)
+        preset = presets.pop(name, None)
+        if preset is None:
+            raise web.HTTPNotFound()
+        await storage.set(_PRESETS_SHARD, _PRESETS_KEY, presets)
+
+    return response(request, preset, Preset)
+
+
+class _BacktestVariantBase(TypedDict):
+    name: str
+
+
+# Trade direction. Advices in the disabled direction are ignored. For example, spot accounts can
+# only go long.
+Direction = Literal["both", "long", "short"]
+_DIRECTIONS = set(get_args(Direction))
+
+
+# Either `preset` or `strategy` is required. Parameters specified in the variant take precedence
+# over the ones in the preset.
+class BacktestVariant(_BacktestVariantBase, total=False):
+    preset: str
+    strategy: dict[str, Any]
+    stop_loss: Optional[dict[str, Any]]
+    take_profit: Optional[dict[str, Any]]
+    direction: Direction
+
+
+async def _resolve_variant(request: web.Request, variant: BacktestVariant) -> BacktestVariant:
+    preset_name = variant.get("preset")
+    if preset_name is None:
+        if variant.get("strategy") is None:
+            raise_bad_request_response(f"Variant {variant['name']} missing strategy or preset")
+        return variant
+
+    presets = await _list_presets(request)
+    preset = presets.get(preset_name)
+    if preset is None:
+        raise_bad_request_response(f"Variant {variant['name']} refers to unknown preset")
+    assert preset is not None
+    return BacktestVariant(
+        name=variant["name"],
+        strategy=variant.get("strategy", preset["strategy"]),
+        stop_loss=variant.get("stop_loss", preset["stop_loss"]),
+        take_profit=variant.get("take_profit", preset["take_profit"]),
+        direction=variant.get("direction", "both"),
+    )
+
+
+class _BacktestRangeBase(TypedDict):
+    exchange: str
+    interval: Interval
+    start: Timestamp
+    end: Timestamp
+    quote: Decimal
+
+
+# Fees and filters shadow the stored exchange info of the symbol for a single run. For example, to
+# simulate a zero-fee promotion.
+class BacktestRange(_BacktestRangeBase, total=False):
+    fees: Fees
+    filters: Filters
+
+
+class BacktestRequest(BacktestRange):
+    symbol: str
+
+
+class _BacktestCompareRequestBase(BacktestRequest):
+    variants: list[BacktestVariant]
+
+
+# Advice trace of every candle can be included for debugging why a strategy did or did not trade.
+# Strategy snapshots contain internal values of the strategy, such as indicator outputs, of every
+# candle for overlaying on charts.
+class BacktestCompareRequest(_BacktestCompareRequestBase, total=False):
+    include_advice_trace: bool
+    include_strategy_snapshots: bool
+
+
+class BacktestVariantResult(TypedDict):
+    name: str
+    statistics: CoreStatistics
+    # Return and risk figures of the equity curve, accumulated while backtesting.
+    extended_statistics: ExtendedStatistics
+    advice_trace: list[AdviceTraceEntry]  # Empty unless requested.
+    strategy_snapshots: list[StrategySnapshot]  # Empty unless requested.
+
+
+# Statistics of the second variant minus the first.
+class BacktestDelta(TypedDict):
+    first: str
+    second: str
+    profit: Decimal
+    roi: Decimal
+    max_drawdown: Decimal
+    num_positions: int
+
+
+class BacktestCompareResponse(TypedDict):
+    results: list[BacktestVariantResult]
+    deltas: list[BacktestDelta]
+
+
+@routes.post("/backtest/compare")
+async def backtest_compare(request: web.Request) -> web.Response:
+    payload = await body(request, BacktestCompareRequest)
+    if len(payload["variants"]) == 0:
+        raise_bad_request_response("At least one variant required")
+
+    symbol = Symbol_.normalize(payload["symbol"])
+    await _preload_candles(request, symbol, payload)
+
+    variants = [await _resolve_variant(request, v) for v in payload["variants"]]
+
+    results = []
+    for variant in variants:
+        summary, state = await _run_backtest(
+            request,
+            symbol,
+            payload,
+            variant,
+            trace_advice=payload.get("include_advice_trace", False),
+            snapshot_strategy=payload.get("include_strategy_snapshots", False),
+        )
+        results.append(
+            BacktestVariantResult(
+                name=variant["name"],
+                statistics=CoreStatistics.compose(summary),
+                extended_statistics=state.statistics.compose(),
+                advice_trace=state.advice_trace,
+                strategy_snapshots=state.strategy_snapshots,
+            )
+        )
+
+    deltas = [
+        BacktestDelta(
+            first=a["name"],
+            second=b["name"],
+            profit=b["statistics"].profit - a["statistics"].profit,
+            roi=b["statistics"].roi - a["statistics"].roi,
+            max_drawdown=b["statistics"].max_drawdown - a["statistics"].max_drawdown,
+            num_positions=b["statistics"].num_positions - a["statistics"].num_positions,
+        )
+        for i, a in enumerate(results)
+        for b in results[i + 1 :]
+    ]
+
+    return response(
+        request, BacktestCompareResponse(results=results, deltas=deltas), BacktestCompareResponse
+    )
+
+
+class BacktestDiffRequest(BacktestRequest):
+    first: BacktestVariant
+    second: BacktestVariant
+
+
+class BacktestDiffPosition(TypedDict):
+    type: str  # "long" or "short".
+    open_time: Timestamp
+    close_time: Timestamp
+    close_reason: CloseReason
+    profit: Decimal
+
+
+# Positions are matched by their type and open time.
+class BacktestPositionDiff(TypedDict):
+    first: Optional[BacktestDiffPosition]  # None if only present in the second backtest.
+    second: Optional[BacktestDiffPosition]  # None if only present in the first backtest.
+
+
+class BacktestDiffResponse(TypedDict):
+    first: CoreStatistics
+    second: CoreStatistics
+    # Numeric statistics of the second backtest minus the first.
+    deltas: dict[str, Decimal]
+    # Positions which are present in only one of the backtests or which differ between the two.
+    # Ordered by open time.
+    positions: list[BacktestPositionDiff]
+
+
+@routes.post("/backtest/diff")
+async def backtest_diff(request: web.Request) -> web.Response:
+    payload = await body(request, BacktestDiffRequest)
+
+    symbol = Symbol_.normalize(payload["symbol"])
+    await _preload_candles(request, symbol, payload)
+
+    first_summary, _ = await _run_backtest(
+        request, symbol, payload, await _resolve_variant(request, payload["first"])
+    )
+    second_summary, _ = await _run_backtest(
+        request, symbol, payload, await _resolve_variant(request, payload["second"])
+    )
+    first = CoreStatistics.compose(first_summary)
+    second = CoreStatistics.compose(second_summary)
+
+    deltas = {
+        name: Decimal(getattr(second, name)) - Decimal(getattr(first, name))
+        for name, type_ in get_type_hints(CoreStatistics).items()
+        if type_ in {Decimal, int}
+    }
+
+    first_positions = {
+        (p["type"], p["open_time"]): p for p in map(_diff_position, first_summary.positions)
+    }
+    second_positions = {
+        (p["type"], p["open_time"]): p for p in map(_diff_position, second_summary.positions)
+    }
+    positions = [
+        BacktestPositionDiff(first=first_positions.get(key), second=second_positions.get(key))
+        for key in sorted(
+            first_positions.keys() | second_positions.keys(), key=lambda k: (k[1], k[0])
+        )
+        if first_positions.get(key) != second_positions.get(key)
+    ]
+
+    return response(
+        request,
+        BacktestDiffResponse(first=first, second=second, deltas=deltas, positions=positions),
+        BacktestDiffResponse,
+    )
+
+
+def _diff_position(position: Position.Closed) -> BacktestDiffPosition:
+    return BacktestDiffPosition(
+        type="long" if isinstance(position, Position.Long) else "short",
+        open_time=position.open_time,
+        close_time=position.close_time,
+        close_reason=position.close_reason,
+        profit=position.profit,
+    )
+
+
+class _BacktestSignificanceRequestBase(BacktestRequest):
+    variant: BacktestVariant
+
+
+class BacktestSignificanceRequest(_BacktestSignificanceRequestBase, total=False):
+    block_size: int  # In candles.
+    iterations: int
+    seed: int
+
+
+class BacktestSignificanceResponse(TypedDict):
+    profit: Decimal
+    # Profits of the variant over candles with block-shuffled returns.
+    distribution: list[Decimal]
+    p_value: Decimal  # Probability of a profit at least as high by chance.
+
+
+# Each iteration is a full backtest.
+_MAX_ITERATIONS = 1000
+
+
+# Permutation test of whether the variant exploits structure in the price series or is merely
+# lucky. A small p-value means few shuffled series yield a similar profit.
+@routes.post("/backtest/significance")
+async def backtest_significance(request: web.Request) -> web.Response:
+    payload = await body(request, BacktestSignificanceRequest)
+    block_size = payload.get("block_size", 10)
+    iterations = payload.get("iterations", 100)
+    if block_size < 1 or iterations < 1:
+        raise_bad_request_response("Block size and iterations should be positive")
+    if iterations > _MAX_ITERATIONS:
+        raise_bad_request_response(f"Iterations cannot exceed {_MAX_ITERATIONS}")
+
+    symbol = Symbol_.normalize(payload["symbol"])
+    candles = await _preload_candles(request, symbol, payload)
+    variant = await _resolve_variant(request, payload["variant"])
+
+    recording = _ChandlerRecording(request.app["chandler"])
+    summary, _ = await _run_backtest(
+        request, symbol, payload, variant, chandler=cast(Chandler, recording)
+    )
+
+    random = Random(payload.get("seed", 0))
+    distribution: list[Decimal] = []
+    for _ in range(iterations):
+        # Let other requests through between backtests.
+        await asyncio.sleep(0)
+        try:
+            shuffled_candles = block_shuffle(candles, block_size, random)
+        except ValueError as exc:
+            raise_bad_request_response(str(exc))
+        chandler = _ChandlerReplay(
+            request.app["chandler"],
+            recording.candles,
+            symbol,
+            payload["interval"],
+            shuffled_candles,
+        )
+        shuffled_summary, _ = await _run_backtest(
+            request, symbol, payload, variant, chandler=cast(Chandler, chandler)
+        )
+        distribution.append(shuffled_summary.profit)
+
+    return response(
+        request,
+        BacktestSignificanceResponse(
+            profit=summary.profit,
+            distribution=distribution,
+            p_value=p_value(summary.profit, distribution),
+        ),
+        BacktestSignificanceResponse,
+    )
+
+
+class _BacktestRandomBaselineRequestBase(BacktestRequest):
+    variant: BacktestVariant
+
+
+class BacktestRandomBaselineRequest(_BacktestRandomBaselineRequestBase, total=False):
+    iterations: int
+    seed: int
+
+
+class BacktestRandomBaselineResponse(TypedDict):
+    profit: Decimal
+    num_positions: int
+    # Profits of random advice sequences with the same number of positions.
+    distribution: list[Decimal]
+    p_value: Decimal  # Share of random sequences with a profit at least as high.
+
+
+# Sanity check of whether the variant beats random trading with a similar turnover. Random advice
+# is backtested through the same trader with the `fixed` strategy in place of the variant's, while
+# the stop loss, take profit and direction of the variant are kept.
+@routes.post("/backtest/random-baseline")
+async def backtest_random_baseline(request: web.Request) -> web.Response:
+    payload = await body(request, BacktestRandomBaselineRequest)
+    iterations = payload.get("iterations", 100)
+    if iterations < 1:
+        raise_bad_request_response("Iterations should be positive")
+    if iterations > _MAX_ITERATIONS:
+        raise_bad_request_response(f"Iterations cannot exceed {_MAX_ITERATIONS}")
+
+    symbol = Symbol_.normalize(payload["symbol"])
+    candles = await _preload_candles(request, symbol, payload)
+    variant = await _resolve_variant(request, payload["variant"])
+
+    recording = _ChandlerRecording(request.app["chandler"])
+    summary, _ = await _run_backtest(
+        request, symbol, payload, variant, chandler=cast(Chandler, recording)
+    )
+    num_positions = len(summary.positions)
+
+    direction = variant.get("direction", "both")
+    sides = {
+        "both": [Advice.LONG, Advice.SHORT],
+        "long": [Advice.LONG],
+        "short": [Advice.SHORT],
+    }[direction]
+    random = Random(payload.get("seed", 0))
+    distribution: list[Decimal] = []
+    for _ in range(iterations):
+        # Let other requests through between backtests.
+        await asyncio.sleep(0)
+        try:
+            advices = strategies.Fixed.random_advices(len(candles), num_positions, sides, random)
+        except ValueError as exc:
+            raise_bad_request_response(str(exc))
+        random_variant: BacktestVariant = {
+            **variant,
+            "name": f"{variant['name']} random",
+            "strategy": {"type": "fixed", "advices": [a.name.lower() for a in advices]},
+        }
+        chandler = _ChandlerReplay(
+            request.app["chandler"], recording.candles, symbol, payload["interval"], candles
+        )
+        random_summary, _ = await _run_backtest(
+            request, symbol, payload, random_variant, chandler=cast(Chandler, chandler)
+        )
+        distribution.append(random_summary.profit)
+
+    return response(
+        request,
+        BacktestRandomBaselineResponse(
+            profit=summary.profit,
+            num_positions=num_positions,
+            distribution=distribution,
+            p_value=p_value(summary.profit, distribution),
+        ),
+        BacktestRandomBaselineResponse,
+    )
+
+
+class _BacktestReturnsRequestBase(BacktestRequest):
+    variant: BacktestVariant
+
+
+# `returns_interval` defaults to a day and cannot be shorter than the backtest interval.
+class BacktestReturnsRequest(_BacktestReturnsRequestBase, total=False):
+    returns_interval: Interval
+
+
+# Times are period starts. Returns are simple returns of the portfolio equity, the first one
+# relative to the starting quote.
+class BacktestReturnsResponse(TypedDict):
+    times: list[Timestamp]
+    returns: list[Decimal]
+
+
+# Returns series of the backtest for external portfolio and risk tools. With `Accept: text/csv`,
+# the series is returned as `time,return` rows with ISO 8601 times instead.
+@routes.post("/backtest/returns")
+async def backtest_returns(request: web.Request) -> web.Response:
+    payload = await body(request, BacktestReturnsRequest)
+    returns_interval = payload.get("returns_interval", Interval_.DAY)
+    if returns_interval < payload["interval"]:
+        raise_bad_request_response("Returns interval cannot be shorter than the interval")
+
+    symbol = Symbol_.normalize(payload["symbol"])
+    await _preload_candles(request, symbol, payload)
+    variant = await _resolve_variant(request, payload["variant"])
+
+    _, state = await _run_backtest(request, symbol, payload, variant, record_equity=True)
+    returns = period_returns(state.starting_quote, state.equity, returns_interval)
+
+    if request.headers.get("Accept") == "text/csv":
+        rows = [f"{Timestamp_.format(t)},{r}" for t, r in returns]
+        return web.Response(text="\n".join(["time,return"] + rows) + "\n", content_type="text/csv")
+    return response(
+        request,
+        BacktestReturnsResponse(times=[t for t, _ in returns], returns=[r for _, r in returns]),
+        BacktestReturnsResponse,
+    )
+
+
+# Grid keys are dotted paths into the base variant, for example `strategy.short_period`, or
+# `direction`.
+class BacktestBatchRequest(BacktestRequest):
+    base: BacktestVariant
+    grid: dict[str, list[Any]]
+
+
+# Columns are grid keys followed by statistics. Rows are in the order of the Cartesian product.
+class BacktestBatchResponse(TypedDict):
+    columns: list[str]
+    rows: list[list[Any]]
+
+
+_BATCH_STATISTICS = ["profit", "roi", "max_drawdown", "num_positions"]
+_MAX_BATCH_VARIANTS = 1000
+
+
+@routes.post("/backtest/batch")
+async def backtest_batch(request: web.Request) -> web.Response:
+    payload = await body(request, BacktestBatchRequest)
+
+    keys = list(payload["grid"].keys())
+    for key in keys:
+        if key == "direction":
+            continue
+        component = key.split(".")[0]
+        if component not in {"strategy", "stop_loss", "take_profit"} or "." not in key:
+            raise_bad_request_response(f"Invalid grid key: {key}")
+    num_variants = math.prod(len(v) for v in payload["grid"].values())
+    if num_variants > _MAX_BATCH_VARIANTS:
+        raise_bad_request_response(
+            f"Grid yields {num_variants} variants; cannot exceed {_MAX_BATCH_VARIANTS}"
+        )
+
+    symbol = Symbol_.normalize(payload["symbol"])
+    await _preload_candles(request, symbol, payload)
+
+    base = await _resolve_variant(request, payload["base"])
+
+    rows = []
+    for values in itertools.product(*(payload["grid"][k] for k in keys)):
+        variant = _expand_variant(base, dict(zip(keys, values)))
+        summary, _ = await _run_backtest(request, symbol, payload, variant)
+        statistics = CoreStatistics.compose(summary)
+        rows.append(list(values) + [getattr(statistics, s) for s in _BATCH_STATISTICS])
+
+    return response(
+        request,
+        BacktestBatchResponse(columns=keys + _BATCH_STATISTICS, rows=rows),
+        BacktestBatchResponse,
+    )
+
+
+def _expand_variant(base: BacktestVariant, overrides: dict[str, Any]) -> BacktestVariant:
+    result: dict[str, Any] = {k: (dict(v) if isinstance(v, dict) else v) for k, v in base.items()}
+    for key, value in overrides.items():
+        if key == "direction":
+            result[key] = value
+            continue
+        component, field = key.split(".", 1)
+        if result.get(component) is None:
+            raise_bad_request_response(f"Grid key {key} refers to a missing {component}")
+        result[component][field] = value
+    result["name"] = ",".join(f"{k}={v}" for k, v in overrides.items())
+    return result  # type: ignore
+
+
+class _EvaluateRequestBase(BacktestRange):
+    variant: BacktestVariant
+
+
+class _EvaluateUniverseBase(TypedDict):
+    top: int
+    lookback: Interval
+
+
+# Resolves symbols at request time instead of listing them: the `top` spot symbols matching
+# `patterns` (all if omitted) by mean daily quote volume over `lookback` before the start.
+class EvaluateUniverse(_EvaluateUniverseBase, total=False):
+    patterns: list[str]
+
+
+# Re-evaluates each symbol `resamples` times with candle closes perturbed by bootstrapped noise:
+# returns drawn with replacement from the symbol's own close-to-close returns, multiplied by
+# `scale`. The metric of a symbol becomes the mean minus the standard deviation across resamples,
+# penalizing variants whose profit depends on exact prices.
+class EvaluateNoise(TypedDict):
+    resamples: int
+    scale: Decimal
+    seed: int
+
+
+# The statistic named by `metric` is aggregated across symbols into a single fitness value.
+# `k` is used by worst-k mean and `penalty` (λ) by penalized mean aggregation.
+class EvaluateRequest(_EvaluateRequestBase, total=False):
+    symbols: list[str]
+    universe: EvaluateUniverse
+    metric: str
+    aggregation: EvaluationAggregation
+    k: int
+    penalty: Decimal
+    correlation: CorrelationMethod
+    noise: EvaluateNoise
+
+
+# Buy-and-hold over the same range, buying with the whole quote at the first close and paying a
+# taker fee. Helps to filter out variants which merely ride the market.
+class EvaluateBenchmark(TypedDict):
+    profit: Decimal
+    roi: Decimal
+    vs_roi: Decimal  # Variant minus benchmark.
+
+
+class EvaluateSymbolResult(TypedDict):
+    symbol: str
+    statistics: CoreStatistics
+    benchmark: EvaluateBenchmark
+    noisy_metric: Optional[Decimal]  # Mean minus std of the metric across noise resamples.
+
+
+# Fitness is the worst possible (negative infinity) if the metric of any symbol is degenerate, i.e.
+# NaN or infinite.
+class EvaluateResponse(TypedDict):
+    results: list[EvaluateSymbolResult]
+    fitness: Decimal
+    degenerate_evaluations: int  # Number of symbols with a degenerate metric.
+    # Pairwise correlation of candle close returns over the range. Helps to avoid allocating to
+    # highly correlated symbols.
+    correlations: dict[str, dict[str, float]]
+
+
+# Numeric statistics a variant can be evaluated by. Excludes the range of the backtest.
+_METRICS = frozenset(
+    [
+        "cost",
+        "gain",
+        "profit",
+        "roi",
+        "annualized_roi",
+        "mean_position_profit",
+        "mean_long_position_profit",
+        "mean_short_position_profit",
+        "mean_position_duration",
+        "mean_long_position_duration",
+        "mean_short_position_duration",
+        "max_drawdown",
+        "mean_drawdown",
+        "return_over_max_drawdown",
+        "num_positions",
+        "num_positions_in_profit",
+        "num_positions_in_loss",
+        "num_long_positions",
+        "num_long_positions_in_profit",
+        "num_long_positions_in_loss",
+        "num_short_positions",
+        "num_short_positions_in_profit",
+        "num_short_positions_in_loss",
+        "num_stop_losses",
+        "num_take_profits",
+        "fees",
+        "volume",
+        "turnover",
+        "max_consecutive_wins",
+        "max_consecutive_losses",
+        "profit_factor",
+        "payoff_ratio",
+        "win_loss_ratio",
+        "expectancy",
+        "kelly_fraction",
+        "half_kelly_fraction",
+        "risk_of_ruin",
+        "mean_mae",
+        "median_mae",
+        "max_mae",
+        "mean_winning_mae",
+        "mean_mfe",
+        "median_mfe",
+        "max_mfe",
+    ]
+)
+
+
+# Evaluates a single variant across many symbols. Useful for verifying optimization results.
+@routes.post("/evaluate")
+async def evaluate(request: web.Request) -> web.Response:
+    payload = await body(request, EvaluateRequest)
+    if ("symbols" in payload) == ("universe" in payload):
+        raise_bad_request_response("Either symbols or universe must be provided")
+    metric = payload.get("metric", "roi")
+    if metric not in _METRICS:
+        raise_bad_request_response(f"Invalid metric: {metric}")
+    try:
+        symbols = (
+            [Symbol_.normalize(s) for s in payload["symbols"]]
+            if "symbols" in payload
+            else await _resolve_universe(request, payload, payload["universe"])
+        )
+    except ValueError as exc:
+        raise_bad_request_response(str(exc))
+    if len(symbols) == 0:
+        raise_bad_request_response("At least one symbol required")
+
+    symbol_candles = await asyncio.gather(
+        *(_preload_candles(request, s, payload) for s in symbols)
+    )
+
+    noise = payload.get("noise")
+    if noise is not None and (noise["resamples"] < 2 or noise["scale"] <= 0):
+        raise_bad_request_response("Noise requires at least two resamples and a positive scale")
+
+    variant = await _resolve_variant(request, payload["variant"])
+
+    results = []
+    values: list[Decimal] = []
+    for symbol, candles in zip(symbols, symbol_candles):
+        summary, _ = await _run_backtest(request, symbol, payload, variant)
+        statistics = CoreStatistics.compose(summary)
+        noisy_metric = (
+            None
+            if noise is None
+            else await _evaluate_noisy_metric(
+                request, symbol, payload, variant, candles, metric, noise
+            )
+        )
+        results.append(
+            EvaluateSymbolResult(
+                symbol=symbol,
+                statistics=statistics,
+                benchmark=_buy_and_hold(request, symbol, payload, candles, statistics),
+                noisy_metric=noisy_metric,
+            )
+        )
+        values.append(
+            Decimal(getattr(statistics, metric)) if noisy_metric is None else noisy_metric
+        )
+    degenerate_evaluations = 0
+    for symbol, value in zip(symbols, values):
+        if is_degenerate(value):
+            _log.warning(f"degenerate {metric} {value} for {symbol} with {payload['variant']}")
+            degenerate_evaluations += 1
+    _degenerate_evaluations.inc(degenerate_evaluations)
+
+    try:
+        fitness = aggregate(
+            values,
+            aggregation=payload.get("aggregation", EvaluationAggregation.MEAN),
+            k=payload.get("k", 1),
+            penalty=payload.get("penalty", Decimal("1.0")),
+        )
+    except ValueError as exc:
+        raise_bad_request_response(str(exc))
+
+    return response(
+        request,
+        EvaluateResponse(
+            results=results,
+            fitness=fitness,
+            degenerate_evaluations=degenerate_evaluations,
+            correlations=_correlations(
+                symbols, symbol_candles, payload.get("correlation", CorrelationMethod.PEARSON)
+            ),
+        ),
+        EvaluateResponse,
+    )
+
+
+# Only candles present for all symbols are considered to keep returns aligned.
+async def _resolve_universe(
+    request: web.Request, payload: BacktestRange, universe: EvaluateUniverse
+) -> list[str]:
+    if universe["top"] < 1:
+        raise_bad_request_response(f"Invalid universe top ({universe['top']})")
+    if universe["lookback"] < Interval_.DAY:
+        raise_bad_request_response("Universe lookback must be at least a day")
+
+    informant: Informant = request.app["informant"]
+    chandler: Chandler = request.app["chandler"]
+
+    symbols = informant.list_symbols(payload["exchange"], universe.get("patterns"), spot=True)
+    result = await chandler.list_top_symbols_by_quote_volume(
+        exchange=payload["exchange"],
+        symbols=symbols,
+        count=universe["top"],
+        interval=Interval_.DAY,
+        start=payload["start"] - universe["lookback"],
+        end=payload["start"],
+    )
+    _log.info(f"resolved universe {universe} to {result}")
+    return result
+
+
+def _correlations(
+    symbols: list[str], symbol_candles: list[list[Candle]], method: CorrelationMethod
+) -> dict[str, dict[str, float]]:
+    times = set.intersection(*({c.time for c in candles} for candles in symbol_candles))
+    return correlation_matrix(
+        {
+            symbol: [c.close for c in candles if c.time in times]
+            for symbol, candles in zip(symbols, symbol_candles)
+        },
+        method,
+    )
+
+
+async def _evaluate_noisy_metric(
+    request: web.Request,
+    symbol: str,
+    payload: BacktestRange,
+    variant: BacktestVariant,
+    candles: list[Candle],
+    metric: str,
+    noise: EvaluateNoise,
+) -> Decimal:
+    returns = [b.close / a.close - 1 for a, b in zip(candles, candles[1:]) if a.close != 0]
+    if len(returns) == 0:
+        raise_bad_request_response(f"Not enough candles for {symbol} to sample noise from")
+
+    random = Random(noise["seed"])
+    values: list[Decimal] = []
+    for _ in range(noise["resamples"]):
+        chandler = _ChandlerPerturbation(request.app["chandler"], returns, noise["scale"], random)
+        summary, _ = await _run_backtest(
+            request, symbol, payload, variant, chandler=cast(Chandler, chandler)
+        )
+        values.append(Decimal(getattr(CoreStatistics.compose(summary), metric)))
+    return aggregate(values, EvaluationAggregation.PENALIZED_MEAN)
+
+
+def _buy_and_hold(
+    request: web.Request,
+    symbol: str,
+    payload: BacktestRange,
+    candles: list[Candle],
+    statistics: CoreStatistics,
+) -> EvaluateBenchmark:
+    quote = payload["quote"]
+    if len(candles) == 0:
+        return EvaluateBenchmark(profit=Decimal("0.0"), roi=Decimal("0.0"), vs_roi=statistics.roi)
+
+    informant: Informant = request.app["informant"]
+    fees = payload.get("fees") or informant.get_fees_filters(payload["exchange"], symbol)[0]
+    base = quote / candles[0].close
+    base -= base * fees.taker
+    profit = base * candles[-1].close - quote
+    roi = profit / quote
+    return EvaluateBenchmark(profit=profit, roi=roi, vs_roi=statistics.roi - roi)
+
+
+class ExportStrategyRequest(TypedDict):
+    strategy: dict[str, Any]
+
+
+class ExportStrategyResponse(TypedDict):
+    config: dict[str, Any]  # Strategy config with all parameters resolved.
+    pine: str  # Pine Script inputs for TradingView.
+
+
+# Converts a strategy config, such as one found by an optimizer, into a complete config block and
+# Pine Script inputs so that the parameters can be transferred to other platforms.
+@routes.post("/export_strategy")
+async def export_strategy(request: web.Request) -> web.Response:
+    payload = await body(request, ExportStrategyRequest)
+    try:
+        type_, kwargs = get_module_type_and_kwargs(strategies, payload["strategy"])
+        params = strategies.export_params(type_, kwargs)
+    except (ValueError, TypeError) as exc:
+        raise_bad_request_response(f"Invalid strategy: {exc}")
+
+    return response(
+        request,
+        ExportStrategyResponse(
+            config={"type": type_.__name__.lower(), **params},
+            pine=strategies.export_pine(type_, params),
+        ),
+        ExportStrategyResponse,
+    )
+
+
+# Fetches candles from the exchange into storage once so that subsequent backtests over the same
+# range only read from storage.
+async def _preload_candles(
+    request: web.Request, symbol: str, payload: BacktestRange
+) -> list[Candle]:
+    chandler: Chandler = request.app["chandler"]
+    return await chandler.list_candles(
+        exchange=payload["exchange"],
+        symbol=symbol,
+        interval=payload["interval"],
+        start=payload["start"],
+        end=payload["end"],
+    )
+
+
+async def _run_backtest(
+    request: web.Request,
+    symbol: str,
+    payload: BacktestRange,
+    variant: BacktestVariant,
+    trace_advice: bool = False,
+    snapshot_strategy: bool = False,
+    record_equity: bool = False,
+    chandler: Optional[Chandler] = None,
+) -> tuple[TradingSummary, BasicState]:
+    trader: Basic = request.app["basic"]
+    if (
+        chandler is not None
+        or payload.get("fees") is not None
+        or payload.get("filters") is not None
+    ):
+        trader = Basic(
+            chandler=request.app["chandler"] if chandler is None else chandler,
+            informant=cast(
+                Informant,
+                _InformantOverride(
+                    request.app["informant"], payload.get("fees"), payload.get("filters")
+                ),
+            ),
+        )
+
+    direction = variant.get("direction", "both")
+    if direction not in _DIRECTIONS:
+        raise_bad_request_response(f"Invalid variant {variant['name']} direction: {direction}")
+
+    try:
+        config = BasicConfig(
+            exchange=payload["exchange"],
+            symbol=symbol,
+            interval=payload["interval"],
+            start=payload["start"],
+            end=payload["end"],
+            quote=payload["quote"],
+            strategy=get_module_type_constructor(strategies, variant["strategy"]),
+            stop_loss=(
+                None
+                if variant.get("stop_loss") is None
+                else get_module_type_constructor(stop_loss, variant["stop_loss"])
+            ),
+            take_profit=(
+                None
+                if variant.get("take_profit") is None
+                else get_module_type_constructor(take_profit, variant["take_profit"])
+            ),
+            # Warm up the strategy before start so that positions can be taken from the very
+            # first requested candle.
+            adjusted_start="strategy",
+            long=direction != "short",
+            short=direction != "long",
+            trace_advice=trace_advice,
+            snapshot_strategy=snapshot_strategy,
+            record_equity=record_equity,
+        )
+    except (ValueError, TypeError) as exc:
+        raise_bad_request_response(f"Invalid variant {variant['name']}: {exc}")
+
+    state = await trader.initialize(config)
+    summary = await trader.run(state)
+    return summary, state
+
+
+# Delegates to the wrapped chandler but perturbs the closes of streamed candles. See
+# `Candle.gen_perturbed`.
+class _ChandlerPerturbation:
+    def __init__(
+        self, chandler: Chandler, returns: list[Decimal], scale: Decimal, random: Random
+    ) -> None:
+        self._chandler = chandler
+        self._returns = returns
+        self._scale = scale
+        self._random = random
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self._chandler, name)
+
+    async def stream_concurrent_candles(
+        self, *args: Any, **kwargs: Any
+    ) -> AsyncIterator[tuple[Candle, CandleMeta]]:
+        gen_candle = Candle.gen_perturbed(self._returns, self._scale, self._random)
+        async for candle, candle_meta in self._chandler.stream_concurrent_candles(
+            *args, **kwargs
+        ):
+            next(gen_candle)
+            yield gen_candle.send(candle), candle_meta
+
+
+# Delegates to the wrapped chandler but keeps the streamed candles for `_ChandlerReplay`.
+class _ChandlerRecording:
+    def __init__(self, chandler: Chandler) -> None:
+        self._chandler = chandler
+        self.candles: list[tuple[Candle, CandleMeta]] = []
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self._chandler, name)
+
+    async def stream_concurrent_candles(
+        self, *args: Any, **kwargs: Any
+    ) -> AsyncIterator[tuple[Candle, CandleMeta]]:
+        async for candle, candle_meta in self._chandler.stream_concurrent_candles(
+            *args, **kwargs
+        ):
+            self.candles.append((candle, candle_meta))
+            yield candle, candle_meta
+
+
+# Streams recorded candles from memory instead of reading them from storage or the exchange. The
+# candles of a symbol and interval are replaced with the given candles of the same time. Other
+# candles, such as strategy warm-up candles before the given ones or extra candles of other
+# symbols, are left as is.
+class _ChandlerReplay:
+    def __init__(
+        self,
+        chandler: Chandler,
+        recorded: list[tuple[Candle, CandleMeta]],
+        symbol: str,
+        interval: Interval,
+        candles: list[Candle],
+    ) -> None:
+        self._chandler = chandler
+        self._recorded = recorded
+        self._symbol = symbol
+        self._interval = interval
+        self._candles = {c.time: c for c in candles}
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self._chandler, name)
+
+    async def stream_concurrent_candles(
+        self,
+        exchange: str,
+        entries: list[CandleMeta],
+        start: Timestamp,
+        end: Timestamp = Timestamp_.MAX_TIME,
+    ) -> AsyncIterator[tuple[Candle, CandleMeta]]:
+        unique_entries = set(entries)
+        for candle, candle_meta in self._recorded:
+            if candle_meta not in unique_entries or not start <= candle.time < end:
+                continue
+            symbol, interval, _ = candle_meta
+            if symbol == self._symbol and interval == self._interval:
+                candle = self._candles.get(candle.time, candle)
+            yield candle, candle_meta
+
+
+# Delegates to the wrapped informant except for fees and filters, which are overridden for all
+# symbols if specified.
+class _InformantOverride:
+    def __init__(
+        self, informant: Informant, fees: Optional[Fees], filters: Optional[Filters]
+    ) -> None:
+        self._informant = informant
+        self._fees = fees
+        self._filters = filters
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self._informant, name)
+
+    def get_fees_filters(self, exchange: str, symbol: str) -> tuple[Fees, Filters]:
+        fees, filters = self._informant.get_fees_filters(exchange, symbol)
+        return (
+            fees if self._fees is None else self._fees,
+            filters if self._filters is None else self._filters,
+        )
+
+
 # Main.
 
 logging.basicConfig(
@@ -211,7 +1734,7 @@ logging.basicConfig(
     level=logging.getLevelName("INFO"),
 )
 
-app = web.Application()
+app = web.Application(middlewares=[instrumentation, compression])
 app.cleanup_ctx.append(juno)
 app.add_routes(routes)
 
@@ -11,6 +11,7 @@ from .common import (
     Depth,
     ExchangeInfo,
     Fees,
+    FeeTier,
     Fill,
     Order,
     OrderResult,
@@ -43,6 +44,7 @@ __all__ = [
     "ExchangeException",
     "ExchangeInfo",
     "Fees",
+    "FeeTier",
     "Fill",
     "Filters",
     "InsufficientFunds",
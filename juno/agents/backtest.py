@@ -19,7 +19,7 @@ from juno.config import get_module_type_constructor, get_type_name_and_kwargs, k
 from juno.inspect import construct
 from juno.statistics import CoreStatistics, Statistician
 from juno.storages import Memory, Storage
-from juno.traders import Trader
+from juno.traders import MultiState, Trader
 from juno.trading import TradingMode, TradingSummary
 
 from .agent import Agent, AgentStatus
@@ -123,11 +123,20 @@ class Backtest(Agent):
                 summary=summary,
                 exchange=config.fiat_exchange or config.exchange,
                 target_asset=config.fiat_asset,
+                symbols=(
+                    list(state.result.symbol_states.keys())
+                    if isinstance(state.result, MultiState)
+                    else [state.result.config.symbol]
+                ),
             )
         except InsufficientPrices as exc:
             _log.warning(f"unable to show extended statistics: {exc}")
         else:
             _log.info(json.dumps(serialization.config.serialize(stats.extended), indent=4))
+            _log.info(
+                "buy-and-hold benchmarks: "
+                f"{json.dumps(serialization.config.serialize(stats.benchmarks), indent=4)}"
+            )
 
     async def on_finally(self, config: Config, state: State) -> Any:
         summary = self.build_summary(config, state)
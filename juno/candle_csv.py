@@ -0,0 +1,102 @@
+import csv
+import io
+from dataclasses import dataclass
+from decimal import Decimal, InvalidOperation
+from enum import IntEnum
+
+from juno import Candle, Interval
+from juno.components import validate_candle
+
+
+class CandleCsvFormat(IntEnum):
+    # Header: timestamp,open,high,low,close,volume. Time in milliseconds.
+    KAIKO = 0
+    # Header: time,high,low,open,volumefrom,volumeto,close. Time in seconds. Volume is taken
+    # from `volumefrom`, i.e. it is in base asset.
+    CRYPTOCOMPARE = 1
+
+
+@dataclass(frozen=True)
+class _Schema:
+    time: str
+    time_multiplier: int  # To milliseconds.
+    open: str
+    high: str
+    low: str
+    close: str
+    volume: str
+
+
+_SCHEMAS = {
+    CandleCsvFormat.KAIKO: _Schema(
+        time="timestamp",
+        time_multiplier=1,
+        open="open",
+        high="high",
+        low="low",
+        close="close",
+        volume="volume",
+    ),
+    CandleCsvFormat.CRYPTOCOMPARE: _Schema(
+        time="time",
+        time_multiplier=1000,
+        open="open",
+        high="high",
+        low="low",
+        close="close",
+        volume="volumefrom",
+    ),
+}
+
+
+@dataclass(frozen=True)
+class CandleRowError:
+    row: int  # 1-based, excluding the header.
+    reason: str
+
+
+# Maps a third-party CSV export to candles. Columns are matched by header name so their order does
+# not matter. Rows which cannot be parsed or fail validation are reported and left out.
+def parse_candles_csv(
+    text: str, csv_format: CandleCsvFormat, interval: Interval
+) -> tuple[list[Candle], list[CandleRowError]]:
+    schema = _SCHEMAS[csv_format]
+    reader = csv.DictReader(io.StringIO(text.strip()))
+    columns = [
+        schema.time,
+        schema.open,
+        schema.high,
+        schema.low,
+        schema.close,
+        schema.volume,
+    ]
+    missing = [c for c in columns if c not in (reader.fieldnames or [])]
+    if len(missing) > 0:
+        raise ValueError(f"Missing {csv_format.name.lower()} CSV column(s): {', '.join(missing)}")
+
+    candles = []
+    errors = []
+    for i, row in enumerate(reader, start=1):
+        try:
+            candle = Candle(
+                time=int(row[schema.time]) * schema.time_multiplier,
+                open=Decimal(row[schema.open]),
+                high=Decimal(row[schema.high]),
+                low=Decimal(row[schema.low]),
+                close=Decimal(row[schema.close]),
+                volume=Decimal(row[schema.volume]),
+            )
+        except (InvalidOperation, TypeError, ValueError):
+            errors.append(CandleRowError(row=i, reason="malformed value"))
+            continue
+        if not all(
+            v.is_finite()
+            for v in [candle.open, candle.high, candle.low, candle.close, candle.volume]
+        ):
+            errors.append(CandleRowError(row=i, reason="non-finite value"))
+            continue
+        if (reason := validate_candle(candle, interval)) is not None:
+            errors.append(CandleRowError(row=i, reason=reason))
+            continue
+        candles.append(candle)
+    return candles, errors
@@ -4,8 +4,9 @@ from collections import defaultdict
 from dataclasses import dataclass, field
 from decimal import Decimal
 from enum import IntEnum
+from random import Random
 from types import ModuleType
-from typing import Generator, Literal, NamedTuple, Optional, Union
+from typing import Generator, Literal, NamedTuple, Optional, Sequence, Union
 
 from juno.filters import Filters
 from juno.math import precision_to_decimal, round_down, round_half_up, round_up
@@ -136,6 +137,67 @@ class Candle(NamedTuple):
         #     volume=current.volume,
         # )
 
+    @staticmethod
+    def interpolate(previous: Candle, next_: Candle, interval: Interval) -> list[Candle]:
+        """
+        Synthesizes candles for the gap between two candles by linearly interpolating the close
+        price. Each synthesized candle opens at the previous close and has zero volume.
+        """
+        num_missed = Timestamp_.num_intervals(previous.time, next_.time, interval) - 1
+        result = []
+        time = previous.time
+        open = previous.close
+        for i in range(1, num_missed + 1):
+            time = Timestamp_.next(time, interval)
+            close = previous.close + (next_.close - previous.close) * i / (num_missed + 1)
+            result.append(
+                Candle(
+                    time=time,
+                    open=open,
+                    high=max(open, close),
+                    low=min(open, close),
+                    close=close,
+                    volume=Decimal("0.0"),
+                )
+            )
+            open = close
+        return result
+
+    @staticmethod
+    def merge(candles: Sequence[Candle]) -> Candle:
+        """
+        Combines consecutive candles into a single candle spanning all of them.
+        """
+        if len(candles) == 0:
+            raise ValueError("Cannot merge zero candles")
+        return Candle(
+            time=candles[0].time,
+            open=candles[0].open,
+            high=max(c.high for c in candles),
+            low=min(c.low for c in candles),
+            close=candles[-1].close,
+            volume=sum((c.volume for c in candles), Decimal("0.0")),
+        )
+
+    @staticmethod
+    def gen_perturbed(
+        returns: Sequence[Decimal], scale: Decimal, random: Random
+    ) -> Generator[Candle, Candle, None]:
+        """
+        A generator that yields the sent candles with their close multiplied by one plus a return
+        drawn with replacement from `returns` and scaled by `scale`. High and low are widened to
+        contain the perturbed close. Useful for testing whether a strategy depends on exact
+        prices.
+        """
+        if len(returns) == 0:
+            raise ValueError("At least one return required")
+        while True:
+            candle = yield  # type: ignore
+            close = candle.close * (1 + scale * random.choice(returns))
+            yield candle._replace(
+                high=max(candle.high, close), low=min(candle.low, close), close=close
+            )
+
     @staticmethod
     def gen_regular() -> Generator[Candle, Candle, None]:
         """
@@ -185,16 +247,41 @@ class Depth(ModuleType):
     Any = Union[Snapshot, Update]
 
 
+@dataclass(frozen=True)
+class FeeTier:
+    volume: Decimal  # Minimum 30 day quote volume to qualify for the tier.
+    maker: Decimal
+    taker: Decimal
+
+
 @dataclass(frozen=True)
 class Fees:
     maker: Decimal = Decimal("0.0")
     taker: Decimal = Decimal("0.0")
+    # Discounted rates for higher trading volume (VIP levels), in increasing order of volume.
+    # Maker and taker above are the rates of level 0.
+    tiers: list[FeeTier] = field(default_factory=list)
 
     def __post_init__(self) -> None:
         if self.maker < 0:
             raise ValueError("Maker fee cannot be negative")
         if self.taker < 0:
             raise ValueError("Taker fee cannot be negative")
+        if any(t.maker < 0 or t.taker < 0 for t in self.tiers):
+            raise ValueError("Tier fee cannot be negative")
+        if [t.volume for t in self.tiers] != sorted(t.volume for t in self.tiers):
+            raise ValueError("Tiers must be in increasing order of volume")
+
+    def at_level(self, level: int) -> Fees:
+        if level < 0 or level > len(self.tiers):
+            raise ValueError(f"Fee level {level} out of range; max {len(self.tiers)}")
+        if level == 0:
+            return Fees(maker=self.maker, taker=self.taker)
+        tier = self.tiers[level - 1]
+        return Fees(maker=tier.maker, taker=tier.taker)
+
+    def for_volume(self, volume: Decimal) -> Fees:
+        return self.at_level(sum(1 for t in self.tiers if t.volume <= volume))
 
 
 @dataclass(frozen=True)
@@ -346,6 +433,17 @@ class Fill:
     def total_quote(fills: list[Fill]) -> Decimal:
         return sum((f.quote for f in fills), Decimal("0.0"))
 
+    @staticmethod
+    def total_quote_fee(fills: list[Fill], base_asset: Asset, quote_asset: Asset) -> Decimal:
+        """Sum of fees paid in either base or quote asset, expressed in quote asset."""
+        result = Decimal("0.0")
+        for fill in fills:
+            if fill.fee_asset == quote_asset:
+                result += fill.fee
+            elif fill.fee_asset == base_asset:
+                result += fill.fee * fill.price
+        return result
+
     @staticmethod
     def total_fee(fills: list[Fill], asset: Asset) -> Decimal:
         return sum((f.fee for f in fills if f.fee_asset == asset), Decimal("0.0"))
@@ -1,4 +1,5 @@
-from .chandler import Chandler
+from .candle_syncer import CandleSyncEntry, CandleSyncer, CandleSyncStatus
+from .chandler import CandleStoreReport, Chandler, validate_candle
 from .events import Events
 from .informant import Informant
 from .orderbook import Orderbook
@@ -7,6 +8,10 @@ from .trades import Trades
 from .user import User
 
 __all__ = [
+    "CandleStoreReport",
+    "CandleSyncEntry",
+    "CandleSyncer",
+    "CandleSyncStatus",
     "Chandler",
     "Events",
     "Informant",
@@ -14,4 +19,5 @@ __all__ = [
     "Prices",
     "Trades",
     "User",
+    "validate_candle",
 ]
@@ -0,0 +1,116 @@
+from __future__ import annotations
+
+import asyncio
+import logging
+from dataclasses import dataclass, replace
+from types import TracebackType
+from typing import Callable, Optional
+
+from juno import ExchangeException, Interval, Interval_, Symbol, Timestamp, Timestamp_
+from juno.asyncio import cancel, create_task_sigint_on_exception
+
+from .chandler import Chandler
+
+_log = logging.getLogger(__name__)
+
+
+@dataclass(frozen=True)
+class CandleSyncEntry:
+    exchange: str
+    symbol: Symbol
+    interval: Interval
+
+
+@dataclass(frozen=True)
+class CandleSyncStatus:
+    exchange: str
+    symbol: Symbol
+    interval: Interval
+    last_sync: Optional[Timestamp] = None  # Wall clock time of the last successful sync.
+    synced_until: Optional[Timestamp] = None  # End of the latest closed candle in storage.
+    error: Optional[str] = None  # Set if the last sync attempt failed.
+
+
+# Keeps historical candles of the configured entries up to date in storage. Candles are backfilled
+# from the first candle available on the exchange and then refreshed periodically, so that
+# backtests do not have to wait for the data to be fetched.
+class CandleSyncer:
+    def __init__(
+        self,
+        chandler: Chandler,
+        entries: list[CandleSyncEntry],
+        get_time_ms: Callable[[], int] = Timestamp_.now,
+        period: int = Interval_.HOUR,
+    ) -> None:
+        assert period > 0
+
+        self._chandler = chandler
+        self._entries = entries
+        self._get_time_ms = get_time_ms
+        self._period = period
+
+        self._statuses = {
+            e: CandleSyncStatus(exchange=e.exchange, symbol=e.symbol, interval=e.interval)
+            for e in entries
+        }
+        self._sync_task: Optional[asyncio.Task] = None
+
+    async def __aenter__(self) -> CandleSyncer:
+        if len(self._entries) > 0:
+            self._sync_task = create_task_sigint_on_exception(self._periodic_sync())
+        return self
+
+    async def __aexit__(
+        self,
+        exc_type: Optional[type[BaseException]],
+        exc: Optional[BaseException],
+        tb: Optional[TracebackType],
+    ) -> None:
+        await cancel(self._sync_task)
+
+    def list_statuses(self) -> list[CandleSyncStatus]:
+        return list(self._statuses.values())
+
+    async def _periodic_sync(self) -> None:
+        _log.info(
+            f"starting periodic sync of candles for {len(self._entries)} entries every "
+            f"{Interval_.format(self._period)}"
+        )
+        while True:
+            # Entries are synced sequentially to not exhaust exchange rate limits.
+            for entry in self._entries:
+                await self._sync(entry)
+            await asyncio.sleep(self._period / 1000.0)
+
+    async def _sync(self, entry: CandleSyncEntry) -> None:
+        status = self._statuses[entry]
+        try:
+            first = await self._chandler.get_first_candle(
+                exchange=entry.exchange,
+                symbol=entry.symbol,
+                interval=entry.interval,
+            )
+            now = self._get_time_ms()
+            end = Timestamp_.floor(now, entry.interval)
+            # Streaming historical candles through chandler stores them in storage. Spans which
+            # are already stored are not fetched from the exchange again.
+            async for _ in self._chandler.stream_candles(
+                exchange=entry.exchange,
+                symbol=entry.symbol,
+                interval=entry.interval,
+                start=first.time,
+                end=end,
+            ):
+                pass
+        except (ExchangeException, ValueError) as exc:
+            _log.exception(
+                f"failed to sync {entry.exchange} {entry.symbol} "
+                f"{Interval_.format(entry.interval)} candles"
+            )
+            self._statuses[entry] = replace(status, error=str(exc))
+        else:
+            _log.info(
+                f"synced {entry.exchange} {entry.symbol} {Interval_.format(entry.interval)} "
+                f"candles until {Timestamp_.format(end)}"
+            )
+            self._statuses[entry] = replace(status, last_sync=now, synced_until=end, error=None)
@@ -4,6 +4,7 @@ import logging
 import math
 import sys
 from contextlib import AsyncExitStack, aclosing
+from dataclasses import dataclass
 from decimal import Decimal
 from typing import AsyncGenerator, AsyncIterable, Callable, Iterable, Optional
 
@@ -37,6 +38,14 @@ _CANDLE_KEY = Candle.__name__.lower()
 _FIRST_CANDLE_KEY = f"first_{_CANDLE_KEY}"
 
 
+@dataclass(frozen=True)
+class CandleStoreReport:
+    stored: int = 0
+    duplicates: int = 0  # Candles with the same time as a previous candle. Last one is kept.
+    invalid: int = 0  # Misaligned time, high below low or negative volume.
+    overlapping: int = 0  # Candles within already stored spans. Skipped when repairing.
+
+
 class Chandler(AsyncContextManager):
     def __init__(
         self,
@@ -166,9 +175,11 @@ class Chandler(AsyncContextManager):
         try:
             async for candle in stream:
                 if last_candle:
-                    num_missed = (candle.time - last_candle.time) // interval - 1
+                    num_missed = (
+                        Timestamp_.num_intervals(last_candle.time, candle.time, interval) - 1
+                    )
                 else:
-                    num_missed = (candle.time - start) // interval
+                    num_missed = Timestamp_.num_intervals(start, candle.time, interval)
                 if num_missed > 0:
                     _log.info(f"filling {num_missed} candle(s) with None")
                     for _ in range(num_missed):
@@ -179,9 +190,9 @@ class Chandler(AsyncContextManager):
             await aclose(stream)
 
         if last_candle:
-            num_missed = (end - last_candle.time) // interval - 1
+            num_missed = Timestamp_.num_intervals(last_candle.time, end, interval) - 1
         else:
-            num_missed = (end - start) // interval
+            num_missed = Timestamp_.num_intervals(start, end, interval)
         if num_missed > 0:
             _log.info(f"filling {num_missed} candle(s) with None")
             for _ in range(num_missed):
@@ -229,6 +240,89 @@ class Chandler(AsyncContextManager):
             )
         )
 
+    async def store_candles(
+        self,
+        exchange: str,
+        symbol: Symbol,
+        interval: Interval,
+        candles: list[Candle],
+        repair: bool = False,
+        dry_run: bool = False,
+    ) -> CandleStoreReport:
+        """
+        Stores user-provided candles. Candles are deduplicated by time and invalid candles are
+        rejected. If `repair` is set, candles overlapping with existing spans are skipped and only
+        the missing ranges are filled. Otherwise, an overlap raises a `ValueError`. If `dry_run`
+        is set, nothing is stored and overlaps are only reported.
+        """
+        shard = Storage.key(exchange, symbol, interval)
+
+        valid: dict[Timestamp, Candle] = {}
+        duplicates = 0
+        invalid = 0
+        for candle in candles:
+            if (reason := validate_candle(candle, interval)) is not None:
+                _log.warning(f"rejecting invalid candle {candle}: {reason}")
+                invalid += 1
+                continue
+            if candle.time in valid:
+                duplicates += 1
+            valid[candle.time] = candle
+        sorted_candles = sorted(valid.values(), key=lambda c: c.time)
+
+        if len(sorted_candles) == 0:
+            return CandleStoreReport(duplicates=duplicates, invalid=invalid)
+
+        start = sorted_candles[0].time
+        end = Timestamp_.next(sorted_candles[-1].time, interval)
+        existing_spans = await list_async(
+            self._storage.stream_time_series_spans(
+                shard=shard,
+                key=_CANDLE_KEY,
+                start=start,
+                end=end,
+            )
+        )
+        missing_spans = list(generate_missing_spans(start, end, existing_spans))
+        num_missing = sum(
+            1 for c in sorted_candles if any(a <= c.time < b for a, b in missing_spans)
+        )
+        overlapping = len(sorted_candles) - num_missing
+        if dry_run:
+            return CandleStoreReport(
+                stored=num_missing,
+                duplicates=duplicates,
+                invalid=invalid,
+                overlapping=overlapping,
+            )
+        if overlapping > 0 and not repair:
+            raise ValueError(
+                f"{overlapping} {exchange} {symbol} {Interval_.format(interval)} candle(s) "
+                "overlap with existing candles"
+            )
+
+        for span_start, span_end in missing_spans:
+            items = [c for c in sorted_candles if span_start <= c.time < span_end]
+            # Gaps within the imported candles are not marked as covered so that they can still
+            # be fetched from the exchange.
+            for run in _contiguous_runs(items, interval):
+                await self._storage.store_time_series_and_span(
+                    shard=shard,
+                    key=_CANDLE_KEY,
+                    items=run,
+                    start=run[0].time,
+                    end=Timestamp_.next(run[-1].time, interval),
+                )
+
+        report = CandleStoreReport(
+            stored=num_missing,
+            duplicates=duplicates,
+            invalid=invalid,
+            overlapping=overlapping,
+        )
+        _log.info(f"stored {exchange} {symbol} {Interval_.format(interval)} candles: {report}")
+        return report
+
     async def stream_candles(
         self,
         exchange: str,
@@ -352,6 +446,7 @@ class Chandler(AsyncContextManager):
                 batch = []
                 swap_batch: list[Candle] = []
                 current = Timestamp_.floor(self._get_time_ms(), interval)
+                last_time: Optional[Timestamp] = None
 
                 try:
                     async with aclosing(
@@ -365,11 +460,16 @@ class Chandler(AsyncContextManager):
                         )
                     ) as stream:
                         async for candle in stream:
+                            # Exchanges may occasionally return the same candle twice.
+                            if last_time is not None and candle.time <= last_time:
+                                _log.warning(f"skipping duplicate exchange candle {candle}")
+                                continue
+                            last_time = candle.time
                             batch.append(candle)
                             if len(batch) == self._storage_batch_size:
                                 del swap_batch[:]
                                 batch_start = start
-                                batch_end = batch[-1].time + interval
+                                batch_end = Timestamp_.next(batch[-1].time, interval)
                                 start = batch_end
                                 swap_batch, batch = batch, swap_batch
                                 await self._storage.store_time_series_and_span(
@@ -383,7 +483,7 @@ class Chandler(AsyncContextManager):
                 except (asyncio.CancelledError, ExchangeException):
                     if len(batch) > 0:
                         batch_start = start
-                        batch_end = batch[-1].time + interval
+                        batch_end = Timestamp_.next(batch[-1].time, interval)
                         start = batch_end
                         await self._storage.store_time_series_and_span(
                             shard=shard,
@@ -522,7 +622,7 @@ class Chandler(AsyncContextManager):
         _log.info(f"constructing {exchange} {symbol} {interval} candles from trades")
 
         current = start
-        next_ = current + interval
+        next_ = Timestamp_.next(current, interval)
         open_ = Decimal("0.0")
         high = Decimal("0.0")
         low = Decimal(f"{sys.maxsize}.0")
@@ -543,7 +643,7 @@ class Chandler(AsyncContextManager):
                     volume=volume,
                 )
                 current = Timestamp_.floor(trade.time, interval)
-                next_ = current + interval
+                next_ = Timestamp_.next(current, interval)
                 open_ = Decimal("0.0")
                 high = Decimal("0.0")
                 low = Decimal(f"{sys.maxsize}.0")
@@ -724,6 +824,33 @@ class Chandler(AsyncContextManager):
         )
         return {(s, i): c for (s, i), c in zip(itertools.product(symbols, intervals), candles)}
 
+    async def list_top_symbols_by_quote_volume(
+        self,
+        exchange: str,
+        symbols: Iterable[Symbol],
+        count: int,
+        interval: Interval,
+        start: Timestamp,
+        end: Timestamp,
+    ) -> list[Symbol]:
+        """
+        Ranks symbols by their mean quote volume per interval within the range, approximated by
+        base volume times close. Missing candles count as zero volume so symbols with a partial
+        history rank lower. Ties are broken by symbol.
+        """
+        symbols = sorted(set(symbols))
+        start = Timestamp_.floor(start, interval)
+        end = Timestamp_.floor(end, interval)
+        num_intervals = max((end - start) // interval, 1)
+        candles = await asyncio.gather(
+            *(self.list_candles(exchange, s, interval, start, end) for s in symbols)
+        )
+        quote_volumes = {
+            s: sum((c.volume * c.close for c in cs), Decimal("0.0")) / num_intervals
+            for s, cs in zip(symbols, candles)
+        }
+        return sorted(symbols, key=lambda s: quote_volumes[s], reverse=True)[:count]
+
     def list_candle_intervals(
         self,
         exchange: str,
@@ -734,3 +861,25 @@ class Chandler(AsyncContextManager):
             return intervals
 
         return [i for i in intervals if i in patterns]
+
+
+# Returns the reason the candle is invalid, if any.
+def validate_candle(candle: Candle, interval: Interval) -> Optional[str]:
+    if Timestamp_.floor(candle.time, interval) != candle.time:
+        return "time not aligned to interval"
+    if candle.high < candle.low:
+        return "high below low"
+    if candle.volume < 0:
+        return "negative volume"
+    return None
+
+
+# Splits sorted candles into runs without missing candles in between.
+def _contiguous_runs(candles: list[Candle], interval: Interval) -> list[list[Candle]]:
+    runs: list[list[Candle]] = []
+    for candle in candles:
+        if len(runs) > 0 and Timestamp_.next(runs[-1][-1].time, interval) == candle.time:
+            runs[-1].append(candle)
+        else:
+            runs.append([candle])
+    return runs
@@ -100,6 +100,24 @@ class Informant:
     ) -> None:
         await cancel(self._exchange_info_sync_task, self._tickers_sync_task)
 
+    def get_exchange_info(self, exchange: str) -> ExchangeInfo:
+        return self._synced_data[exchange][_Timestamped[ExchangeInfo]].item
+
+    # Overrides stored exchange info. Note that it will be replaced by exchange info fetched from
+    # the exchange once it becomes out-of-date.
+    async def set_exchange_info(self, exchange: str, exchange_info: ExchangeInfo) -> None:
+        item = _Timestamped(time=self._get_time_ms(), item=exchange_info)
+        await self._storage.set(shard=exchange, key="exchange_info", item=item)
+        self._synced_data[exchange][_Timestamped[ExchangeInfo]] = item
+
+    async def sync_exchange_info(self, exchange: str) -> ExchangeInfo:
+        _log.info(f"updating {exchange} exchange info by fetching from exchange")
+        item = await self._fetch_from_exchange_and_cache(
+            exchange, "exchange_info", lambda e: e.get_exchange_info(), self._get_time_ms()
+        )
+        self._synced_data[exchange][_Timestamped[ExchangeInfo]] = item
+        return item.item
+
     def get_asset_info(self, exchange: str, asset: Asset) -> AssetInfo:
         exchange_info: ExchangeInfo = self._synced_data[exchange][_Timestamped[ExchangeInfo]].item
         return _get_or_default(exchange_info.assets, asset)
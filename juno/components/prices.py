@@ -1,7 +1,8 @@
 import asyncio
 import logging
+from collections import OrderedDict
 from decimal import Decimal
-from typing import Collection, Iterable, Optional
+from typing import Callable, Collection, Iterable, Optional
 
 from juno import Asset, Candle, Interval, Interval_, Symbol, Symbol_, Timestamp, Timestamp_
 from juno.asyncio import gather_dict
@@ -17,9 +18,20 @@ class InsufficientPrices(Exception):
 
 
 class Prices(AsyncContextManager):
-    def __init__(self, informant: Informant, chandler: Chandler) -> None:
+    def __init__(
+        self,
+        informant: Informant,
+        chandler: Chandler,
+        get_time_ms: Callable[[], int] = Timestamp_.now,
+        cache_size: int = 128,  # Number of price series kept. Least recently used are evicted.
+    ) -> None:
         self._informant = informant
         self._chandler = chandler
+        self._get_time_ms = get_time_ms
+        self._cache_size = cache_size
+        self._cache: OrderedDict[
+            tuple[str, Symbol, Interval, Timestamp, Timestamp], list[Decimal]
+        ] = OrderedDict()
 
     # In the returned prices, the first price is always the opening price of the first candle.
     # When matching with end of period results, don't forget to offset price index by one.
@@ -46,52 +58,62 @@ class Prices(AsyncContextManager):
 
         supported_symbols = set(self._informant.list_symbols(exchange))
 
-        # We can fetch prices directly for these symbols.
-        direct_symbols = [
-            symbol
+        # We can fetch prices directly for these symbols. Reversed symbols are supported by
+        # inverting prices. For example, `usdt` prices in `eur` can be found from `eur-usdt`.
+        direct_symbols = {
+            a: symbol
             for a in unique_assets
-            if a != target_asset and (symbol := f"{a}-{target_asset}") in supported_symbols
-        ]
-        _log.info(f"can directly map {direct_symbols}")
+            if a != target_asset
+            and (symbol := _find_symbol(a, target_asset, supported_symbols)) is not None
+        }
+        _log.info(f"can directly map {list(direct_symbols.values())}")
 
         # Validate we have enough data.
         await asyncio.gather(
             *(
                 self._validate_sufficient_data(exchange, s, interval, start, end)
-                for s in direct_symbols
+                for s in direct_symbols.values()
             ),
         )
 
         # Gather direct prices.
-        async def assign_direct(symbol: Symbol) -> None:
-            base_asset = Symbol_.base_asset(symbol)
-            assert base_asset not in result
-            result[base_asset] = await self._list_prices(exchange, symbol, interval, start, end)
+        async def assign_direct(asset: Asset, symbol: Symbol) -> None:
+            assert asset not in result
+            result[asset] = await self._list_symbol_prices(
+                exchange, asset, symbol, interval, start, end
+            )
 
-        await asyncio.gather(*(assign_direct(s) for s in direct_symbols))
+        await asyncio.gather(*(assign_direct(a, s) for a, s in direct_symbols.items()))
 
         # We need to use an intermediary asset to find these prices. Currently we only support BTC
         # for that.
         indirect_assets = [
-            a
-            for a in unique_assets
-            if a != target_asset and f"{a}-{target_asset}" not in supported_symbols
+            a for a in unique_assets if a != target_asset and a not in direct_symbols
         ]
         if len(indirect_assets) > 0:
             assert target_asset != "btc"
 
             _log.info(f"have to indirectly map {indirect_assets}")
 
-            btc_prices = await self._list_prices(
-                exchange, f"btc-{target_asset}", interval, start, end
+            btc_symbol = _find_symbol("btc", target_asset, supported_symbols)
+            if btc_symbol is None:
+                raise InsufficientPrices(
+                    f"Unable to map prices; no btc pair with {target_asset} on {exchange}"
+                )
+            btc_prices = await self._list_symbol_prices(
+                exchange, "btc", btc_symbol, interval, start, end
             )
 
             # Gather indirect prices.
             async def assign_indirect(asset: Asset) -> None:
                 assert asset not in result
-                intermediary_symbol = f"{asset}-btc"
-                intermediary_prices = await self._list_prices(
-                    exchange, intermediary_symbol, interval, start, end
+                intermediary_symbol = _find_symbol(asset, "btc", supported_symbols)
+                if intermediary_symbol is None:
+                    raise InsufficientPrices(
+                        f"Unable to map prices; no {asset} pair with btc on {exchange}"
+                    )
+                intermediary_prices = await self._list_symbol_prices(
+                    exchange, asset, intermediary_symbol, interval, start, end
                 )
                 result[asset] = [a * b for a, b in zip(intermediary_prices, btc_prices)]
 
@@ -146,7 +168,8 @@ class Prices(AsyncContextManager):
                     start=time,
                     end=time + interval,
                 )
-                if len(candles) == 0:
+                # A zero price cannot be inverted, so it is treated as missing.
+                if len(candles) == 0 or candles[0].open == 0:
                     if ignore_missing_price:
                         return Decimal("NaN")
                     else:
@@ -167,6 +190,23 @@ class Prices(AsyncContextManager):
 
         return {asset: symbol_prices.get(asset, Decimal("1.0")) for asset in assets}
 
+    # Lists prices of `asset` in the other asset of the symbol.
+    async def _list_symbol_prices(
+        self,
+        exchange: str,
+        asset: Asset,
+        symbol: Symbol,
+        interval: Interval,
+        start: Timestamp,
+        end: Timestamp,
+    ) -> list[Decimal]:
+        prices = await self._list_prices(exchange, symbol, interval, start, end)
+        if Symbol_.base_asset(symbol) == asset:
+            return prices
+        # A zero price cannot be inverted. It is marked as missing instead to keep the prices
+        # aligned with time.
+        return [Decimal("NaN") if p == 0 else Decimal("1.0") / p for p in prices]
+
     async def _list_prices(
         self,
         exchange: str,
@@ -175,6 +215,13 @@ class Prices(AsyncContextManager):
         start: Timestamp,
         end: Timestamp,
     ) -> list[Decimal]:
+        # Historical prices do not change, so we can cache them. The latest prices may still be
+        # updating though. Copies are returned so that callers cannot modify cached prices.
+        key = (exchange, symbol, interval, start, end)
+        if (cached := self._cache.get(key)) is not None:
+            self._cache.move_to_end(key)
+            return list(cached)
+
         prices: list[Decimal] = []
         last_candle: Optional[Candle] = None
         async for candle in self._chandler.stream_candles_fill_missing_with_none(
@@ -192,6 +239,11 @@ class Prices(AsyncContextManager):
             prices.append(price)
             if candle:
                 last_candle = candle
+
+        if end <= self._get_time_ms() and self._cache_size > 0:
+            self._cache[key] = list(prices)
+            if len(self._cache) > self._cache_size:
+                self._cache.popitem(last=False)
         return prices
 
     async def _validate_sufficient_data(
@@ -218,3 +270,11 @@ class Prices(AsyncContextManager):
                 f"candle at {Timestamp_.format(last.time)} but requested end at "
                 f"{Timestamp_.format(end)}"
             )
+
+
+def _find_symbol(asset: Asset, other_asset: Asset, symbols: set[Symbol]) -> Optional[Symbol]:
+    if (symbol := f"{asset}-{other_asset}") in symbols:
+        return symbol
+    if (symbol := f"{other_asset}-{asset}") in symbols:
+        return symbol
+    return None
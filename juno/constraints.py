@@ -30,6 +30,10 @@ class Choice(Constraint):
     def __init__(self, choices: list[Any]) -> None:
         self._choices = choices
 
+    @property
+    def choices(self) -> list[Any]:
+        return self._choices
+
     def validate(self, value: Any) -> bool:
         return value in self._choices
 
@@ -66,6 +70,14 @@ class Uniform(Constraint):
         self._min_int = int(min_ * self._factor)
         self._max_int = int(max_ * self._factor)
 
+    @property
+    def min(self) -> Decimal:
+        return self._min
+
+    @property
+    def max(self) -> Decimal:  # Inclusive.
+        return self._max
+
     def validate(self, value: Decimal) -> bool:
         return value >= self._min and value <= self._max
 
@@ -84,6 +96,14 @@ class Int(Constraint):
         self._min = min_
         self._max = max_
 
+    @property
+    def min(self) -> int:
+        return self._min
+
+    @property
+    def max(self) -> int:  # Exclusive.
+        return self._max
+
     def validate(self, value: int) -> bool:
         return value >= self._min and value < self._max
 
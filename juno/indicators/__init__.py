@@ -12,6 +12,7 @@ from .adxr import Adxr
 from .alma import Alma
 from .atr import Atr
 from .atr2 import Atr2
+from .awesome_oscillator import AwesomeOscillator
 from .bbands import Bbands
 from .cci import Cci
 from .cci2 import Cci2
@@ -21,22 +22,28 @@ from .darvas_box import DarvasBox
 from .dema import Dema
 from .di import DI
 from .dm import DM
+from .donchian_channel import DonchianChannel
 from .dx import DX
 from .ema import Ema
 from .ema2 import Ema2
 from .kama import Kama
+from .keltner_channel import KeltnerChannel
 from .kvo import Kvo
+from .linreg import LinReg
 from .lsma import Lsma
 from .macd import Macd
+from .mfi import Mfi
 from .mmi import Mmi
 from .momersion import Momersion
 from .obv import Obv
 from .obv2 import Obv2
+from .parabolic_sar import ParabolicSar
 from .rsi import Rsi
 from .sma import Sma
 from .smma import Smma
 from .stoch import Stoch
 from .stochrsi import StochRsi
+from .trix import Trix
 from .tsi import Tsi
 from .wma import Wma
 from .zlsma import Zlsma
@@ -49,6 +56,7 @@ __all__ = [
     "Alma",
     "Atr",
     "Atr2",
+    "AwesomeOscillator",
     "Bbands",
     "Cci",
     "Cci2",
@@ -58,23 +66,29 @@ __all__ = [
     "Dema",
     "DI",
     "DM",
+    "DonchianChannel",
     "DX",
     "Ema",
     "Ema2",
     "Kama",
+    "KeltnerChannel",
     "Kvo",
+    "LinReg",
     "Lsma",
     "Macd",
     "MA",
+    "Mfi",
     "Mmi",
     "Momersion",
     "Obv",
     "Obv2",
+    "ParabolicSar",
     "Rsi",
     "Sma",
     "Smma",
     "Stoch",
     "StochRsi",
+    "Trix",
     "Tsi",
     "Wma",
     "Zlsma",
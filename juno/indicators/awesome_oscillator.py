@@ -0,0 +1,46 @@
+from decimal import Decimal
+
+from .sma import Sma
+
+
+# Awesome Oscillator
+# Difference between a short and a long simple moving average of the median price.
+class AwesomeOscillator:
+    value: Decimal = Decimal("0.0")
+
+    _short_sma: Sma
+    _long_sma: Sma
+    _t: int = 0
+    _t1: int
+
+    def __init__(self, short_period: int = 5, long_period: int = 34) -> None:
+        if short_period < 1 or long_period < 2:
+            raise ValueError(f"Invalid period(s) ({short_period}, {long_period})")
+        if long_period <= short_period:
+            raise ValueError(
+                f"Long period ({long_period}) must be larger than short period ({short_period})"
+            )
+
+        self._short_sma = Sma(short_period)
+        self._long_sma = Sma(long_period)
+        self._t1 = long_period
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, high: Decimal, low: Decimal) -> Decimal:
+        self._t = min(self._t + 1, self._t1)
+
+        median_price = (high + low) / 2
+        self._short_sma.update(median_price)
+        self._long_sma.update(median_price)
+
+        if self._t >= self._t1:
+            self.value = self._short_sma.value - self._long_sma.value
+
+        return self.value
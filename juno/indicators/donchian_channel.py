@@ -0,0 +1,45 @@
+from collections import deque
+from decimal import Decimal
+
+from juno.math import minmax
+
+
+# Donchian Channel
+class DonchianChannel:
+    upper: Decimal = Decimal("0.0")
+    middle: Decimal = Decimal("0.0")
+    lower: Decimal = Decimal("0.0")
+
+    _highs: deque[Decimal]
+    _lows: deque[Decimal]
+    _t: int = 0
+    _t1: int
+
+    def __init__(self, period: int) -> None:
+        if period < 1:
+            raise ValueError(f"Invalid period ({period})")
+
+        self._highs = deque(maxlen=period)
+        self._lows = deque(maxlen=period)
+        self._t1 = period
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, high: Decimal, low: Decimal) -> tuple[Decimal, Decimal, Decimal]:
+        self._t = min(self._t + 1, self._t1)
+
+        self._highs.append(high)
+        self._lows.append(low)
+
+        if self._t >= self._t1:
+            _, self.upper = minmax(self._highs)
+            self.lower, _ = minmax(self._lows)
+            self.middle = (self.upper + self.lower) / 2
+
+        return self.lower, self.middle, self.upper
@@ -0,0 +1,47 @@
+from decimal import Decimal
+
+from .atr import Atr
+from .ema import Ema
+
+
+# Keltner Channel
+class KeltnerChannel:
+    upper: Decimal = Decimal("0.0")
+    middle: Decimal = Decimal("0.0")
+    lower: Decimal = Decimal("0.0")
+
+    _ema: Ema
+    _atr: Atr
+    _multiplier: Decimal
+
+    def __init__(
+        self, period: int = 20, atr_period: int = 10, multiplier: Decimal = Decimal("2.0")
+    ) -> None:
+        if multiplier < 0:
+            raise ValueError(f"Invalid multiplier ({multiplier})")
+
+        self._ema = Ema(period)
+        self._atr = Atr(atr_period)
+        self._multiplier = multiplier
+
+    @property
+    def maturity(self) -> int:
+        return max(self._ema.maturity, self._atr.maturity)
+
+    @property
+    def mature(self) -> bool:
+        return self._ema.mature and self._atr.mature
+
+    def update(
+        self, high: Decimal, low: Decimal, close: Decimal
+    ) -> tuple[Decimal, Decimal, Decimal]:
+        self._ema.update(close)
+        self._atr.update(high, low, close)
+
+        if self.mature:
+            self.middle = self._ema.value
+            band = self._multiplier * self._atr.value
+            self.upper = self.middle + band
+            self.lower = self.middle - band
+
+        return self.lower, self.middle, self.upper
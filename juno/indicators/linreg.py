@@ -0,0 +1,68 @@
+from collections import deque
+from decimal import Decimal
+
+
+# Linear Regression Channel
+# Fits a least-squares line over the last `period` prices. The middle of the channel is the line
+# value at the latest price and the bands are `deviations` standard deviations of the residuals
+# away from it. The intercept is the line value at the oldest price in the window.
+class LinReg:
+    value: Decimal = Decimal("0.0")
+    slope: Decimal = Decimal("0.0")
+    intercept: Decimal = Decimal("0.0")
+    upper: Decimal = Decimal("0.0")
+    lower: Decimal = Decimal("0.0")
+
+    _prices: deque[Decimal]
+    _deviations: Decimal
+
+    _x_sum: Decimal
+    _divisor: Decimal
+
+    _t: int = 0
+    _t1: int
+
+    def __init__(self, period: int = 20, deviations: Decimal = Decimal("2.0")) -> None:
+        if period < 2:
+            raise ValueError(f"Invalid period ({period})")
+
+        self._prices = deque(maxlen=period)
+        self._deviations = deviations
+
+        # Prices are placed at x = 0, 1, ..., period - 1.
+        self._x_sum = Decimal(period * (period - 1)) / 2
+        x2_sum = Decimal((period - 1) * period * (2 * period - 1)) / 6
+        self._divisor = period * x2_sum - self._x_sum * self._x_sum
+
+        self._t1 = period
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, price: Decimal) -> tuple[Decimal, Decimal, Decimal]:
+        self._t = min(self._t + 1, self._t1)
+
+        self._prices.append(price)
+
+        if self._t >= self._t1:
+            y_sum = sum(self._prices, Decimal("0.0"))
+            xy_sum = sum((x * y for x, y in enumerate(self._prices)), Decimal("0.0"))
+
+            self.slope = (self._t1 * xy_sum - self._x_sum * y_sum) / self._divisor
+            self.intercept = (y_sum - self.slope * self._x_sum) / self._t1
+            self.value = self.intercept + self.slope * (self._t1 - 1)
+
+            residuals2_sum = sum(
+                ((y - (self.intercept + self.slope * x)) ** 2 for x, y in enumerate(self._prices)),
+                Decimal("0.0"),
+            )
+            sd = (residuals2_sum / self._t1).sqrt()
+            self.upper = self.value + self._deviations * sd
+            self.lower = self.value - self._deviations * sd
+
+        return self.lower, self.value, self.upper
@@ -0,0 +1,52 @@
+from collections import deque
+from decimal import Decimal
+from typing import Optional
+
+
+# Money Flow Index
+class Mfi:
+    value: Decimal = Decimal("0.0")
+
+    _positive_flows: deque[Decimal]
+    _negative_flows: deque[Decimal]
+    _prev_typical_price: Optional[Decimal] = None
+    _t: int = 0
+    _t1: int
+
+    def __init__(self, period: int = 14) -> None:
+        if period < 1:
+            raise ValueError(f"Invalid period ({period})")
+
+        self._positive_flows = deque(maxlen=period)
+        self._negative_flows = deque(maxlen=period)
+        self._t1 = period + 1
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, high: Decimal, low: Decimal, close: Decimal, volume: Decimal) -> Decimal:
+        self._t = min(self._t + 1, self._t1)
+
+        typical_price = (high + low + close) / 3
+
+        if self._prev_typical_price is not None:
+            flow = typical_price * volume
+            self._positive_flows.append(
+                flow if typical_price > self._prev_typical_price else Decimal("0.0")
+            )
+            self._negative_flows.append(
+                flow if typical_price < self._prev_typical_price else Decimal("0.0")
+            )
+
+        if self._t >= self._t1:
+            positive = sum(self._positive_flows, Decimal("0.0"))
+            total = positive + sum(self._negative_flows, Decimal("0.0"))
+            self.value = Decimal("50.0") if total == 0 else 100 * positive / total
+
+        self._prev_typical_price = typical_price
+        return self.value
@@ -0,0 +1,96 @@
+from decimal import Decimal
+from typing import Optional
+
+
+# Parabolic Stop and Reverse
+# Based on the algorithm used in Tulip Indicators.
+class ParabolicSar:
+    value: Decimal = Decimal("0.0")
+    long: bool = True  # Whether the SAR is trailing an uptrend (below price).
+
+    _step: Decimal
+    _max: Decimal
+    _accel: Decimal
+    _extreme: Decimal = Decimal("0.0")
+    _prev_high: Decimal = Decimal("0.0")
+    _prev_low: Decimal = Decimal("0.0")
+    _prev_prev_high: Decimal = Decimal("0.0")
+    _prev_prev_low: Decimal = Decimal("0.0")
+    _t: int = 0
+    _t1: int = 2
+    _t2: int = 3
+
+    def __init__(
+        self,
+        step: Decimal = Decimal("0.02"),
+        max_: Decimal = Decimal("0.2"),
+        long: Optional[bool] = None,  # None means the trend is inferred from the first candles.
+    ) -> None:
+        if step <= 0:
+            raise ValueError(f"Invalid acceleration step ({step})")
+        if max_ < step:
+            raise ValueError(f"Invalid acceleration max ({max_})")
+
+        self._step = step
+        self._max = max_
+        self._accel = step
+        self._initial_long = long
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, high: Decimal, low: Decimal) -> Decimal:
+        self._t = min(self._t + 1, self._t2)
+
+        if self._t == 1:
+            if self._initial_long is not None:
+                self._start(self._initial_long, high, low)
+        else:
+            if self._t == 2 and self._initial_long is None:
+                self._start(
+                    self._prev_high + self._prev_low <= high + low, self._prev_high, self._prev_low
+                )
+            self._step_sar(high, low)
+
+        self._prev_prev_high, self._prev_prev_low = self._prev_high, self._prev_low
+        self._prev_high, self._prev_low = high, low
+        return self.value
+
+    def _start(self, long: bool, high: Decimal, low: Decimal) -> None:
+        self.long = long
+        self._extreme = high if long else low
+        self.value = low if long else high
+
+    def _step_sar(self, high: Decimal, low: Decimal) -> None:
+        sar = (self._extreme - self.value) * self._accel + self.value
+
+        if self.long:
+            if self._t > 2 and sar > self._prev_prev_low:
+                sar = self._prev_prev_low
+            if sar > self._prev_low:
+                sar = self._prev_low
+            if high > self._extreme:
+                self._accel = min(self._accel + self._step, self._max)
+                self._extreme = high
+        else:
+            if self._t > 2 and sar < self._prev_prev_high:
+                sar = self._prev_prev_high
+            if sar < self._prev_high:
+                sar = self._prev_high
+            if low < self._extreme:
+                self._accel = min(self._accel + self._step, self._max)
+                self._extreme = low
+
+        # Reverse.
+        if (self.long and low < sar) or (not self.long and high > sar):
+            self._accel = self._step
+            sar = self._extreme
+            self.long = not self.long
+            self._extreme = high if self.long else low
+
+        self.value = sar
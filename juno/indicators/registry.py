@@ -0,0 +1,102 @@
+"""
+Central lookup of indicators by name. Entries are derived from the types exported by
+`juno.indicators`, so a newly exported indicator is available to every consumer (strategy DSL,
+moving average construction, API schema) without further registration.
+"""
+
+from __future__ import annotations
+
+import inspect
+from dataclasses import dataclass
+from typing import Any, get_args, get_origin, get_type_hints
+
+from juno import indicators
+
+_NO_DEFAULT = inspect.Parameter.empty
+
+
+@dataclass(frozen=True)
+class Parameter:
+    name: str
+    type_: Any
+    default: Any = _NO_DEFAULT
+
+    @property
+    def required(self) -> bool:
+        return self.default is _NO_DEFAULT
+
+
+@dataclass(frozen=True)
+class IndicatorInfo:
+    name: str
+    type_: type[Any]
+    parameters: list[Parameter]
+    inputs: list[str]  # Names of the values passed to `update`, in order.
+    outputs: int  # Number of values returned from `update`.
+    ma: bool  # Whether usable as a moving average of a single price.
+
+    def create(self, *args: Any, **kwargs: Any) -> Any:
+        return self.type_(*args, **kwargs)
+
+
+def all_() -> dict[str, IndicatorInfo]:
+    return _REGISTRY
+
+
+def get(name: str) -> IndicatorInfo:
+    info = _REGISTRY.get(name.lower())
+    if info is None:
+        raise ValueError(f'Indicator named "{name}" not found')
+    return info
+
+
+def ma_names() -> list[str]:
+    return [name for name, info in _REGISTRY.items() if info.ma]
+
+
+def create_ma(name: str, period: int) -> Any:
+    info = get(name)
+    if not info.ma:
+        raise ValueError(f'Indicator named "{name}" is not a moving average')
+    return info.create(period)
+
+
+def _build() -> dict[str, IndicatorInfo]:
+    ma_types = set(get_args(indicators.MA))
+    result = {}
+    for type_name in indicators.__all__:
+        type_ = getattr(indicators, type_name)
+        if not inspect.isclass(type_) or not hasattr(type_, "update"):
+            continue
+        name = type_.__name__.lower()
+        result[name] = IndicatorInfo(
+            name=name,
+            type_=type_,
+            parameters=_parameters(type_.__init__),
+            inputs=[p for p in inspect.signature(type_.update).parameters.keys() if p != "self"],
+            outputs=_outputs(get_type_hints(type_.update).get("return")),
+            ma=type_ in ma_types,
+        )
+    return result
+
+
+def _parameters(init: Any) -> list[Parameter]:
+    if init is object.__init__:
+        return []
+    type_hints = get_type_hints(init)
+    return [
+        Parameter(name=p.name, type_=type_hints.get(p.name, Any), default=p.default)
+        for p in inspect.signature(init).parameters.values()
+        if p.name != "self"
+    ]
+
+
+def _outputs(return_type: Any) -> int:
+    if return_type is None or return_type is type(None):
+        return 0
+    if get_origin(return_type) is tuple:
+        return len(get_args(return_type))
+    return 1
+
+
+_REGISTRY = _build()
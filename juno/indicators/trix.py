@@ -0,0 +1,46 @@
+from decimal import Decimal
+
+from .ema import Ema
+
+
+# Triple Exponential Average
+# One period rate of change, in percent, of a triple smoothed exponential moving average.
+class Trix:
+    value: Decimal = Decimal("0.0")
+
+    _ema1: Ema
+    _ema2: Ema
+    _ema3: Ema
+    _prev_ema3: Decimal = Decimal("0.0")
+    _t: int = 0
+    _t1: int
+
+    def __init__(self, period: int = 15) -> None:
+        if period < 1:
+            raise ValueError(f"Invalid period ({period})")
+
+        self._ema1 = Ema(period)
+        self._ema2 = Ema(period)
+        self._ema3 = Ema(period)
+        self._t1 = (period - 1) * 3 + 1
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, price: Decimal) -> Decimal:
+        self._t = min(self._t + 1, self._t1)
+
+        self._ema1.update(price)
+        self._ema2.update(self._ema1.value)
+        self._ema3.update(self._ema2.value)
+
+        if self._t >= self._t1 and self._prev_ema3 != 0:
+            self.value = (self._ema3.value - self._prev_ema3) / self._prev_ema3 * 100
+
+        self._prev_ema3 = self._ema3.value
+        return self.value
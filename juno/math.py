@@ -4,6 +4,7 @@ from decimal import ROUND_DOWN, ROUND_HALF_DOWN, ROUND_HALF_UP, ROUND_UP, Decima
 from typing import Iterable, TypeVar
 
 TNum = TypeVar("TNum", int, Decimal)
+TReal = TypeVar("TReal", float, Decimal)
 
 _YEAR_MS = 31_556_952_000
 
@@ -77,6 +78,38 @@ def rpstdev(data: Iterable[Decimal]) -> Decimal:
     return statistics.pstdev(data) / mean
 
 
+def kahan_sum(values: Iterable[TReal], start: TReal) -> TReal:
+    """Compensated summation (Kahan-Babuska-Neumaier). Keeps the rounding error constant instead
+    of growing with the number of values."""
+    total = start
+    compensation = start - start
+    for value in values:
+        temp = total + value
+        if abs(total) >= abs(value):
+            compensation += (total - temp) + value
+        else:
+            compensation += (value - temp) + total
+        total = temp
+    return total + compensation
+
+
+# Running mean and population variance using Welford's algorithm.
+class Welford:
+    count: int = 0
+    mean: float = 0.0
+    _m2: float = 0.0
+
+    @property
+    def variance(self) -> float:
+        return self._m2 / self.count if self.count > 0 else 0.0
+
+    def update(self, value: float) -> None:
+        self.count += 1
+        delta = value - self.mean
+        self.mean += delta / self.count
+        self._m2 += delta * (value - self.mean)
+
+
 # Ref: https://www.investopedia.com/articles/basics/10/guide-to-calculating-roi.asp
 # TODO: Move outside math module.
 def annualized(duration: int, value: Decimal) -> Decimal:
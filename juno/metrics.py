@@ -0,0 +1,140 @@
+from __future__ import annotations
+
+import math
+from bisect import bisect_left
+from collections import defaultdict
+from typing import Optional, Sequence, TypeVar, Union
+
+# Minimal in-process metrics with rendering in the Prometheus text exposition format.
+# https://prometheus.io/docs/instrumenting/exposition_formats/
+
+Labels = tuple[tuple[str, str], ...]
+
+DEFAULT_BUCKETS = (0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0)
+
+
+def _labels(labels: dict[str, str]) -> Labels:
+    return tuple(sorted(labels.items()))
+
+
+def _escape(value: str) -> str:
+    return value.replace("\\", "\\\\").replace("\n", "\\n").replace('"', '\\"')
+
+
+def _format_labels(labels: Labels, extra: Optional[tuple[str, str]] = None) -> str:
+    items = list(labels) + ([extra] if extra else [])
+    if len(items) == 0:
+        return ""
+    return "{" + ",".join(f'{k}="{_escape(v)}"' for k, v in items) + "}"
+
+
+def _format_value(value: float) -> str:
+    if math.isinf(value):
+        return "+Inf" if value > 0 else "-Inf"
+    return repr(float(value))
+
+
+class Counter:
+    def __init__(self, name: str, help_: str) -> None:
+        self.name = name
+        self.help = help_
+        self._values: dict[Labels, float] = defaultdict(float)
+
+    def inc(self, amount: float = 1.0, **labels: str) -> None:
+        assert amount >= 0
+        self._values[_labels(labels)] += amount
+
+    def get(self, **labels: str) -> float:
+        return self._values.get(_labels(labels), 0.0)
+
+    def render(self) -> list[str]:
+        return [
+            f"{self.name}{_format_labels(k)} {_format_value(v)}" for k, v in self._values.items()
+        ]
+
+
+class Gauge:
+    def __init__(self, name: str, help_: str) -> None:
+        self.name = name
+        self.help = help_
+        self._values: dict[Labels, float] = defaultdict(float)
+
+    def set(self, value: float, **labels: str) -> None:
+        self._values[_labels(labels)] = value
+
+    def inc(self, amount: float = 1.0, **labels: str) -> None:
+        self._values[_labels(labels)] += amount
+
+    def dec(self, amount: float = 1.0, **labels: str) -> None:
+        self._values[_labels(labels)] -= amount
+
+    def get(self, **labels: str) -> float:
+        return self._values.get(_labels(labels), 0.0)
+
+    def render(self) -> list[str]:
+        return [
+            f"{self.name}{_format_labels(k)} {_format_value(v)}" for k, v in self._values.items()
+        ]
+
+
+class Histogram:
+    def __init__(self, name: str, help_: str, buckets: Sequence[float] = DEFAULT_BUCKETS) -> None:
+        assert list(buckets) == sorted(buckets)
+        self.name = name
+        self.help = help_
+        self._buckets = list(buckets) + [math.inf]
+        self._counts: dict[Labels, list[int]] = {}
+        self._sums: dict[Labels, float] = defaultdict(float)
+
+    def observe(self, value: float, **labels: str) -> None:
+        key = _labels(labels)
+        counts = self._counts.setdefault(key, [0] * len(self._buckets))
+        counts[bisect_left(self._buckets, value)] += 1
+        self._sums[key] += value
+
+    def render(self) -> list[str]:
+        lines = []
+        for key, counts in self._counts.items():
+            cumulative = 0
+            for bound, count in zip(self._buckets, counts):
+                cumulative += count
+                lines.append(
+                    f"{self.name}_bucket{_format_labels(key, ('le', _format_value(bound)))} "
+                    f"{cumulative}"
+                )
+            lines.append(f"{self.name}_sum{_format_labels(key)} {_format_value(self._sums[key])}")
+            lines.append(f"{self.name}_count{_format_labels(key)} {cumulative}")
+        return lines
+
+
+M = TypeVar("M", Counter, Gauge, Histogram)
+
+
+class Registry:
+    def __init__(self) -> None:
+        self._metrics: dict[str, Union[Counter, Gauge, Histogram]] = {}
+
+    def counter(self, name: str, help_: str) -> Counter:
+        return self._register(Counter(name, help_))
+
+    def gauge(self, name: str, help_: str) -> Gauge:
+        return self._register(Gauge(name, help_))
+
+    def histogram(
+        self, name: str, help_: str, buckets: Sequence[float] = DEFAULT_BUCKETS
+    ) -> Histogram:
+        return self._register(Histogram(name, help_, buckets))
+
+    def render(self) -> str:
+        lines = []
+        for metric in self._metrics.values():
+            lines.append(f"# HELP {metric.name} {metric.help}")
+            lines.append(f"# TYPE {metric.name} {type(metric).__name__.lower()}")
+            lines.extend(metric.render())
+        return "\n".join(lines) + "\n"
+
+    def _register(self, metric: M) -> M:
+        if metric.name in self._metrics:
+            raise ValueError(f"Metric {metric.name} already registered")
+        self._metrics[metric.name] = metric
+        return metric
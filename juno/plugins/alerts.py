@@ -0,0 +1,187 @@
+from __future__ import annotations
+
+import asyncio
+import logging
+from types import TracebackType
+from typing import Any, Optional
+
+import aiohttp
+from more_itertools import sliced
+
+from juno import json, serialization
+from juno.components import Events
+from juno.inspect import extract_public
+from juno.statistics.core import CoreStatistics
+from juno.traceback import exc_traceback
+from juno.trading import Position, TradingSummary
+
+from .plugin import Plugin
+
+_log = logging.getLogger(__name__)
+
+_TELEGRAM_API_URL = "https://api.telegram.org"
+_TELEGRAM_MAX_MESSAGE_LENGTH = 4096
+
+
+# Posts agent events as JSON to webhook URLs and optionally as messages to a Telegram chat.
+#
+# Alerts are sent in the background so that a slow or unreachable endpoint does not hold up the
+# agent. Every request is bounded by `timeout` seconds. Failures are logged without URLs because
+# both webhook URLs and the Telegram bot token are secrets.
+#
+# Example config:
+# {
+#     "alerts": {
+#         "webhook_urls": ["https://example.com/hook"],
+#         "telegram": {"token": "<bot token>", "chat_id": "<chat id>"},
+#         "max_attempts": 5,
+#         "timeout": 5
+#     }
+# }
+class Alerts(Plugin):
+    def __init__(self, events: Events, config: dict[str, Any]) -> None:
+        alerts_config = config.get(type(self).__name__.lower(), {})
+
+        webhook_urls = alerts_config.get("webhook_urls", [])
+        if not isinstance(webhook_urls, list):
+            raise ValueError(
+                f"Webhook URLs should be a list but was a {type(webhook_urls).__name__} instead"
+            )
+
+        telegram_config = alerts_config.get("telegram")
+        if telegram_config is not None and not (
+            isinstance(telegram_config.get("token"), str) and telegram_config.get("chat_id")
+        ):
+            raise ValueError("Telegram config should contain a token and a chat ID")
+
+        if len(webhook_urls) == 0 and telegram_config is None:
+            raise ValueError("Missing webhook URLs or Telegram config")
+
+        max_attempts = alerts_config.get("max_attempts", 5)
+        if not isinstance(max_attempts, int) or max_attempts < 1:
+            raise ValueError("Max attempts should be a positive integer")
+
+        timeout = alerts_config.get("timeout", 5)
+        if not isinstance(timeout, (int, float)) or timeout <= 0:
+            raise ValueError("Timeout should be a positive number of seconds")
+
+        self._events = events
+        self._webhook_urls: list[str] = webhook_urls
+        self._telegram_config: Optional[dict[str, Any]] = telegram_config
+        self._max_attempts = max_attempts
+        self._timeout = timeout
+        self._tasks: set[asyncio.Task] = set()
+
+    async def __aenter__(self) -> Alerts:
+        # Not using `juno.http.ClientSession` because it logs request URLs.
+        self._session = aiohttp.ClientSession(timeout=aiohttp.ClientTimeout(total=self._timeout))
+        await self._session.__aenter__()
+        return self
+
+    async def __aexit__(
+        self,
+        exc_type: Optional[type[BaseException]],
+        exc: Optional[BaseException],
+        tb: Optional[TracebackType],
+    ) -> None:
+        await self.flush()
+        await self._session.__aexit__(exc_type, exc, tb)
+
+    # Waits for alerts being sent in the background.
+    async def flush(self) -> None:
+        await asyncio.gather(*self._tasks)
+
+    async def activate(self, agent_name: str, agent_type: str) -> None:
+        async def send(event: str, data: Any) -> None:
+            task = asyncio.create_task(
+                self._send(
+                    {
+                        "agent": agent_name,
+                        "agent_type": agent_type,
+                        "event": event,
+                        "data": data,
+                    }
+                )
+            )
+            self._tasks.add(task)
+            task.add_done_callback(self._tasks.discard)
+
+        @self._events.on(agent_name, "positions_opened")
+        async def on_positions_opened(positions: list[Position], summary: TradingSummary) -> None:
+            await send(
+                "positions_opened",
+                [
+                    serialization.config.serialize(extract_public(p, exclude=["fills"]))
+                    for p in positions
+                ],
+            )
+
+        @self._events.on(agent_name, "positions_closed")
+        async def on_positions_closed(positions: list[Position], summary: TradingSummary) -> None:
+            await send(
+                "positions_closed",
+                {
+                    "positions": [
+                        serialization.config.serialize(
+                            extract_public(p, exclude=["open_fills", "close_fills"])
+                        )
+                        for p in positions
+                    ],
+                    "summary": serialization.config.serialize(CoreStatistics.compose(summary)),
+                },
+            )
+
+        @self._events.on(agent_name, "finished")
+        async def on_finished(summary: TradingSummary) -> None:
+            await send("finished", serialization.config.serialize(CoreStatistics.compose(summary)))
+
+        @self._events.on(agent_name, "errored")
+        async def on_errored(exc: Exception) -> None:
+            await send("errored", exc_traceback(exc))
+
+        @self._events.on(agent_name, "message")
+        async def on_message(message: str) -> None:
+            await send("message", message)
+
+        _log.info(f"activated for {agent_name} ({agent_type})")
+
+    async def _send(self, payload: dict[str, Any]) -> None:
+        for i, url in enumerate(self._webhook_urls):
+            await self._post(f"webhook {i}", url, payload)
+        if self._telegram_config is not None:
+            for text in _format_telegram_messages(payload):
+                await self._post(
+                    "telegram",
+                    f"{_TELEGRAM_API_URL}/bot{self._telegram_config['token']}/sendMessage",
+                    {"chat_id": self._telegram_config["chat_id"], "text": text},
+                )
+
+    # The target is a name safe for logging, unlike the URL.
+    async def _post(self, target: str, url: str, payload: dict[str, Any]) -> None:
+        for attempt in range(1, self._max_attempts + 1):
+            try:
+                async with self._session.post(
+                    url,
+                    data=json.dumps(payload),
+                    headers={"Content-Type": "application/json"},
+                ) as response:
+                    if response.status < 400:
+                        return
+                    reason = f"status {response.status}"
+            except (aiohttp.ClientError, asyncio.TimeoutError) as exc:
+                reason = type(exc).__name__
+            _log.warning(
+                f"sending alert to {target} failed with {reason}; attempt {attempt} of "
+                f"{self._max_attempts}"
+            )
+            if attempt < self._max_attempts:
+                await asyncio.sleep(min(2 ** (attempt - 1), self._timeout))
+
+
+# Telegram limits the length of a message. Longer texts are split into multiple messages.
+def _format_telegram_messages(payload: dict[str, Any]) -> list[str]:
+    text = (
+        f"Agent {payload['agent']} {payload['event']}:\n"
+        f"{json.dumps(payload['data'], indent=4)}"
+    )
+    return list(sliced(text, _TELEGRAM_MAX_MESSAGE_LENGTH))
@@ -2,7 +2,7 @@ import asyncio
 import logging
 from collections import defaultdict
 from decimal import Decimal
-from typing import Any, Iterable
+from typing import Any, Iterable, Optional
 
 from tenacity import (
     RetryError,
@@ -18,6 +18,7 @@ from juno import (
     Asset,
     BadOrder,
     Balance,
+    Fees,
     Fill,
     Filters,
     Interval,
@@ -33,7 +34,7 @@ from juno.custodians import Custodian
 from juno.exchanges import Exchange, Kraken
 from juno.inspect import extract_public
 from juno.math import ceil_multiple, round_down, round_half_up
-from juno.trading import CloseReason, Position, TradingMode
+from juno.trading import CloseReason, FeeDiscount, Position, TradingMode
 
 _log = logging.getLogger(__name__)
 
@@ -652,12 +653,19 @@ class SimulatedPositioner:
         exchange: str,
         # [symbol, quote, short, time, price]
         entries: list[tuple[str, Decimal, bool, Timestamp, Decimal]],
+        maker: bool = False,  # Whether orders pay the maker fee instead of taker.
+        fees: Optional[Fees] = None,  # Overrides the fees of the symbols.
+        fee_discount: Optional[FeeDiscount] = None,
     ) -> list[Position.Open]:
         return [
             (
-                self._open_simulated_short_position(exchange, symbol, time, price, quote)
+                self._open_simulated_short_position(
+                    exchange, symbol, time, price, quote, maker, fees, fee_discount
+                )
                 if short
-                else self._open_simulated_long_position(exchange, symbol, time, price, quote)
+                else self._open_simulated_long_position(
+                    exchange, symbol, time, price, quote, maker, fees, fee_discount
+                )
             )
             for symbol, quote, short, time, price in entries
         ]
@@ -666,12 +674,19 @@ class SimulatedPositioner:
         self,
         # [symbol, close reason, time, price]
         entries: list[tuple[Position.Open, CloseReason, Timestamp, Decimal]],
+        maker: bool = False,  # Whether orders pay the maker fee instead of taker.
+        fees: Optional[Fees] = None,  # Overrides the fees of the symbols.
+        fee_discount: Optional[FeeDiscount] = None,
     ) -> list[Position.Closed]:
         return [
             (
-                self._close_simulated_short_position(pos, time, price, reason)
+                self._close_simulated_short_position(
+                    pos, time, price, reason, maker, fees, fee_discount
+                )
                 if isinstance(pos, Position.OpenShort)
-                else self._close_simulated_long_position(pos, time, price, reason)
+                else self._close_simulated_long_position(
+                    pos, time, price, reason, maker, fees, fee_discount
+                )
             )
             for pos, reason, time, price in entries
         ]
@@ -683,15 +698,21 @@ class SimulatedPositioner:
         time: Timestamp,
         price: Decimal,
         quote: Decimal,
+        maker: bool,
+        fees: Optional[Fees],
+        fee_discount: Optional[FeeDiscount],
     ) -> Position.OpenLong:
         base_asset, quote_asset = Symbol_.assets(symbol)
-        fees, filters = self._informant.get_fees_filters(exchange, symbol)
+        symbol_fees, filters = self._informant.get_fees_filters(exchange, symbol)
+        fees = symbol_fees if fees is None else fees
+        fee_rate = fees.maker if maker else fees.taker
 
         size = filters.size.round_down(quote / price)
         if size == 0:
             raise BadOrder("Insufficient funds")
         quote = round_down(price * size, filters.quote_precision)
-        fee = round_half_up(size * fees.taker, filters.base_precision)
+        fee = round_half_up(size * fee_rate, filters.base_precision)
+        fee, fee_asset = _discount_fee(fee, base_asset, price, quote_asset, fee_discount)
         base_asset_info = self._informant.get_asset_info(exchange, base_asset)
         quote_asset_info = self._informant.get_asset_info(exchange, quote_asset)
 
@@ -699,7 +720,7 @@ class SimulatedPositioner:
             exchange=exchange,
             symbol=symbol,
             time=time,
-            fills=[Fill(price=price, size=size, quote=quote, fee=fee, fee_asset=base_asset)],
+            fills=[Fill(price=price, size=size, quote=quote, fee=fee, fee_asset=fee_asset)],
             base_asset_info=base_asset_info,
             quote_asset_info=quote_asset_info,
         )
@@ -712,9 +733,14 @@ class SimulatedPositioner:
         time: Timestamp,
         price: Decimal,
         reason: CloseReason,
+        maker: bool,
+        fees: Optional[Fees],
+        fee_discount: Optional[FeeDiscount],
     ) -> Position.Long:
         base_asset, quote_asset = Symbol_.assets(position.symbol)
-        fees, filters = self._informant.get_fees_filters(position.exchange, position.symbol)
+        symbol_fees, filters = self._informant.get_fees_filters(position.exchange, position.symbol)
+        fees = symbol_fees if fees is None else fees
+        fee_rate = fees.maker if maker else fees.taker
         base_asset_info = self._informant.get_asset_info(position.exchange, base_asset)
         quote_asset_info = self._informant.get_asset_info(position.exchange, quote_asset)
 
@@ -722,8 +748,9 @@ class SimulatedPositioner:
         size = filters.size.round_down(position.base_gain)
         if size > 0:
             quote = round_down(price * size, filters.quote_precision)
-            fee = round_half_up(quote * fees.taker, filters.quote_precision)
-            fills.append(Fill(price=price, size=size, quote=quote, fee=fee, fee_asset=quote_asset))
+            fee = round_half_up(quote * fee_rate, filters.quote_precision)
+            fee, fee_asset = _discount_fee(fee, quote_asset, price, quote_asset, fee_discount)
+            fills.append(Fill(price=price, size=size, quote=quote, fee=fee, fee_asset=fee_asset))
         # If size is 0, we cannot close the position anymore. This can happen if the amount bought
         # falls below min size filter due to fees, for example.
 
@@ -747,9 +774,14 @@ class SimulatedPositioner:
         time: Timestamp,
         price: Decimal,
         collateral: Decimal,
+        maker: bool,
+        fees: Optional[Fees],
+        fee_discount: Optional[FeeDiscount],
     ) -> Position.OpenShort:
         base_asset, quote_asset = Symbol_.assets(symbol)
-        fees, filters = self._informant.get_fees_filters(exchange, symbol)
+        symbol_fees, filters = self._informant.get_fees_filters(exchange, symbol)
+        fees = symbol_fees if fees is None else fees
+        fee_rate = fees.maker if maker else fees.taker
         limit = self._informant.get_borrow_info(
             exchange=exchange, asset=base_asset, account=symbol
         ).limit
@@ -761,7 +793,8 @@ class SimulatedPositioner:
 
         borrowed = _calculate_borrowed(filters, MARGIN_MULTIPLIER, limit, collateral, price)
         quote = round_down(price * borrowed, filters.quote_precision)
-        fee = round_half_up(quote * fees.taker, filters.quote_precision)
+        fee = round_half_up(quote * fee_rate, filters.quote_precision)
+        fee, fee_asset = _discount_fee(fee, quote_asset, price, quote_asset, fee_discount)
 
         open_position = Position.OpenShort.build(
             exchange=exchange,
@@ -769,7 +802,7 @@ class SimulatedPositioner:
             collateral=collateral,
             borrowed=borrowed,
             time=time,
-            fills=[Fill(price=price, size=borrowed, quote=quote, fee=fee, fee_asset=quote_asset)],
+            fills=[Fill(price=price, size=borrowed, quote=quote, fee=fee, fee_asset=fee_asset)],
         )
         _log.info(f"opened simulated short position {symbol} at {Timestamp_.format(time)}")
         return open_position
@@ -780,9 +813,14 @@ class SimulatedPositioner:
         time: Timestamp,
         price: Decimal,
         reason: CloseReason,
+        maker: bool,
+        fees: Optional[Fees],
+        fee_discount: Optional[FeeDiscount],
     ) -> Position.Short:
         base_asset, quote_asset = Symbol_.assets(position.symbol)
-        fees, filters = self._informant.get_fees_filters(position.exchange, position.symbol)
+        symbol_fees, filters = self._informant.get_fees_filters(position.exchange, position.symbol)
+        fees = symbol_fees if fees is None else fees
+        fee_rate = fees.maker if maker else fees.taker
         base_asset_info = self._informant.get_asset_info(
             exchange=position.exchange, asset=base_asset
         )
@@ -802,14 +840,16 @@ class SimulatedPositioner:
             precision=base_asset_info.precision,
         )
         size = position.borrowed + interest
-        fee = round_half_up(size * fees.taker, filters.base_precision)
-        size += fee
+        fee = round_half_up(size * fee_rate, filters.base_precision)
+        fee, fee_asset = _discount_fee(fee, base_asset, price, quote_asset, fee_discount)
+        if fee_asset == base_asset:
+            size += fee
         quote = round_down(price * size, filters.quote_precision)
 
         closed_position = position.close(
             time=time,
             interest=interest,
-            fills=[Fill(price=price, size=size, quote=quote, fee=fee, fee_asset=base_asset)],
+            fills=[Fill(price=price, size=size, quote=quote, fee=fee, fee_asset=fee_asset)],
             reason=reason,
             quote_asset_info=quote_asset_info,
         )
@@ -820,6 +860,19 @@ class SimulatedPositioner:
         return closed_position
 
 
+def _discount_fee(
+    fee: Decimal,
+    fee_asset: Asset,
+    price: Decimal,
+    quote_asset: Asset,
+    fee_discount: Optional[FeeDiscount],
+) -> tuple[Decimal, Asset]:
+    if fee_discount is None:
+        return fee, fee_asset
+    quote_fee = fee if fee_asset == quote_asset else fee * price
+    return fee_discount.fee(quote_fee), fee_discount.asset
+
+
 def _calculate_borrowed(
     filters: Filters, margin_multiplier: int, limit: Decimal, collateral: Decimal, price: Decimal
 ) -> Decimal:
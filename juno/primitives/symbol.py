@@ -1,8 +1,11 @@
+import re
 from types import ModuleType
 from typing import Iterable
 
 from ._aliases import Asset, Symbol
 
+_SYMBOL_RE = re.compile(r"^([a-z0-9]+)[-/_]([a-z0-9]+)$")
+
 
 class Symbol_(ModuleType):
     @staticmethod
@@ -28,3 +31,15 @@ class Symbol_(ModuleType):
     @staticmethod
     def iter_assets(symbols: Iterable[Symbol]) -> Iterable[Asset]:
         return (asset for symbol in symbols for asset in Symbol_.assets(symbol))
+
+    @staticmethod
+    def is_valid(symbol: Symbol) -> bool:
+        return _SYMBOL_RE.match(symbol) is not None and "-" in symbol
+
+    @staticmethod
+    def normalize(symbol: str) -> Symbol:
+        """Converts a symbol such as `ETH/BTC` or `eth_btc` into the canonical `eth-btc` form."""
+        match = _SYMBOL_RE.match(symbol.strip().lower())
+        if not match:
+            raise ValueError(f"Invalid symbol: {symbol}")
+        return f"{match.group(1)}-{match.group(2)}"
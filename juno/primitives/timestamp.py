@@ -1,4 +1,4 @@
-from datetime import datetime, timedelta, timezone
+from datetime import datetime, timezone
 from time import time
 from types import ModuleType
 
@@ -7,8 +7,6 @@ from juno.math import ceil_multiple, ceil_multiple_offset, floor_multiple, floor
 from ._aliases import Interval, Timestamp
 from .interval import Interval_
 
-_WEEK_OFFSET_MS = 345_600_000
-
 
 class Timestamp_(ModuleType):
     MAX_TIME: Timestamp = 3_000_000_000_000  # 2065-01-24 05:20
@@ -47,36 +45,67 @@ class Timestamp_(ModuleType):
             dt = dt.replace(tzinfo=timezone.utc)
         return Timestamp_.from_datetime_utc(dt)
 
+    # Intervals which are multiples of a month (including years) are calendar-aware: they have
+    # varying length and are aligned to the start of a year (e.g. 3M to quarters).
+    # Intervals which are multiples of a week are aligned to `week_start` (Monday is 0).
+
     @staticmethod
-    def floor(timestamp: Timestamp, interval: Interval) -> Timestamp:
-        if interval < Interval_.WEEK:
+    def floor(timestamp: Timestamp, interval: Interval, week_start: int = 0) -> Timestamp:
+        if _is_monthly(interval):
+            months = _month_index(timestamp)
+            return _from_month_index(floor_multiple(months, interval // Interval_.MONTH))
+        if interval % Interval_.WEEK == 0:
+            return floor_multiple_offset(timestamp, interval, _week_offset(week_start))
+        if interval < Interval_.MONTH:
             return floor_multiple(timestamp, interval)
-        if interval == Interval_.WEEK:
-            return floor_multiple_offset(timestamp, interval, _WEEK_OFFSET_MS)
-        if interval == Interval_.MONTH:
-            dt = Timestamp_.to_datetime_utc(timestamp)
-            return Timestamp_.from_datetime_utc(
-                dt.replace(day=1, hour=0, minute=0, second=0, microsecond=0)
-            )
         raise NotImplementedError()
 
     @staticmethod
-    def ceil(timestamp: Timestamp, interval: Interval) -> Timestamp:
-        if interval < Interval_.WEEK:
+    def ceil(timestamp: Timestamp, interval: Interval, week_start: int = 0) -> Timestamp:
+        if _is_monthly(interval):
+            floor = Timestamp_.floor(timestamp, interval)
+            return floor if floor == timestamp else Timestamp_.next(floor, interval)
+        if interval % Interval_.WEEK == 0:
+            return ceil_multiple_offset(timestamp, interval, _week_offset(week_start))
+        if interval < Interval_.MONTH:
             return ceil_multiple(timestamp, interval)
-        if interval == Interval_.WEEK:
-            return ceil_multiple_offset(timestamp, interval, _WEEK_OFFSET_MS)
-        if interval == Interval_.MONTH:
-            dt = Timestamp_.to_datetime_utc(timestamp)
-            return Timestamp_.from_datetime_utc(
-                (dt.replace(day=1) + timedelta(days=32)).replace(day=1)
-            )
         raise NotImplementedError()
 
     @staticmethod
-    def is_in_interval(timestamp: Timestamp, interval: Interval) -> bool:
-        if interval < Interval_.WEEK:
-            return timestamp % interval == 0
-        if interval == Interval_.WEEK:
-            return (timestamp % interval) - _WEEK_OFFSET_MS == 0
-        raise NotImplementedError()
+    def is_in_interval(timestamp: Timestamp, interval: Interval, week_start: int = 0) -> bool:
+        return Timestamp_.floor(timestamp, interval, week_start) == timestamp
+
+    @staticmethod
+    def next(timestamp: Timestamp, interval: Interval, week_start: int = 0) -> Timestamp:
+        """Returns the start of the interval following the one `timestamp` falls into."""
+        floor = Timestamp_.floor(timestamp, interval, week_start)
+        if _is_monthly(interval):
+            return _from_month_index(_month_index(floor) + interval // Interval_.MONTH)
+        return floor + interval
+
+    @staticmethod
+    def num_intervals(start: Timestamp, end: Timestamp, interval: Interval) -> int:
+        """Returns the number of whole intervals between two interval aligned timestamps."""
+        if _is_monthly(interval):
+            return (_month_index(end) - _month_index(start)) // (interval // Interval_.MONTH)
+        return (end - start) // interval
+
+
+def _is_monthly(interval: Interval) -> bool:
+    return interval >= Interval_.MONTH and interval % Interval_.MONTH == 0
+
+
+def _month_index(timestamp: Timestamp) -> int:
+    dt = Timestamp_.to_datetime_utc(timestamp)
+    return dt.year * 12 + dt.month - 1
+
+
+def _from_month_index(index: int) -> Timestamp:
+    year, month = divmod(index, 12)
+    return Timestamp_.from_datetime_utc(datetime(year, month + 1, 1, tzinfo=timezone.utc))
+
+
+def _week_offset(week_start: int) -> int:
+    assert 0 <= week_start <= 6
+    # Epoch (1970-01-01) is a Thursday.
+    return ((week_start - 3) % 7) * Interval_.DAY
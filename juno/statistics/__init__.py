@@ -1,13 +1,32 @@
 from __future__ import annotations
 
+from .aggregation import WORST_FITNESS, EvaluationAggregation, aggregate, is_degenerate
+from .breakdown import BreakdownBucket, Breakdowns
 from .core import CoreStatistics
-from .extended import ExtendedStatistics
+from .correlation import CorrelationMethod, correlation_matrix
+from .extended import BenchmarkStatistics, ExtendedStatistics
+from .returns import period_returns
+from .significance import block_shuffle, p_value
 from .statistician import Statistician
 from .statistics import Statistics
+from .streaming import StreamingStatistics
 
 __all__ = [
+    "BenchmarkStatistics",
+    "BreakdownBucket",
+    "Breakdowns",
     "CoreStatistics",
+    "CorrelationMethod",
+    "EvaluationAggregation",
     "ExtendedStatistics",
     "Statistician",
     "Statistics",
+    "StreamingStatistics",
+    "WORST_FITNESS",
+    "aggregate",
+    "block_shuffle",
+    "correlation_matrix",
+    "is_degenerate",
+    "p_value",
+    "period_returns",
 ]
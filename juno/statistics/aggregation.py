@@ -0,0 +1,54 @@
+import statistics
+from decimal import Decimal
+from enum import IntEnum
+
+
+class EvaluationAggregation(IntEnum):
+    MEAN = 0
+    MIN = 1
+    MEDIAN = 2
+    HARMONIC_MEAN = 3  # Requires all values to be positive.
+    WORST_K_MEAN = 4  # Mean of the `k` smallest values.
+    PENALIZED_MEAN = 5  # Mean minus `penalty` times the population standard deviation.
+
+
+# Fitness of an evaluation with a degenerate statistic value. Statistics can be NaN or infinite for
+# degenerate runs, e.g. without any positions or with zero variance of returns. Such values would
+# otherwise poison comparisons and aggregations.
+WORST_FITNESS = Decimal("-Infinity")
+
+
+def is_degenerate(value: Decimal) -> bool:
+    return not value.is_finite()
+
+
+# Aggregates a statistic of multiple symbols into a single fitness value. Aggregations other than
+# mean penalize inconsistent results across symbols to a varying degree.
+def aggregate(
+    values: list[Decimal],
+    aggregation: EvaluationAggregation = EvaluationAggregation.MEAN,
+    k: int = 1,
+    penalty: Decimal = Decimal("1.0"),
+) -> Decimal:
+    if len(values) == 0:
+        raise ValueError("At least one value required")
+    if any(is_degenerate(v) for v in values):
+        return WORST_FITNESS
+
+    if aggregation is EvaluationAggregation.MEAN:
+        return statistics.mean(values)
+    if aggregation is EvaluationAggregation.MIN:
+        return min(values)
+    if aggregation is EvaluationAggregation.MEDIAN:
+        return statistics.median(values)
+    if aggregation is EvaluationAggregation.HARMONIC_MEAN:
+        if any(v <= 0 for v in values):
+            raise ValueError("Harmonic mean requires all values to be positive")
+        return len(values) / sum(1 / v for v in values)
+    if aggregation is EvaluationAggregation.WORST_K_MEAN:
+        if k < 1:
+            raise ValueError("K should be positive")
+        return statistics.mean(sorted(values)[:k])
+    if aggregation is EvaluationAggregation.PENALIZED_MEAN:
+        return statistics.mean(values) - penalty * statistics.pstdev(values)
+    raise NotImplementedError()
@@ -0,0 +1,56 @@
+from __future__ import annotations
+
+from collections import defaultdict
+from dataclasses import dataclass, field
+from decimal import Decimal
+from typing import Callable
+
+from juno import Timestamp_
+from juno.math import kahan_sum
+from juno.trading import Position, TradingSummary
+
+
+@dataclass(frozen=True)
+class BreakdownBucket:
+    num_positions: int = 0
+    profit: Decimal = Decimal("0.0")
+    win_rate: Decimal = Decimal("0.0")
+
+
+# Breaks positions down by their UTC open time to spot seasonality effects. Months are 1-12,
+# weekdays 0-6 (Monday is 0) and hours 0-23. Buckets without positions are omitted.
+@dataclass(frozen=True)
+class Breakdowns:
+    by_month: dict[int, BreakdownBucket] = field(default_factory=dict)
+    by_weekday: dict[int, BreakdownBucket] = field(default_factory=dict)
+    by_hour: dict[int, BreakdownBucket] = field(default_factory=dict)
+
+    @staticmethod
+    def compose(summary: TradingSummary) -> Breakdowns:
+        positions = summary.positions
+        return Breakdowns(
+            by_month=_breakdown(
+                positions, lambda p: Timestamp_.to_datetime_utc(p.open_time).month
+            ),
+            by_weekday=_breakdown(
+                positions, lambda p: Timestamp_.to_datetime_utc(p.open_time).weekday()
+            ),
+            by_hour=_breakdown(positions, lambda p: Timestamp_.to_datetime_utc(p.open_time).hour),
+        )
+
+
+def _breakdown(
+    positions: list[Position.Closed], get_bucket: Callable[[Position.Closed], int]
+) -> dict[int, BreakdownBucket]:
+    buckets: dict[int, list[Position.Closed]] = defaultdict(list)
+    for position in positions:
+        buckets[get_bucket(position)].append(position)
+    return {
+        bucket: BreakdownBucket(
+            num_positions=len(bucket_positions),
+            profit=kahan_sum((p.profit for p in bucket_positions), Decimal("0.0")),
+            win_rate=Decimal(sum(1 for p in bucket_positions if p.profit >= 0))
+            / len(bucket_positions),
+        )
+        for bucket, bucket_positions in sorted(buckets.items())
+    }
@@ -6,7 +6,7 @@ from decimal import Decimal
 from typing import Sequence
 
 from juno import Candle, Fees, Filters, Interval, Timestamp
-from juno.math import annualized, round_half_up
+from juno.math import annualized, kahan_sum, round_half_up
 from juno.trading import CloseReason, Position, TradingSummary
 
 
@@ -40,6 +40,26 @@ class CoreStatistics:
     num_short_positions_in_loss: int
     num_stop_losses: int
     num_take_profits: int
+    fees: Decimal  # In quote asset.
+    volume: Decimal  # In quote asset.
+    turnover: Decimal
+    max_consecutive_wins: int
+    max_consecutive_losses: int
+    profit_factor: Decimal  # Gross profit over gross loss.
+    payoff_ratio: Decimal  # Mean winning position profit over mean losing position loss.
+    win_loss_ratio: Decimal  # Number of winning positions over losing positions.
+    expectancy: Decimal  # Mean position profit per unit of mean loss.
+    kelly_fraction: Decimal  # Optimal share of capital to risk per position.
+    half_kelly_fraction: Decimal
+    risk_of_ruin: Decimal  # Probability of losing all capital. An approximation.
+    # Distributions of maximum adverse and favorable excursions, relative to the entry price.
+    mean_mae: Decimal
+    median_mae: Decimal
+    max_mae: Decimal
+    mean_winning_mae: Decimal  # Adverse excursions winners survived. Guides stop distance.
+    mean_mfe: Decimal
+    median_mfe: Decimal
+    max_mfe: Decimal
 
     @staticmethod
     def compose(summary: TradingSummary) -> CoreStatistics:
@@ -70,6 +90,27 @@ class CoreStatistics:
             max_drawdown = max(max_drawdown, drawdown)
         mean_drawdown = Decimal("0.0") if len(positions) == 0 else sum_drawdown / len(positions)
 
+        # Trade clustering.
+        max_consecutive_wins, max_consecutive_losses = CoreStatistics._max_streaks(positions)
+        wins = [p.profit for p in positions if p.profit >= 0]
+        losses = [-p.profit for p in positions if p.profit < 0]
+        gross_profit = kahan_sum(wins, Decimal("0.0"))
+        gross_loss = kahan_sum(losses, Decimal("0.0"))
+        mean_win = gross_profit / len(wins) if len(wins) > 0 else Decimal("0.0")
+        mean_loss = gross_loss / len(losses) if len(losses) > 0 else Decimal("0.0")
+        expectancy = _ratio(CoreStatistics._mean_position_profit(positions), mean_loss)
+
+        # Position sizing.
+        kelly_fraction = CoreStatistics._kelly_fraction(
+            len(wins), len(losses), mean_win, mean_loss
+        )
+        risk_of_ruin = CoreStatistics._risk_of_ruin(len(positions), cost, mean_loss, expectancy)
+
+        # Excursions.
+        mean_mae, median_mae, max_mae = _distribution([p.mae for p in positions])
+        mean_winning_mae, _, _ = _distribution([p.mae for p in positions if p.profit >= 0])
+        mean_mfe, median_mfe, max_mfe = _distribution([p.mfe for p in positions])
+
         return CoreStatistics(
             start=start,
             end=end,
@@ -101,6 +142,25 @@ class CoreStatistics:
             max_drawdown=max_drawdown,
             mean_drawdown=mean_drawdown,
             return_over_max_drawdown=Decimal("0.0") if max_drawdown == 0 else roi / max_drawdown,
+            fees=summary.fees,
+            volume=summary.volume,
+            turnover=summary.turnover,
+            max_consecutive_wins=max_consecutive_wins,
+            max_consecutive_losses=max_consecutive_losses,
+            profit_factor=_ratio(gross_profit, gross_loss),
+            payoff_ratio=_ratio(mean_win, mean_loss),
+            win_loss_ratio=_ratio(Decimal(len(wins)), Decimal(len(losses))),
+            expectancy=expectancy,
+            kelly_fraction=kelly_fraction,
+            half_kelly_fraction=kelly_fraction / 2,
+            risk_of_ruin=risk_of_ruin,
+            mean_mae=mean_mae,
+            median_mae=median_mae,
+            max_mae=max_mae,
+            mean_winning_mae=mean_winning_mae,
+            mean_mfe=mean_mfe,
+            median_mfe=median_mfe,
+            max_mfe=max_mfe,
         )
 
     @staticmethod
@@ -111,6 +171,47 @@ class CoreStatistics:
     def _num_positions_in_loss(positions: Sequence[Position.Closed]) -> int:
         return sum(1 for p in positions if p.profit < 0)
 
+    @staticmethod
+    def _max_streaks(positions: Sequence[Position.Closed]) -> tuple[int, int]:
+        max_wins, max_losses = 0, 0
+        wins, losses = 0, 0
+        for p in positions:
+            if p.profit >= 0:
+                wins, losses = wins + 1, 0
+            else:
+                wins, losses = 0, losses + 1
+            max_wins = max(max_wins, wins)
+            max_losses = max(max_losses, losses)
+        return max_wins, max_losses
+
+    @staticmethod
+    def _kelly_fraction(
+        num_wins: int, num_losses: int, mean_win: Decimal, mean_loss: Decimal
+    ) -> Decimal:
+        # f = W - (1 - W) / R, where W is the win rate and R the payoff ratio. Negative values
+        # mean there is no edge and are clamped to zero. So do wins which all broke even.
+        if num_wins == 0 or mean_win == 0:
+            return Decimal("0.0")
+        if num_losses == 0:
+            return Decimal("1.0")
+        win_rate = Decimal(num_wins) / (num_wins + num_losses)
+        payoff_ratio = mean_win / mean_loss
+        return max(win_rate - (1 - win_rate) / payoff_ratio, Decimal("0.0"))
+
+    @staticmethod
+    def _risk_of_ruin(
+        num_positions: int, cost: Decimal, mean_loss: Decimal, expectancy: Decimal
+    ) -> Decimal:
+        # ((1 - E) / (1 + E)) ^ U, where E is the expectancy per unit of mean loss and U is the
+        # number of mean losses the starting capital can absorb.
+        if num_positions == 0 or mean_loss == 0:
+            return Decimal("0.0")
+        if expectancy <= 0:
+            return Decimal("1.0")
+        if expectancy >= 1:
+            return Decimal("0.0")
+        return ((1 - expectancy) / (1 + expectancy)) ** (cost / mean_loss)
+
     @staticmethod
     def _mean_position_profit(positions: Sequence[Position.Closed]) -> Decimal:
         if len(positions) == 0:
@@ -139,3 +240,17 @@ class CoreStatistics:
         quote_hodl = filters.size.round_down(base_hodl) * last_candle.close
         quote_hodl -= round_half_up(quote_hodl * fees.taker, filters.quote_precision)
         return quote_hodl - quote
+
+
+# Infinite if only the numerator is non-zero.
+def _ratio(numerator: Decimal, denominator: Decimal) -> Decimal:
+    if denominator == 0:
+        return Decimal("Inf") if numerator > 0 else Decimal("0.0")
+    return numerator / denominator
+
+
+# Mean, median and max. Zeros if there are no values.
+def _distribution(values: list[Decimal]) -> tuple[Decimal, Decimal, Decimal]:
+    if len(values) == 0:
+        return Decimal("0.0"), Decimal("0.0"), Decimal("0.0")
+    return statistics.mean(values), statistics.median(values), max(values)
@@ -0,0 +1,32 @@
+from __future__ import annotations
+
+from decimal import Decimal
+from enum import IntEnum
+
+import pandas as pd
+
+from juno import Symbol
+
+
+class CorrelationMethod(IntEnum):
+    PEARSON = 0
+    SPEARMAN = 1  # Rank based; less sensitive to outliers.
+
+
+# Pairwise correlation of period returns between symbols. Prices of all symbols must be aligned,
+# i.e. cover the same periods. Correlation with a symbol whose price never changes is NaN.
+def correlation_matrix(
+    prices: dict[Symbol, list[Decimal]],
+    method: CorrelationMethod = CorrelationMethod.PEARSON,
+) -> dict[Symbol, dict[Symbol, float]]:
+    if len({len(p) for p in prices.values()}) > 1:
+        raise ValueError("Prices of all symbols must be of equal length")
+
+    returns = pd.DataFrame(
+        {symbol: [float(p) for p in symbol_prices] for symbol, symbol_prices in prices.items()}
+    ).pct_change()[1:]
+    correlations = returns.corr(method=method.name.lower())
+    return {
+        symbol: {other: float(correlations[symbol][other]) for other in prices.keys()}
+        for symbol in prices.keys()
+    }
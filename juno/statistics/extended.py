@@ -1,19 +1,18 @@
 from __future__ import annotations
 
 from collections import defaultdict
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from decimal import Decimal
-from typing import Any, Callable
+from typing import Any, Callable, Optional
 
 import numpy as np
 import pandas as pd
 
-from juno import Asset, Interval, Interval_, Symbol_, Timestamp
-from juno.math import floor_multiple
+from juno import Asset, Fees, Interval, Interval_, Symbol, Symbol_, Timestamp
+from juno.math import floor_multiple, kahan_sum
 from juno.trading import TradingSummary
 
 Operator = Callable[[Decimal, Decimal], Decimal]
-_SQRT_365 = np.sqrt(365)
 
 
 @dataclass(frozen=True)
@@ -26,15 +25,33 @@ class ExtendedStatistics:
     sortino_ratio: float
     cagr: float
 
+    # Annualization factor, i.e. the number of statistics intervals in a year. Annualized figures
+    # scale the per interval mean return by it and the standard deviations by its square root.
+    periods_per_year: int = 365
+    # Sharpe and sortino ratios of per interval returns, before annualization.
+    raw_sharpe_ratio: float = 0.0
+    raw_sortino_ratio: float = 0.0
+
     alpha: float = 0.0
     beta: float = 0.0
 
+    # Style analysis against the benchmark. Capture ratios compare the mean return of the strategy
+    # to that of the benchmark over intervals where the benchmark went up or down respectively.
+    # High capture in both directions suggests leveraged beta rather than alpha.
+    up_capture: float = 0.0
+    down_capture: float = 0.0
+    # Beta over a trailing window of `rolling_window` intervals, starting from the first full
+    # window.
+    rolling_beta: list[float] = field(default_factory=list)
+
     @staticmethod
     def compose(
         summary: TradingSummary,
         asset_prices: dict[Asset, list[Decimal]],
         interval: Interval = Interval_.DAY,
         benchmark_asset: Asset = "btc",
+        rolling_window: int = 30,
+        periods_per_year: Optional[int] = None,  # None means derived from interval.
     ) -> ExtendedStatistics:
         assert summary.end is not None
 
@@ -50,16 +67,96 @@ class ExtendedStatistics:
                     f"Expected at least {num_ticks} price points for {asset} but got {len(prices)}"
                 )
 
-        trades = _get_trades_from_summary(summary, interval)
-        asset_performance = _get_asset_performance(
-            summary, start, end, asset_prices, trades, interval
-        )
-        portfolio_performance = pd.Series(
-            [float(sum(v for v in apd.values())) for apd in asset_performance]
+        portfolio_performance = _get_portfolio_performance(
+            summary, start, end, asset_prices, interval
         )
         benchmark_performance = pd.Series([float(p) for p in asset_prices[benchmark_asset]])
 
-        return _calculate_statistics(portfolio_performance, benchmark_performance)
+        return _calculate_statistics(
+            portfolio_performance,
+            benchmark_performance,
+            rolling_window=rolling_window,
+            periods_per_year=(
+                _periods_per_year(interval) if periods_per_year is None else periods_per_year
+            ),
+            # Compounded over actual elapsed time rather than the number of intervals.
+            years=(summary.end - summary.start) / Interval_.YEAR,
+        )
+
+
+# Buy-and-hold of a single symbol over the same range as the trading summary. The whole starting
+# quote is used to buy the base asset at the first price, paying a taker fee. Symbols are the ones
+# configured for trading, whether or not any positions were taken in them.
+@dataclass(frozen=True)
+class BenchmarkStatistics:
+    profit: float  # In target asset.
+    sharpe_ratio: float
+
+    # Strategy minus benchmark.
+    vs_profit: float
+    vs_sharpe_ratio: float
+
+    @staticmethod
+    def compose(
+        summary: TradingSummary,
+        asset_prices: dict[Asset, list[Decimal]],
+        symbols: list[Symbol],
+        interval: Interval = Interval_.DAY,
+        fees: dict[Symbol, Fees] = {},
+    ) -> dict[Symbol, BenchmarkStatistics]:
+        start = floor_multiple(summary.start, interval)
+        end = floor_multiple(summary.end, interval)
+        num_ticks = (end - start) // interval
+
+        portfolio_performance = _get_portfolio_performance(
+            summary, start, end, asset_prices, interval
+        )
+        periods_per_year = _periods_per_year(interval)
+        portfolio_statistics = _calculate_statistics(
+            portfolio_performance, portfolio_performance, periods_per_year=periods_per_year
+        )
+        portfolio_profit = portfolio_performance.iloc[-1] - portfolio_performance.iloc[0]
+
+        result = {}
+        for symbol in sorted(set(symbols)):
+            base_asset, quote_asset = Symbol_.assets(symbol)
+            starting_quote = summary.starting_assets.get(quote_asset)
+            if starting_quote is None:
+                continue
+            base_prices = asset_prices[base_asset]
+            starting_value = starting_quote * asset_prices[quote_asset][0]
+            base_hodl = starting_value / base_prices[0]
+            base_hodl -= base_hodl * fees.get(symbol, Fees()).taker
+
+            performance = pd.Series(
+                [float(starting_value)]
+                + [float(base_hodl * base_prices[i]) for i in range(1, num_ticks + 1)]
+            )
+            statistics = _calculate_statistics(
+                performance, performance, periods_per_year=periods_per_year
+            )
+            profit = performance.iloc[-1] - performance.iloc[0]
+            result[symbol] = BenchmarkStatistics(
+                profit=profit,
+                sharpe_ratio=statistics.sharpe_ratio,
+                vs_profit=portfolio_profit - profit,
+                vs_sharpe_ratio=portfolio_statistics.sharpe_ratio - statistics.sharpe_ratio,
+            )
+        return result
+
+
+def _get_portfolio_performance(
+    summary: TradingSummary,
+    start: Timestamp,
+    end: Timestamp,
+    asset_prices: dict[Asset, list[Decimal]],
+    interval: Interval,
+) -> pd.Series:
+    trades = _get_trades_from_summary(summary, interval)
+    asset_performance = _get_asset_performance(
+        summary, start, end, asset_prices, trades, interval
+    )
+    return pd.Series([float(kahan_sum(apd.values(), Decimal("0.0"))) for apd in asset_performance])
 
 
 def _get_trades_from_summary(
@@ -128,18 +225,30 @@ def _get_g_returns(performance: pd.Series) -> Any:
     return np.log(a_returns + 1)
 
 
+def _periods_per_year(interval: Interval) -> int:
+    return max(Interval_.YEAR // interval, 1)
+
+
 def _calculate_statistics(
-    performance: pd.Series, benchmark_performance: pd.Series
+    performance: pd.Series,
+    benchmark_performance: pd.Series,
+    rolling_window: int = 30,
+    periods_per_year: int = 365,
+    years: Optional[float] = None,  # None means the number of periods over periods per year.
 ) -> ExtendedStatistics:
+    assert rolling_window > 1
+    assert periods_per_year > 0
+
     g_returns = _get_g_returns(performance)
     neg_g_returns = g_returns[g_returns < 0].dropna()
     benchmark_g_returns = _get_g_returns(benchmark_performance)
 
     # Compute statistics.
     total_return = performance.iloc[-1] / performance.iloc[0] - 1
-    annualized_return = 365 * g_returns.mean()
-    annualized_volatility = _SQRT_365 * g_returns.std(ddof=0)
-    annualized_downside_risk = _SQRT_365 * neg_g_returns.std(ddof=0)
+    sqrt_periods = np.sqrt(periods_per_year)
+    annualized_return = periods_per_year * g_returns.mean()
+    annualized_volatility = sqrt_periods * g_returns.std(ddof=0)
+    annualized_downside_risk = sqrt_periods * neg_g_returns.std(ddof=0)
 
     sharpe_ratio = (
         annualized_return / annualized_volatility if annualized_volatility else Decimal("0.0")
@@ -149,7 +258,9 @@ def _calculate_statistics(
         if annualized_downside_risk
         else Decimal("0.0")
     )
-    cagr = ((performance.iloc[-1] / performance.iloc[0]) ** (1 / (performance.size / 365))) - 1
+    if years is None:
+        years = performance.size / periods_per_year
+    cagr = ((performance.iloc[-1] / performance.iloc[0]) ** (1 / years)) - 1 if years else 0.0
 
     # If benchmark provided, calculate alpha and beta.
     alpha, beta = 0.0, 0.0
@@ -158,7 +269,14 @@ def _calculate_statistics(
     if y != 0:
         x = covariance_matrix.iloc[0].iloc[1]
         beta = x / y
-        alpha = annualized_return - (beta * 365 * benchmark_g_returns.mean())
+        alpha = annualized_return - (beta * periods_per_year * benchmark_g_returns.mean())
+
+    a_returns = performance.pct_change().dropna()
+    benchmark_a_returns = benchmark_performance.pct_change().dropna()
+    up_capture = _capture_ratio(a_returns, benchmark_a_returns, up=True)
+    down_capture = _capture_ratio(a_returns, benchmark_a_returns, up=False)
+    rolling_covariance = g_returns.rolling(rolling_window).cov(benchmark_g_returns)
+    rolling_beta = rolling_covariance / benchmark_g_returns.rolling(rolling_window).var()
 
     return ExtendedStatistics(
         total_return=total_return,
@@ -168,6 +286,21 @@ def _calculate_statistics(
         sharpe_ratio=sharpe_ratio,
         sortino_ratio=sortino_ratio,
         cagr=cagr,
+        periods_per_year=periods_per_year,
+        raw_sharpe_ratio=float(sharpe_ratio) / sqrt_periods,
+        raw_sortino_ratio=float(sortino_ratio) / sqrt_periods,
         alpha=alpha,
         beta=beta,
+        up_capture=up_capture,
+        down_capture=down_capture,
+        rolling_beta=[float(b) for b in rolling_beta.iloc[rolling_window - 1 :]],
     )
+
+
+def _capture_ratio(returns: pd.Series, benchmark_returns: pd.Series, up: bool) -> float:
+    returns, benchmark_returns = returns.align(benchmark_returns, join="inner")
+    mask = benchmark_returns > 0 if up else benchmark_returns < 0
+    benchmark_mean = benchmark_returns[mask].mean()
+    if not benchmark_mean or np.isnan(benchmark_mean):
+        return 0.0
+    return returns[mask].mean() / benchmark_mean
@@ -0,0 +1,26 @@
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import Sequence
+
+from juno import Interval, Timestamp, Timestamp_
+
+
+# Simple returns of an equity curve resampled to `interval`. Equity is sampled at candle close
+# times; the last sample within a period closes it. Returns are keyed by period start and the first
+# one is relative to `starting_value`. Periods without samples are left out.
+def period_returns(
+    starting_value: Decimal,
+    equity: Sequence[tuple[Timestamp, Decimal]],
+    interval: Interval,
+) -> list[tuple[Timestamp, Decimal]]:
+    closes: dict[Timestamp, Decimal] = {}
+    for time, value in equity:
+        closes[Timestamp_.floor(time - 1, interval)] = value
+
+    result = []
+    previous = starting_value
+    for time, value in closes.items():
+        result.append((time, Decimal("0.0") if previous == 0 else value / previous - 1))
+        previous = value
+    return result
@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+from decimal import Decimal
+from random import Random
+from typing import Sequence
+
+from juno import Candle
+
+
+# Rebuilds candles from their returns relative to the previous close with the returns shuffled in
+# blocks of `block_size`. Shuffling in blocks rather than individually preserves short term
+# autocorrelation, such as volatility clustering, while destroying any longer term structure a
+# strategy could exploit. Times, volumes and the first candle are kept as is.
+def block_shuffle(candles: Sequence[Candle], block_size: int, random: Random) -> list[Candle]:
+    if block_size < 1:
+        raise ValueError("Block size should be positive")
+    if len(candles) < 2:
+        return list(candles)
+    if any(c.close == 0 for c in candles[:-1]):
+        raise ValueError("Cannot shuffle returns of candles with a zero close")
+
+    returns = [
+        (c.open / p.close, c.high / p.close, c.low / p.close, c.close / p.close)
+        for p, c in zip(candles, candles[1:])
+    ]
+    blocks = [returns[i : i + block_size] for i in range(0, len(returns), block_size)]
+    random.shuffle(blocks)
+    shuffled = [r for block in blocks for r in block]
+
+    result = [candles[0]]
+    for candle, (open, high, low, close) in zip(candles[1:], shuffled):
+        previous_close = result[-1].close
+        result.append(
+            candle._replace(
+                open=previous_close * open,
+                high=previous_close * high,
+                low=previous_close * low,
+                close=previous_close * close,
+            )
+        )
+    return result
+
+
+# Share of the null distribution at least as good as the realized value. The realized value itself
+# is counted in so that the p-value is never zero.
+def p_value(realized: Decimal, distribution: Sequence[Decimal]) -> Decimal:
+    return Decimal(1 + sum(1 for v in distribution if v >= realized)) / (1 + len(distribution))
@@ -1,7 +1,7 @@
 import logging
 from itertools import chain
 
-from juno import Asset, Interval, Interval_, Symbol_, Timestamp_
+from juno import Asset, Fees, Interval, Interval_, Symbol, Symbol_, Timestamp_
 from juno.components import Prices
 from juno.contextlib import AsyncContextManager
 from juno.trading import TradingSummary
@@ -22,6 +22,8 @@ class Statistician(AsyncContextManager):
         benchmark_asset: Asset = "btc",
         target_asset: Asset = "usdt",
         interval: Interval = Interval_.DAY,
+        symbols: list[Symbol] = [],  # Benchmarked with buy-and-hold.
+        fees: dict[Symbol, Fees] = {},  # Used for benchmark buy-and-hold purchases.
     ) -> Statistics:
         _log.info(f"calculating benchmark and portfolio statistics ({target_asset})")
 
@@ -29,6 +31,7 @@ class Statistician(AsyncContextManager):
         assets = chain(
             summary.starting_assets.keys(),
             Symbol_.iter_assets(p.symbol for p in summary.positions),
+            Symbol_.iter_assets(symbols),
             [benchmark_asset],
         )
         prices = await self._prices.map_asset_prices(
@@ -45,4 +48,6 @@ class Statistician(AsyncContextManager):
             asset_prices=prices,
             interval=interval,
             benchmark_asset=benchmark_asset,
+            symbols=symbols,
+            fees=fees,
         )
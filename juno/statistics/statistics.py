@@ -1,19 +1,23 @@
 from __future__ import annotations
 
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from decimal import Decimal
+from typing import Optional
 
-from juno import Interval, Interval_
+from juno import Fees, Interval, Interval_, Symbol
 from juno.trading import TradingSummary
 
+from .breakdown import Breakdowns
 from .core import CoreStatistics
-from .extended import ExtendedStatistics
+from .extended import BenchmarkStatistics, ExtendedStatistics
 
 
 @dataclass(frozen=True)
 class Statistics:
     core: CoreStatistics
     extended: ExtendedStatistics
+    benchmarks: dict[Symbol, BenchmarkStatistics] = field(default_factory=dict)
+    breakdowns: Breakdowns = field(default_factory=Breakdowns)
 
     @staticmethod
     def compose(
@@ -21,6 +25,9 @@ class Statistics:
         asset_prices: dict[str, list[Decimal]],
         interval: Interval = Interval_.DAY,
         benchmark_asset: str = "btc",
+        symbols: list[Symbol] = [],  # Benchmarked with buy-and-hold.
+        fees: dict[Symbol, Fees] = {},
+        periods_per_year: Optional[int] = None,  # None means derived from interval.
     ) -> Statistics:
         return Statistics(
             core=CoreStatistics.compose(summary),
@@ -29,5 +36,14 @@ class Statistics:
                 asset_prices=asset_prices,
                 interval=interval,
                 benchmark_asset=benchmark_asset,
+                periods_per_year=periods_per_year,
             ),
+            benchmarks=BenchmarkStatistics.compose(
+                summary=summary,
+                asset_prices=asset_prices,
+                symbols=symbols,
+                interval=interval,
+                fees=fees,
+            ),
+            breakdowns=Breakdowns.compose(summary),
         )
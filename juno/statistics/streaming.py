@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+import math
+from decimal import Decimal
+from typing import Optional
+
+from juno.math import Welford
+
+from .extended import ExtendedStatistics
+
+
+# Online counterpart of `ExtendedStatistics`. Instead of requiring full price arrays up front, it
+# is fed the mark-to-market portfolio value once per period, keeping memory usage constant
+# regardless of the length of the trading range. Alpha and beta are not computed because there is
+# no benchmark.
+class StreamingStatistics:
+    _periods_per_year: int
+    _first: Optional[float] = None
+    _last: Optional[float] = None
+    _count: int = 0
+
+    # Log returns.
+    _returns: Welford
+    _neg_returns: Welford
+
+    _peak: float = 0.0
+    _max_drawdown: float = 0.0
+
+    def __init__(self, periods_per_year: int = 365) -> None:
+        assert periods_per_year > 0
+        self._periods_per_year = periods_per_year
+        self._returns = Welford()
+        self._neg_returns = Welford()
+
+    @property
+    def max_drawdown(self) -> float:
+        return self._max_drawdown
+
+    def update(self, value: Decimal) -> None:
+        fvalue = float(value)
+
+        if self._last is not None and self._last > 0 and fvalue > 0:
+            g_return = math.log(fvalue / self._last)
+            self._returns.update(g_return)
+            if g_return < 0:
+                self._neg_returns.update(g_return)
+
+        if self._first is None:
+            self._first = fvalue
+        self._last = fvalue
+        self._count += 1
+
+        self._peak = max(self._peak, fvalue)
+        if self._peak > 0:
+            self._max_drawdown = max(self._max_drawdown, 1 - fvalue / self._peak)
+
+    def compose(self) -> ExtendedStatistics:
+        if self._first is None or self._last is None or self._first == 0:
+            return ExtendedStatistics(
+                total_return=0.0,
+                annualized_return=0.0,
+                annualized_volatility=0.0,
+                annualized_downside_risk=0.0,
+                sharpe_ratio=0.0,
+                sortino_ratio=0.0,
+                cagr=0.0,
+                periods_per_year=self._periods_per_year,
+            )
+
+        sqrt_periods = math.sqrt(self._periods_per_year)
+        annualized_return = self._periods_per_year * self._returns.mean
+        annualized_volatility = sqrt_periods * math.sqrt(self._returns.variance)
+        annualized_downside_risk = sqrt_periods * math.sqrt(self._neg_returns.variance)
+        sharpe_ratio = annualized_return / annualized_volatility if annualized_volatility else 0.0
+        sortino_ratio = (
+            annualized_return / annualized_downside_risk if annualized_downside_risk else 0.0
+        )
+        return ExtendedStatistics(
+            total_return=self._last / self._first - 1,
+            annualized_return=annualized_return,
+            annualized_volatility=annualized_volatility,
+            annualized_downside_risk=annualized_downside_risk,
+            sharpe_ratio=sharpe_ratio,
+            sortino_ratio=sortino_ratio,
+            cagr=(self._last / self._first) ** (self._periods_per_year / self._count) - 1,
+            periods_per_year=self._periods_per_year,
+            raw_sharpe_ratio=sharpe_ratio / sqrt_periods,
+            raw_sortino_ratio=sortino_ratio / sqrt_periods,
+        )
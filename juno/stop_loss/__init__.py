@@ -1,15 +1,19 @@
 from .basic import Basic
 from .basic_plus_trailing import BasicPlusTrailing
+from .duration import Duration
 from .legacy import Legacy
 from .noop import Noop
+from .parabolic_sar import ParabolicSar
 from .stop_loss import StopLoss
 from .trailing import Trailing
 
 __all__ = [
     "Basic",
     "BasicPlusTrailing",
+    "Duration",
     "Legacy",
     "Noop",
+    "ParabolicSar",
     "StopLoss",
     "Trailing",
 ]
@@ -6,30 +6,66 @@ from juno import Candle
 from .stop_loss import StopLoss
 
 
+# With `use_close` disabled, the stop triggers when the low (upside) or high (downside) of a candle
+# breaches the threshold. The position is then assumed to be closed at the threshold price, or at
+# the open if the candle gapped past it.
 class Basic(StopLoss):
     _up_threshold_factor: Decimal
     _down_threshold_factor: Decimal
+    _use_close: bool
     _close_at_position: Decimal = Decimal("0.0")
-    _close: Decimal = Decimal("0.0")
+    _candle: Candle = Candle()
 
-    def __init__(self, up_threshold: Decimal, down_threshold: Optional[Decimal] = None) -> None:
+    def __init__(
+        self,
+        up_threshold: Decimal,
+        down_threshold: Optional[Decimal] = None,
+        use_close: bool = True,
+    ) -> None:
         if down_threshold is None:
             down_threshold = up_threshold
         assert 0 <= up_threshold <= 1
         assert 0 <= down_threshold <= 1
         self._up_threshold_factor = 1 - up_threshold
         self._down_threshold_factor = 1 + down_threshold
+        self._use_close = use_close
 
     @property
     def upside_hit(self) -> bool:
-        return self._close <= self._close_at_position * self._up_threshold_factor
+        low = self._candle.close if self._use_close else self._candle.low
+        return low <= self._up_level
 
     @property
     def downside_hit(self) -> bool:
-        return self._close >= self._close_at_position * self._down_threshold_factor
+        high = self._candle.close if self._use_close else self._candle.high
+        return high >= self._down_level
+
+    @property
+    def upside_level(self) -> Decimal:
+        return self._up_level
+
+    @property
+    def downside_level(self) -> Decimal:
+        return self._down_level
+
+    @property
+    def upside_exit_price(self) -> Optional[Decimal]:
+        return None if self._use_close else min(self._candle.open, self._up_level)
+
+    @property
+    def downside_exit_price(self) -> Optional[Decimal]:
+        return None if self._use_close else max(self._candle.open, self._down_level)
+
+    @property
+    def _up_level(self) -> Decimal:
+        return self._close_at_position * self._up_threshold_factor
+
+    @property
+    def _down_level(self) -> Decimal:
+        return self._close_at_position * self._down_threshold_factor
 
     def clear(self, candle: Candle) -> None:
         self._close_at_position = candle.close
 
     def update(self, candle: Candle) -> None:
-        self._close = candle.close
+        self._candle = candle
@@ -18,14 +18,17 @@ class BasicPlusTrailing(StopLoss):
         up_trailing_threshold: Decimal,
         down_threshold: Optional[Decimal] = None,
         down_trailing_threshold: Optional[Decimal] = None,
+        use_close: bool = True,
     ) -> None:
         self._basic = Basic(
             up_threshold=up_threshold,
             down_threshold=down_threshold,
+            use_close=use_close,
         )
         self._trailing = Trailing(
             up_threshold=up_trailing_threshold,
             down_threshold=down_trailing_threshold,
+            use_close=use_close,
         )
 
     @property
@@ -36,6 +39,26 @@ class BasicPlusTrailing(StopLoss):
     def downside_hit(self) -> bool:
         return self._basic.downside_hit or self._trailing.downside_hit
 
+    # The tighter of the two stops is hit first.
+    @property
+    def upside_level(self) -> Optional[Decimal]:
+        return max(self._basic.upside_level, self._trailing.upside_level)
+
+    @property
+    def downside_level(self) -> Optional[Decimal]:
+        return min(self._basic.downside_level, self._trailing.downside_level)
+
+    # When both stops are hit within a candle, the one closer to the open is reached first.
+    @property
+    def upside_exit_price(self) -> Optional[Decimal]:
+        prices = [s.upside_exit_price for s in (self._basic, self._trailing) if s.upside_hit]
+        return max((p for p in prices if p is not None), default=None)
+
+    @property
+    def downside_exit_price(self) -> Optional[Decimal]:
+        prices = [s.downside_exit_price for s in (self._basic, self._trailing) if s.downside_hit]
+        return min((p for p in prices if p is not None), default=None)
+
     def clear(self, candle: Candle) -> None:
         self._basic.clear(candle)
         self._trailing.clear(candle)
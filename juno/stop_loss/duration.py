@@ -0,0 +1,28 @@
+from juno import Candle
+
+from .stop_loss import StopLoss
+
+
+# Time stop. Hit once the position has been held for `intervals` main candles, regardless of price.
+# Caps exposure for strategies without a price based exit, such as mean reversion.
+class Duration(StopLoss):
+    _intervals: int
+    _held: int = 0
+
+    def __init__(self, intervals: int) -> None:
+        assert intervals > 0
+        self._intervals = intervals
+
+    @property
+    def upside_hit(self) -> bool:
+        return self._held >= self._intervals
+
+    @property
+    def downside_hit(self) -> bool:
+        return self._held >= self._intervals
+
+    def clear(self, candle: Candle) -> None:
+        self._held = 0
+
+    def update(self, candle: Candle) -> None:
+        self._held += 1
@@ -0,0 +1,40 @@
+from decimal import Decimal
+from typing import Optional
+
+from juno import Candle, indicators
+
+from .stop_loss import StopLoss
+
+
+# Trails the position with a parabolic SAR started at the candle the position was opened at. The
+# stop is hit when the SAR reverses against the position.
+class ParabolicSar(StopLoss):
+    _step: Decimal
+    _max: Decimal
+    _long_psar: Optional[indicators.ParabolicSar] = None
+    _short_psar: Optional[indicators.ParabolicSar] = None
+
+    def __init__(self, step: Decimal = Decimal("0.02"), max_: Decimal = Decimal("0.2")) -> None:
+        assert 0 < step <= max_
+        self._step = step
+        self._max = max_
+
+    @property
+    def upside_hit(self) -> bool:
+        return self._long_psar is not None and not self._long_psar.long
+
+    @property
+    def downside_hit(self) -> bool:
+        return self._short_psar is not None and self._short_psar.long
+
+    def clear(self, candle: Candle) -> None:
+        self._long_psar = indicators.ParabolicSar(self._step, self._max, long=True)
+        self._long_psar.update(candle.high, candle.low)
+        self._short_psar = indicators.ParabolicSar(self._step, self._max, long=False)
+        self._short_psar.update(candle.high, candle.low)
+
+    def update(self, candle: Candle) -> None:
+        if self._long_psar is not None:
+            self._long_psar.update(candle.high, candle.low)
+        if self._short_psar is not None:
+            self._short_psar.update(candle.high, candle.low)
@@ -1,4 +1,6 @@
 from abc import ABC, abstractmethod
+from decimal import Decimal
+from typing import Optional
 
 from juno import Candle
 
@@ -14,6 +16,25 @@ class StopLoss(ABC):
     def downside_hit(self) -> bool:
         pass
 
+    # Price below (upside) or above (downside) which the stop is hit. None if the stop is not price
+    # based.
+    @property
+    def upside_level(self) -> Optional[Decimal]:
+        return None
+
+    @property
+    def downside_level(self) -> Optional[Decimal]:
+        return None
+
+    # Price at which a hit stop loss is filled when backtesting. None means the candle close.
+    @property
+    def upside_exit_price(self) -> Optional[Decimal]:
+        return None
+
+    @property
+    def downside_exit_price(self) -> Optional[Decimal]:
+        return None
+
     @abstractmethod
     def clear(self, candle: Candle) -> None:
         pass
@@ -6,34 +6,94 @@ from juno import Candle
 from .stop_loss import StopLoss
 
 
+# With `use_close` disabled, the stop trails the highest high (upside) or lowest low (downside) and
+# triggers when the low or high of a candle breaches it. The extremes of a candle are taken into
+# account before checking for a breach, as the order of prices within a candle is unknown. The
+# position is assumed to be closed at the stop price, or at the open if the candle gapped past it.
+#
+# With `break_even_threshold` set, the stop is moved to the entry price once the position has
+# gained the threshold, so that a winning position is not closed at a loss.
 class Trailing(StopLoss):
     _up_threshold_factor: Decimal
     _down_threshold_factor: Decimal
-    _highest_close_since_position = Decimal("0.0")
-    _lowest_close_since_position = Decimal("Inf")
-    _close: Decimal = Decimal("0.0")
+    _use_close: bool
+    _break_even_threshold: Optional[Decimal]
+    _entry: Decimal = Decimal("0.0")
+    _highest_since_position = Decimal("0.0")
+    _lowest_since_position = Decimal("Inf")
+    _candle: Candle = Candle()
 
-    def __init__(self, up_threshold: Decimal, down_threshold: Optional[Decimal] = None) -> None:
+    def __init__(
+        self,
+        up_threshold: Decimal,
+        down_threshold: Optional[Decimal] = None,
+        use_close: bool = True,
+        break_even_threshold: Optional[Decimal] = None,
+    ) -> None:
         if down_threshold is None:
             down_threshold = up_threshold
         assert 0 <= up_threshold <= 1
         assert 0 <= down_threshold <= 1
+        assert break_even_threshold is None or 0 <= break_even_threshold <= 1
         self._up_threshold_factor = 1 - up_threshold
         self._down_threshold_factor = 1 + down_threshold
+        self._use_close = use_close
+        self._break_even_threshold = break_even_threshold
 
     @property
     def upside_hit(self) -> bool:
-        return self._close <= self._highest_close_since_position * self._up_threshold_factor
+        low = self._candle.close if self._use_close else self._candle.low
+        return low <= self._up_level
 
     @property
     def downside_hit(self) -> bool:
-        return self._close >= self._lowest_close_since_position * self._down_threshold_factor
+        high = self._candle.close if self._use_close else self._candle.high
+        return high >= self._down_level
+
+    @property
+    def upside_level(self) -> Decimal:
+        return self._up_level
+
+    @property
+    def downside_level(self) -> Decimal:
+        return self._down_level
+
+    @property
+    def upside_exit_price(self) -> Optional[Decimal]:
+        return None if self._use_close else min(self._candle.open, self._up_level)
+
+    @property
+    def downside_exit_price(self) -> Optional[Decimal]:
+        return None if self._use_close else max(self._candle.open, self._down_level)
+
+    @property
+    def _up_level(self) -> Decimal:
+        level = self._highest_since_position * self._up_threshold_factor
+        if (
+            self._break_even_threshold is not None
+            and self._highest_since_position >= self._entry * (1 + self._break_even_threshold)
+        ):
+            level = max(level, self._entry)
+        return level
+
+    @property
+    def _down_level(self) -> Decimal:
+        level = self._lowest_since_position * self._down_threshold_factor
+        if (
+            self._break_even_threshold is not None
+            and self._lowest_since_position <= self._entry * (1 - self._break_even_threshold)
+        ):
+            level = min(level, self._entry)
+        return level
 
     def clear(self, candle: Candle) -> None:
-        self._highest_close_since_position = candle.close
-        self._lowest_close_since_position = candle.close
+        self._entry = candle.close
+        self._highest_since_position = candle.close
+        self._lowest_since_position = candle.close
 
     def update(self, candle: Candle) -> None:
-        self._close = candle.close
-        self._highest_close_since_position = max(self._highest_close_since_position, candle.close)
-        self._lowest_close_since_position = min(self._lowest_close_since_position, candle.close)
+        self._candle = candle
+        high = candle.close if self._use_close else candle.high
+        low = candle.close if self._use_close else candle.low
+        self._highest_since_position = max(self._highest_since_position, high)
+        self._lowest_since_position = min(self._lowest_since_position, low)
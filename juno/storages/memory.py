@@ -7,14 +7,15 @@ from threading import Lock
 from types import TracebackType
 from typing import Iterator, Optional
 
+from .migrations import Migration
 from .sqlite import SQLite
 
 
 class Memory(SQLite):
     """In-memory data storage. Uses SQLite's memory mode for implementation."""
 
-    def __init__(self) -> None:
-        super().__init__()
+    def __init__(self, migrations: Optional[list[Migration]] = None) -> None:
+        super().__init__(migrations=migrations)
         self._conns: dict[str, _ConnectionContext] = defaultdict(_ConnectionContext)
 
     async def __aenter__(self) -> Memory:
@@ -0,0 +1,65 @@
+import logging
+import sqlite3
+from dataclasses import dataclass
+from typing import Callable, Sequence
+
+from juno import Timestamp_
+
+_log = logging.getLogger(__name__)
+
+
+# An in-place schema change for existing SQLite shards. Prefer a migration over bumping the storage
+# schema version when existing data can be kept, as a version bump starts over with empty shards.
+@dataclass(frozen=True)
+class Migration:
+    version: int
+    description: str
+    apply: Callable[[sqlite3.Connection], None]
+
+
+# Append new migrations with an incremented version. Never modify or remove applied ones.
+MIGRATIONS: list[Migration] = []
+
+_TABLE = "migration"
+
+
+def validate(migrations: Sequence[Migration]) -> None:
+    versions = [m.version for m in migrations]
+    if any(v < 1 for v in versions) or versions != sorted(set(versions)):
+        raise ValueError(f"Migration versions must be positive, unique and ascending: {versions}")
+
+
+def latest_version(migrations: Sequence[Migration]) -> int:
+    return migrations[-1].version if len(migrations) > 0 else 0
+
+
+def migrate(conn: sqlite3.Connection, shard: str, migrations: Sequence[Migration]) -> int:
+    """Applies pending migrations to the shard. Returns the shard's version after migrating."""
+    conn.execute(
+        f"CREATE TABLE IF NOT EXISTS {_TABLE} "
+        "(version INTEGER PRIMARY KEY, description TEXT NOT NULL, time INTEGER NOT NULL)"
+    )
+    conn.commit()
+
+    # Take the write lock up front so that concurrent connections do not apply the same migration.
+    conn.execute("BEGIN IMMEDIATE")
+    try:
+        applied = {row[0] for row in conn.execute(f"SELECT version FROM {_TABLE}")}
+        for migration in migrations:
+            if migration.version in applied:
+                continue
+            _log.info(
+                f"applying migration {migration.version} ({migration.description}) to shard "
+                f"{shard}"
+            )
+            migration.apply(conn)
+            conn.execute(
+                f"INSERT INTO {_TABLE} VALUES (?, ?, ?)",
+                [migration.version, migration.description, Timestamp_.now()],
+            )
+            applied.add(migration.version)
+        conn.commit()
+    except Exception:
+        conn.rollback()
+        raise
+    return max(applied, default=0)
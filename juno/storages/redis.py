@@ -0,0 +1,207 @@
+from __future__ import annotations
+
+import logging
+from types import TracebackType
+from typing import Any, AsyncIterable, Optional, TypeVar
+
+from juno import Interval, Timestamp, Timestamp_, json, serialization
+from juno.itertools import generate_missing_spans
+
+from .storage import Storage
+
+_log = logging.getLogger(__name__)
+
+# Version should be incremented every time the layout of cached data changes.
+_VERSION = "v1"
+
+T = TypeVar("T")
+
+
+class Redis(Storage):
+    """Shared cache layer in front of another storage. Intended for deployments where several
+    instances would otherwise each load the same candles and exchange info from the exchange.
+
+    Writes go to both Redis and the backing storage. Reads combine both, preferring Redis. Cached
+    time series spans expire after `time_series_ttl` and key-value items after `item_ttl`. None
+    means cached data never expires.
+
+    Cache hits and misses are counted per time series item and key-value item read.
+    """
+
+    hits: int = 0
+    misses: int = 0
+
+    def __init__(
+        self,
+        storage: Storage,
+        url: str = "redis://localhost:6379",
+        time_series_ttl: Optional[Interval] = None,
+        item_ttl: Optional[Interval] = None,
+        client: Optional[Any] = None,  # `redis.asyncio.Redis` compatible client.
+    ) -> None:
+        self._storage = storage
+        self._url = url
+        self._time_series_ttl = time_series_ttl
+        self._item_ttl = item_ttl
+        self._client = client
+        self._owns_client = client is None
+
+    async def __aenter__(self) -> Redis:
+        if self._client is None:
+            import redis.asyncio
+
+            self._client = redis.asyncio.from_url(self._url)
+        await self._storage.__aenter__()
+        return self
+
+    async def __aexit__(
+        self,
+        exc_type: Optional[type[BaseException]],
+        exc: Optional[BaseException],
+        tb: Optional[TracebackType],
+    ) -> None:
+        await self._storage.__aexit__(exc_type, exc, tb)
+        if self._owns_client and self._client is not None:
+            await self._client.aclose()
+            self._client = None
+
+    @property
+    def version(self) -> Optional[str]:
+        return self._storage.version
+
+    @property
+    def client(self) -> Any:
+        assert self._client
+        return self._client
+
+    async def stream_time_series_spans(
+        self, shard: str, key: str, start: Timestamp = 0, end: Timestamp = Timestamp_.MAX_TIME
+    ) -> AsyncIterable[tuple[Timestamp, Timestamp]]:
+        spans = await self._get_cached_spans(shard, key, start, end)
+        spans.extend(
+            [s async for s in self._storage.stream_time_series_spans(shard, key, start, end)]
+        )
+        for span_start, span_end in _merge_spans(spans):
+            yield max(span_start, start), min(span_end, end)
+
+    async def stream_time_series(
+        self,
+        shard: str,
+        key: str,
+        type_: type[T],
+        start: Timestamp = 0,
+        end: Timestamp = Timestamp_.MAX_TIME,
+    ) -> AsyncIterable[T]:
+        items: dict[Timestamp, T] = {}
+        cached_spans = []
+        for span_start, span_end in await self._get_cached_spans(shard, key, start, end):
+            value = await self.client.get(self._span_key(shard, key, span_start, span_end))
+            if value is None:  # Expired in between.
+                continue
+            cached_spans.append((max(span_start, start), min(span_end, end)))
+            for raw_item in json.loads(value):
+                item = serialization.raw.deserialize(raw_item, type_)
+                time = item.time  # type: ignore
+                if time >= start and time < end:
+                    items[time] = item
+
+        cached = len(items)
+        # Only spans not covered by the cache are read from the backing storage.
+        for missing_start, missing_end in generate_missing_spans(
+            start, end, _merge_spans(cached_spans)
+        ):
+            async for item in self._storage.stream_time_series(
+                shard, key, type_, missing_start, missing_end
+            ):
+                items.setdefault(item.time, item)  # type: ignore
+        self.hits += cached
+        self.misses += len(items) - cached
+        _log.info(
+            f"streaming {len(items)} item(s) ({cached} cached) between "
+            f"{Timestamp_.format_span(start, end)} from shard {shard} {key}"
+        )
+
+        for time in sorted(items.keys()):
+            yield items[time]
+
+    async def store_time_series_and_span(
+        self, shard: str, key: str, items: list[Any], start: Timestamp, end: Timestamp
+    ) -> None:
+        await self._storage.store_time_series_and_span(shard, key, items, start, end)
+
+        _log.info(
+            f"caching {len(items)} item(s) between {Timestamp_.format_span(start, end)} for "
+            f"shard {shard} {key}"
+        )
+        await self.client.set(
+            self._span_key(shard, key, start, end),
+            json.dumps([serialization.raw.serialize(i) for i in items]),
+            px=self._time_series_ttl,
+        )
+        await self.client.zadd(self._spans_key(shard, key), {f"{start}:{end}": start})
+
+    async def get(self, shard: str, key: str, type_: type[T]) -> Optional[T]:
+        value = await self.client.get(self._item_key(shard, key))
+        if value is not None:
+            _log.info(f"getting {key} from cache for shard {shard}")
+            self.hits += 1
+            return serialization.raw.deserialize(json.loads(value), type_)
+
+        self.misses += 1
+        item = await self._storage.get(shard, key, type_)
+        if item is not None:
+            await self._set_cached(shard, key, item)
+        return item
+
+    async def set(self, shard: str, key: str, item: T) -> None:
+        await self._storage.set(shard, key, item)
+        await self._set_cached(shard, key, item)
+
+    async def _set_cached(self, shard: str, key: str, item: Any) -> None:
+        await self.client.set(
+            self._item_key(shard, key),
+            json.dumps(serialization.raw.serialize(item)),
+            px=self._item_ttl,
+        )
+
+    async def _get_cached_spans(
+        self, shard: str, key: str, start: Timestamp, end: Timestamp
+    ) -> list[tuple[Timestamp, Timestamp]]:
+        # Spans are indexed by start in a sorted set. The set itself does not expire, so members
+        # whose data has expired are pruned here.
+        spans_key = self._spans_key(shard, key)
+        members = await self.client.zrangebyscore(spans_key, "-inf", f"({end}")
+        spans = []
+        expired = []
+        for member in members:
+            member = member.decode("ascii") if isinstance(member, bytes) else member
+            span_start, span_end = map(int, member.split(":"))
+            if span_end <= start:
+                continue
+            if await self.client.exists(self._span_key(shard, key, span_start, span_end)):
+                spans.append((span_start, span_end))
+            else:
+                expired.append(member)
+        if len(expired) > 0:
+            await self.client.zrem(spans_key, *expired)
+        return spans
+
+    def _span_key(self, shard: str, key: str, start: Timestamp, end: Timestamp) -> str:
+        return f"juno:{_VERSION}:{shard}:{key}:span:{start}:{end}"
+
+    def _spans_key(self, shard: str, key: str) -> str:
+        return f"juno:{_VERSION}:{shard}:{key}:spans"
+
+    def _item_key(self, shard: str, key: str) -> str:
+        return f"juno:{_VERSION}:{shard}:item:{key}"
+
+
+def _merge_spans(spans: list[tuple[Timestamp, Timestamp]]) -> list[tuple[Timestamp, Timestamp]]:
+    # Cached and stored spans may overlap, unlike spans within a single storage.
+    merged: list[tuple[Timestamp, Timestamp]] = []
+    for span_start, span_end in sorted(spans):
+        if len(merged) > 0 and span_start <= merged[-1][1]:
+            merged[-1] = (merged[-1][0], max(merged[-1][1], span_end))
+        else:
+            merged.append((span_start, span_end))
+    return merged
@@ -2,12 +2,13 @@ import asyncio
 import logging
 import sqlite3
 from collections import defaultdict
-from contextlib import closing
+from contextlib import closing, contextmanager
 from decimal import Decimal
 from typing import (
     Any,
     AsyncIterable,
     ContextManager,
+    Iterator,
     NamedTuple,
     Optional,
     TypeVar,
@@ -19,6 +20,7 @@ from juno import Interval, Timestamp, Timestamp_, json, serialization
 from juno.itertools import generate_missing_spans, merge_adjacent_spans
 from juno.path import home_path
 
+from .migrations import MIGRATIONS, Migration, latest_version, migrate, validate
 from .storage import Storage
 
 _log = logging.getLogger(__name__)
@@ -47,10 +49,29 @@ sqlite3.register_converter("BOOLEAN", lambda v: bool(int(v)))
 
 
 class SQLite(Storage):
-    def __init__(self, version: Optional[str] = None) -> None:
+    def __init__(
+        self, version: Optional[str] = None, migrations: Optional[list[Migration]] = None
+    ) -> None:
         self._version = _VERSION if version is None else version
+        self._migrations = MIGRATIONS if migrations is None else migrations
+        validate(self._migrations)
         self._tables: dict[Any, set[str]] = defaultdict(set)
-        _log.info(f"sqlite version: {sqlite3.sqlite_version}; schema version: {self._version}")
+        self._migrated_shards: dict[str, int] = {}
+        _log.info(
+            f"sqlite version: {sqlite3.sqlite_version}; schema version: {self._version}; "
+            f"migration version: {latest_version(self._migrations)}"
+        )
+
+    @property
+    def version(self) -> Optional[str]:
+        return f"{self._version}.{latest_version(self._migrations)}"
+
+    async def migrate(self, shard: str) -> int:
+        def inner() -> int:
+            with self._session(shard):
+                return self._migrated_shards[shard]
+
+        return await asyncio.get_running_loop().run_in_executor(None, inner)
 
     async def stream_time_series_spans(
         self, shard: str, key: str, start: Timestamp = 0, end: Timestamp = Timestamp_.MAX_TIME
@@ -60,7 +81,7 @@ class SQLite(Storage):
                 f"streaming span(s) between {Timestamp_.format_span(start, end)} from shard "
                 f"{shard} {key}"
             )
-            with self._connect(shard) as conn:
+            with self._session(shard) as conn:
                 span_key = f"{key}_{_SPAN_KEY}"
                 self._ensure_table(conn, span_key, Span)
                 return conn.execute(
@@ -85,7 +106,7 @@ class SQLite(Storage):
                 f"streaming items between {Timestamp_.format_span(start, end)} from shard {shard} "
                 f"{key}"
             )
-            with self._connect(shard) as conn:
+            with self._session(shard) as conn:
                 self._ensure_table(conn, key, type_)
                 return conn.execute(
                     f"SELECT * FROM {key} WHERE time >= ? AND time < ? ORDER BY time",
@@ -111,7 +132,7 @@ class SQLite(Storage):
 
         def inner() -> None:
             span_key = f"{key}_{_SPAN_KEY}"
-            with self._connect(shard) as conn:
+            with self._session(shard) as conn:
                 self._ensure_table(conn, span_key, Span)
                 if len(items) > 0:
                     self._ensure_table(conn, key, type_)
@@ -155,7 +176,7 @@ class SQLite(Storage):
     async def get(self, shard: str, key: str, type_: type[T]) -> Optional[T]:
         def inner() -> Optional[T]:
             _log.info(f"getting {key} from shard {shard}")
-            with self._connect(shard) as conn:
+            with self._session(shard) as conn:
                 self._ensure_table(conn, _KEY_VALUE_PAIR_KEY, KeyValuePair)
                 row = conn.execute(
                     f"SELECT * FROM {_KEY_VALUE_PAIR_KEY} WHERE key=? LIMIT 1", [key]
@@ -168,7 +189,7 @@ class SQLite(Storage):
         def inner() -> None:
             _log.info(f"setting {key} to shard {shard}")
             value = json.dumps(serialization.raw.serialize(item))
-            with self._connect(shard) as conn:
+            with self._session(shard) as conn:
                 self._ensure_table(conn, _KEY_VALUE_PAIR_KEY, KeyValuePair)
                 conn.execute(
                     f"INSERT OR REPLACE INTO {_KEY_VALUE_PAIR_KEY} VALUES (?, ?)",
@@ -183,6 +204,14 @@ class SQLite(Storage):
         _log.debug(f"opening shard {path}")
         return closing(sqlite3.connect(path, detect_types=sqlite3.PARSE_DECLTYPES))
 
+    # Opens a connection to the shard, applying pending migrations on first use.
+    @contextmanager
+    def _session(self, shard: str) -> Iterator[sqlite3.Connection]:
+        with self._connect(shard) as conn:
+            if shard not in self._migrated_shards:
+                self._migrated_shards[shard] = migrate(conn, shard, self._migrations)
+            yield conn
+
     def _ensure_table(self, conn: sqlite3.Connection, name: str, type_: type[Any]) -> None:
         tables = self._tables[conn]
         if name not in tables:
@@ -10,6 +10,11 @@ T = TypeVar("T")
 
 
 class Storage(AsyncContextManager, ABC):
+    # Schema version of the stored data, if the storage has one.
+    @property
+    def version(self) -> Optional[str]:
+        return None
+
     @abstractmethod
     async def stream_time_series_spans(
         self, shard: str, key: str, start: Timestamp, end: Timestamp
@@ -1,18 +1,30 @@
 from .adx import Adx
+from .awesome_oscillator import AwesomeOscillator
 from .bbands import BBands
 from .bmsb import Bmsb
+from .candle_pattern import CandlePattern
+from .cci import Cci
 from .chandelier_exit import ChandelierExit
 from .chandelier_exit_plus_zlsma import ChandelierExitPlusZlsma
+from .combine import Combine
+from .custom_signal import CustomSignal
 from .darvas_box import DarvasBox
+from .donchian_breakout import DonchianBreakout
 from .double_ma import DoubleMA, DoubleMAParams
 from .double_ma_2 import DoubleMA2
 from .double_ma_stoch import DoubleMAStoch
+from .export import export_params, export_pine
 from .fixed import Fixed
 from .four_week_rule import FourWeekRule, FourWeekRuleParams
+from .keltner_breakout import KeltnerBreakout
 from .macd import Macd
+from .mfi import Mfi
 from .mmi import Mmi
 from .momersion import Momersion
+from .parabolic_sar import ParabolicSar
+from .regime_filter import RegimeFilter
 from .rsi import Rsi
+from .rsi_divergence import RsiDivergence
 from .sig import Sig
 from .sig_osc import SigOsc
 from .single_ma import SingleMA, SingleMAParams
@@ -27,16 +39,24 @@ from .strategy import (
     Signal,
     Strategy,
 )
+from .trend_slope import TrendSlope
 from .triple_ma import TripleMA, TripleMAParams
+from .trix import Trix
 
 __all__ = [
     "Adx",
+    "AwesomeOscillator",
     "BBands",
     "Bmsb",
+    "CandlePattern",
+    "Cci",
     "ChandelierExit",
     "ChandelierExitPlusZlsma",
     "Changed",
+    "Combine",
+    "CustomSignal",
     "DarvasBox",
+    "DonchianBreakout",
     "DoubleMA",
     "DoubleMAParams",
     "DoubleMA2",
@@ -44,16 +64,21 @@ __all__ = [
     "Fixed",
     "FourWeekRule",
     "FourWeekRuleParams",
+    "KeltnerBreakout",
     "Macd",
     "Maturity",
     "MidTrend",
     "MidTrendPolicy",
+    "Mfi",
     "Mmi",
     "Momersion",
     "Oscillator",
     "Panna",
+    "ParabolicSar",
     "Persistence",
+    "RegimeFilter",
     "Rsi",
+    "RsiDivergence",
     "Sig",
     "SigOsc",
     "Signal",
@@ -61,6 +86,10 @@ __all__ = [
     "SingleMAParams",
     "Stoch",
     "Strategy",
+    "TrendSlope",
     "TripleMA",
     "TripleMAParams",
+    "Trix",
+    "export_params",
+    "export_pine",
 ]
@@ -0,0 +1,68 @@
+import operator
+from decimal import Decimal
+from typing import Optional
+
+from juno import Advice, Candle, CandleMeta, indicators
+from juno.constraints import Int, Pair
+
+from .strategy import Signal, Strategy
+
+
+# Twin peaks strategy. Signals a long position when the oscillator forms two troughs below the
+# zero line with the second one higher than the first, on the first rising value after the second
+# trough. Signals a short position on two peaks above the zero line with the second one lower.
+# Crossing the zero line discards previously seen troughs or peaks.
+class AwesomeOscillator(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                ("short_period", "long_period"): Pair(Int(1, 50), operator.lt, Int(2, 101)),
+            }
+        )
+
+    _ao: indicators.AwesomeOscillator
+    _advice: Advice = Advice.NONE
+    _prev: Optional[Decimal] = None
+    _prev2: Optional[Decimal] = None
+    _trough: Optional[Decimal] = None
+    _peak: Optional[Decimal] = None
+
+    def __init__(self, short_period: int = 5, long_period: int = 34) -> None:
+        self._ao = indicators.AwesomeOscillator(short_period, long_period)
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._ao.maturity
+
+    @property
+    def mature(self) -> bool:
+        return self._ao.mature
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        value = self._ao.update(candle.high, candle.low)
+
+        if not self._ao.mature:
+            return
+
+        prev, prev2 = self._prev, self._prev2
+        if prev is not None and prev2 is not None:
+            if value < 0:
+                self._peak = None
+                if prev < prev2 and value > prev:
+                    if self._trough is not None and prev > self._trough:
+                        self._advice = Advice.LONG
+                    self._trough = prev
+            elif value > 0:
+                self._trough = None
+                if prev > prev2 and value < prev:
+                    if self._peak is not None and prev < self._peak:
+                        self._advice = Advice.SHORT
+                    self._peak = prev
+
+        self._prev2 = prev
+        self._prev = value
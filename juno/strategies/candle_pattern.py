@@ -0,0 +1,124 @@
+from decimal import Decimal
+from itertools import combinations
+from typing import Optional
+
+from juno import Advice, Candle, CandleMeta
+from juno.constraints import Choice, Int, Uniform
+from juno.indicators import MA, Ema, registry
+
+from .strategy import Signal, Strategy, ma_choices
+
+_PATTERNS = ["doji", "engulfing", "hammer"]
+
+
+# Signals on classic candlestick reversal patterns:
+# - engulfing - long when a bullish candle's body engulfs the previous bearish body, short on the
+#   opposite
+# - hammer - long on a hammer after a bearish candle, short on a shooting star after a bullish
+#   candle
+# - doji - liquidate on indecision, when the body is at most `doji_ratio` of the candle range
+#
+# When `ma_period` is set, long and short advice is only taken if the close price is respectively
+# above or below the moving average.
+class CandlePattern(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "patterns": Choice(
+                    [
+                        list(c)
+                        for n in range(1, len(_PATTERNS) + 1)
+                        for c in combinations(_PATTERNS, n)
+                    ]
+                ),
+                "ma": ma_choices,
+                "ma_period": Int(0, 101),
+                "doji_ratio": Uniform(Decimal("0.01"), Decimal("0.2")),
+            }
+        )
+
+    _patterns: set[str]
+    _ma: Optional[MA] = None
+    _doji_ratio: Decimal
+    _prev: Optional[Candle] = None
+    _advice: Advice = Advice.NONE
+
+    def __init__(
+        self,
+        patterns: Optional[list[str]] = None,  # None means all patterns.
+        ma: str = Ema.__name__.lower(),
+        ma_period: int = 0,  # 0 disables confirmation.
+        doji_ratio: Decimal = Decimal("0.1"),
+    ) -> None:
+        patterns = _PATTERNS if patterns is None else patterns
+        assert len(patterns) > 0
+        assert all(p in _PATTERNS for p in patterns)
+        assert ma_period >= 0
+
+        self._patterns = set(patterns)
+        if ma_period > 0:
+            self._ma = registry.create_ma(ma, ma_period)
+        self._doji_ratio = doji_ratio
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return max(2, self._ma.maturity) if self._ma else 2
+
+    @property
+    def mature(self) -> bool:
+        return self._prev is not None and (self._ma is None or self._ma.mature)
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        if self._ma:
+            self._ma.update(candle.close)
+
+        if self.mature:
+            assert self._prev
+            advice = self._detect(self._prev, candle)
+            if (
+                advice is Advice.LIQUIDATE
+                or (advice is Advice.LONG and self._confirms(candle, long=True))
+                or (advice is Advice.SHORT and self._confirms(candle, long=False))
+            ):
+                self._advice = advice
+
+        self._prev = candle
+
+    def _detect(self, prev: Candle, candle: Candle) -> Advice:
+        body = abs(candle.close - candle.open)
+        range_ = candle.high - candle.low
+        prev_bullish = prev.close > prev.open
+        prev_bearish = prev.close < prev.open
+        bullish = candle.close > candle.open
+        bearish = candle.close < candle.open
+
+        if "engulfing" in self._patterns:
+            top, bottom = max(candle.open, candle.close), min(candle.open, candle.close)
+            engulfs = top >= max(prev.open, prev.close) and bottom <= min(prev.open, prev.close)
+            if engulfs and prev_bearish and bullish:
+                return Advice.LONG
+            if engulfs and prev_bullish and bearish:
+                return Advice.SHORT
+
+        if "hammer" in self._patterns and body > 0:
+            upper_shadow = candle.high - max(candle.open, candle.close)
+            lower_shadow = min(candle.open, candle.close) - candle.low
+            if prev_bearish and lower_shadow >= 2 * body and upper_shadow <= body:
+                return Advice.LONG
+            if prev_bullish and upper_shadow >= 2 * body and lower_shadow <= body:
+                return Advice.SHORT
+
+        if "doji" in self._patterns and range_ > 0 and body <= range_ * self._doji_ratio:
+            return Advice.LIQUIDATE
+
+        return Advice.NONE
+
+    def _confirms(self, candle: Candle, long: bool) -> bool:
+        if self._ma is None:
+            return True
+        return candle.close > self._ma.value if long else candle.close < self._ma.value
@@ -0,0 +1,54 @@
+from decimal import Decimal
+
+from juno import Candle, CandleMeta, indicators
+from juno.constraints import Int, Uniform
+
+from .strategy import Oscillator, Strategy
+
+
+class Cci(Oscillator):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "period": Int(2, 101),
+                "up_threshold": Uniform(Decimal("0.0"), Decimal("300.0")),
+                "down_threshold": Uniform(Decimal("-300.0"), Decimal("0.0")),
+            }
+        )
+
+    indicator: indicators.Cci
+    _up_threshold: Decimal
+    _down_threshold: Decimal
+
+    def __init__(
+        self,
+        period: int = 20,
+        up_threshold: Decimal = Decimal("100.0"),
+        down_threshold: Decimal = Decimal("-100.0"),
+    ) -> None:
+        assert period > 1
+        assert up_threshold >= down_threshold
+
+        self.indicator = indicators.Cci(period)
+        self._up_threshold = up_threshold
+        self._down_threshold = down_threshold
+
+    @property
+    def maturity(self) -> int:
+        return self.indicator.maturity
+
+    @property
+    def mature(self) -> bool:
+        return self.indicator.mature
+
+    @property
+    def overbought(self) -> bool:
+        return self.indicator.mature and self.indicator.value >= self._up_threshold
+
+    @property
+    def oversold(self) -> bool:
+        return self.indicator.mature and self.indicator.value < self._down_threshold
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        self.indicator.update(candle.high, candle.low, candle.close)
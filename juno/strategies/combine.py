@@ -0,0 +1,62 @@
+from collections import Counter
+from decimal import Decimal
+from typing import Any
+
+from juno import Advice, Candle, CandleMeta, strategies
+from juno.config import init_module_instance
+
+from .strategy import Signal
+
+
+# Combines advices of multiple signals into one. Together with other composite signals (`Sig`,
+# `SigOsc`, `RegimeFilter`), allows describing an arbitrarily nested strategy tree in config.
+#
+# Modes:
+# - 'unanimous' - all signals must agree; liquidate if they disagree
+# - 'majority' - more than half of the signals must agree; liquidate otherwise
+class Combine(Signal):
+    _advice: Advice = Advice.NONE
+    _sigs: list[Signal]
+    _mode: str
+    _t: int = 0
+    _t1: int
+
+    def __init__(self, sigs: list[dict[str, Any]], mode: str = "unanimous") -> None:
+        assert len(sigs) > 0
+        assert mode in {"unanimous", "majority"}
+
+        self._sigs = [init_module_instance(strategies, sig) for sig in sigs]
+        self._mode = mode
+        self._t1 = max(sig.maturity for sig in self._sigs)
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    @property
+    def snapshot(self) -> dict[str, Decimal]:
+        return {
+            f"sigs.{i}.{k}": v for i, sig in enumerate(self._sigs) for k, v in sig.snapshot.items()
+        }
+
+    def update(self, candle: Candle, meta: CandleMeta) -> None:
+        self._t = min(self._t + 1, self._t1)
+
+        for sig in self._sigs:
+            sig.update(candle, meta)
+
+        if all(sig.mature for sig in self._sigs):
+            advices = [sig.advice for sig in self._sigs]
+            if self._mode == "unanimous":
+                self._advice = Advice.combine(*advices)
+            else:
+                advice, count = Counter(advices).most_common(1)[0]
+                self._advice = advice if count > len(advices) / 2 else Advice.LIQUIDATE
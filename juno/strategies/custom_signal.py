@@ -0,0 +1,53 @@
+from juno import Advice, Candle, CandleMeta
+from juno.constraints import Constant
+
+from . import dsl
+from .strategy import Signal, Strategy
+
+
+# Signals the advice an expression evaluates to. See `dsl` module for the expression syntax.
+# Example: `ema(close, 12) > ema(close, 26) ? long : short`
+class CustomSignal(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "expression": Constant("close > sma(close, 50) ? long : liquidate"),
+            }
+        )
+
+    _expression: dsl.Node
+    _advice: Advice = Advice.NONE
+    _t: int = 0
+    _t1: int
+
+    def __init__(
+        self,
+        expression: str = "close > sma(close, 50) ? long : liquidate",
+    ) -> None:
+        self._expression = dsl.parse(expression)
+        self._t1 = self._expression.maturity
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        self._t = min(self._t + 1, self._t1)
+
+        value = self._expression.update(candle)
+        if value is None:
+            self._advice = Advice.NONE
+            return
+
+        if not isinstance(value, Advice):
+            raise ValueError(f"Expression must evaluate to an advice; got {value}")
+        self._advice = value
@@ -0,0 +1,49 @@
+from juno import Advice, Candle, CandleMeta, indicators
+from juno.constraints import Int
+
+from .strategy import Signal, Strategy
+
+
+# Signals a long position when a candle close price breaks above the upper band of the previous
+# Donchian channel and a short position when it breaks below the lower band.
+class DonchianBreakout(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "period": Int(2, 101),
+            }
+        )
+
+    _donchian: indicators.DonchianChannel
+    _advice: Advice = Advice.NONE
+    _t: int = 0
+    _t1: int
+
+    def __init__(self, period: int = 20) -> None:
+        self._donchian = indicators.DonchianChannel(period)
+        self._t1 = period + 1
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        self._t = min(self._t + 1, self._t1)
+
+        # Compare against the channel of the previous candles, excluding the current one.
+        if self._donchian.mature:
+            if candle.close > self._donchian.upper:
+                self._advice = Advice.LONG
+            elif candle.close < self._donchian.lower:
+                self._advice = Advice.SHORT
+
+        self._donchian.update(candle.high, candle.low)
@@ -2,11 +2,12 @@ from __future__ import annotations
 
 import operator
 from dataclasses import dataclass
+from decimal import Decimal
 
-from juno import Advice, Candle, CandleMeta, indicators
+from juno import Advice, Candle, CandleMeta
 from juno.constraints import Int, Pair
-from juno.indicators import MA, Ema
-from juno.inspect import Constructor, get_module_type
+from juno.indicators import MA, Ema, registry
+from juno.inspect import Constructor
 
 from .strategy import Signal, Strategy, ma_choices
 
@@ -38,7 +39,13 @@ class DoubleMA(Signal):
                 "short_ma": ma_choices,
                 "long_ma": ma_choices,
                 ("short_period", "long_period"): Pair(Int(1, 100), operator.lt, Int(2, 101)),
-            }
+            },
+            labels={
+                "short_ma": "Short moving average",
+                "long_ma": "Long moving average",
+                "short_period": "Short moving average period",
+                "long_period": "Long moving average period",
+            },
         )
 
     _short_ma: MA
@@ -55,8 +62,8 @@ class DoubleMA(Signal):
         assert short_period > 0
         assert short_period < long_period
 
-        self._short_ma = get_module_type(indicators, short_ma)(short_period)
-        self._long_ma = get_module_type(indicators, long_ma)(long_period)
+        self._short_ma = registry.create_ma(short_ma, short_period)
+        self._long_ma = registry.create_ma(long_ma, long_period)
 
     @property
     def advice(self) -> Advice:
@@ -70,6 +77,10 @@ class DoubleMA(Signal):
     def mature(self) -> bool:
         return self._long_ma.mature and self._short_ma.mature
 
+    @property
+    def snapshot(self) -> dict[str, Decimal]:
+        return {"short_ma": self._short_ma.value, "long_ma": self._long_ma.value}
+
     def update(self, candle: Candle, _: CandleMeta) -> None:
         self._short_ma.update(candle.close)
         self._long_ma.update(candle.close)
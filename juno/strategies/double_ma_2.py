@@ -1,10 +1,9 @@
 import operator
 from decimal import Decimal
 
-from juno import Advice, Candle, CandleMeta, indicators
+from juno import Advice, Candle, CandleMeta
 from juno.constraints import Int, Pair, Uniform
-from juno.indicators import MA, Ema
-from juno.inspect import get_module_type
+from juno.indicators import MA, Ema, registry
 
 from .strategy import Signal, Strategy, ma_choices
 
@@ -41,8 +40,8 @@ class DoubleMA2(Signal):
         assert short_period > 0
         assert short_period < long_period
 
-        self._short_ma = get_module_type(indicators, short_ma)(short_period)
-        self._long_ma = get_module_type(indicators, long_ma)(long_period)
+        self._short_ma = registry.create_ma(short_ma, short_period)
+        self._long_ma = registry.create_ma(long_ma, long_period)
         self._neg_threshold = neg_threshold
         self._pos_threshold = pos_threshold
 
@@ -0,0 +1,303 @@
+"""
+A small expression language for composing ad-hoc strategies out of indicators.
+
+Example: `ema(close, 12) > ema(close, 26) ? long : short`
+
+Grammar:
+    expression := or ("?" expression ":" expression)?
+    or         := and (("or" | "||") and)*
+    and        := comparison (("and" | "&&") comparison)*
+    comparison := sum ((">" | ">=" | "<" | "<=" | "==" | "!=") sum)?
+    sum        := product (("+" | "-") product)*
+    product    := unary (("*" | "/") unary)*
+    unary      := ("-" | "not" | "!") unary | primary
+    primary    := number | name | name "(" expression ("," number)* ")" | "(" expression ")"
+
+Names refer to candle fields (`open`, `high`, `low`, `close`, `volume`) or advices (`long`,
+`short`, `liquidate`, `none`). Function calls construct an indicator from `juno.indicators` by
+name (see `juno.indicators.registry`); the first argument is the indicator input and the rest are
+passed to its constructor. Only indicators taking a single input value and returning a single value
+are supported.
+"""
+
+from __future__ import annotations
+
+import operator
+import re
+from abc import ABC, abstractmethod
+from decimal import Decimal, InvalidOperation
+from typing import Any, Callable, Optional, Union
+
+from juno import Advice, Candle
+from juno.indicators import registry
+
+Value = Union[Decimal, bool, Advice]
+
+_TOKEN_RE = re.compile(
+    r"\s*(?:(?P<number>\d+(?:\.\d+)?)|(?P<name>[A-Za-z_][A-Za-z_0-9]*)"
+    r"|(?P<op>>=|<=|==|!=|&&|\|\||[-+*/<>!?:(),]))"
+)
+_CANDLE_FIELDS = {"open", "high", "low", "close", "volume"}
+_ADVICES = {
+    "long": Advice.LONG,
+    "short": Advice.SHORT,
+    "liquidate": Advice.LIQUIDATE,
+    "none": Advice.NONE,
+}
+_COMPARISONS: dict[str, Callable[[Any, Any], bool]] = {
+    ">": operator.gt,
+    ">=": operator.ge,
+    "<": operator.lt,
+    "<=": operator.le,
+    "==": operator.eq,
+    "!=": operator.ne,
+}
+_ARITHMETIC: dict[str, Callable[[Any, Any], Any]] = {
+    "+": operator.add,
+    "-": operator.sub,
+    "*": operator.mul,
+    "/": operator.truediv,
+}
+
+
+def tokenize(expression: str) -> list[str]:
+    result = []
+    pos = 0
+    expression = expression.rstrip()
+    while pos < len(expression):
+        match = _TOKEN_RE.match(expression, pos)
+        if not match:
+            raise ValueError(f"Unexpected character at {pos} in expression: {expression}")
+        result.append(match.group(match.lastgroup))  # type: ignore
+        pos = match.end()
+    return result
+
+
+def parse(expression: str) -> Node:
+    return _Parser(tokenize(expression)).parse()
+
+
+class Node(ABC):
+    @property
+    @abstractmethod
+    def maturity(self) -> int:
+        pass
+
+    # Returns None while the node is not mature.
+    @abstractmethod
+    def update(self, candle: Candle) -> Optional[Value]:
+        pass
+
+
+class Constant(Node):
+    def __init__(self, value: Value) -> None:
+        self._value = value
+
+    @property
+    def maturity(self) -> int:
+        return 1
+
+    def update(self, candle: Candle) -> Optional[Value]:
+        return self._value
+
+
+class Field(Node):
+    def __init__(self, name: str) -> None:
+        self._name = name
+
+    @property
+    def maturity(self) -> int:
+        return 1
+
+    def update(self, candle: Candle) -> Optional[Value]:
+        return getattr(candle, self._name)
+
+
+class Indicator(Node):
+    def __init__(self, name: str, input: Node, args: list[Decimal]) -> None:
+        info = registry.get(name)
+        if len(info.inputs) != 1 or info.outputs != 1:
+            raise ValueError(
+                f"Indicator {name} takes {len(info.inputs)} input(s) and returns "
+                f"{info.outputs} output(s); only single input and output indicators are supported"
+            )
+        self._input = input
+        self._indicator = info.create(*(int(a) if a == a.to_integral_value() else a for a in args))
+
+    @property
+    def maturity(self) -> int:
+        return self._input.maturity + self._indicator.maturity - 1
+
+    def update(self, candle: Candle) -> Optional[Value]:
+        value = self._input.update(candle)
+        if value is None:
+            return None
+        if not isinstance(value, Decimal):
+            raise ValueError(f"Indicator input must be a number; got {value}")
+        result = self._indicator.update(value)
+        return result if self._indicator.mature else None
+
+
+class Unary(Node):
+    def __init__(self, op: str, operand: Node) -> None:
+        self._op = op
+        self._operand = operand
+
+    @property
+    def maturity(self) -> int:
+        return self._operand.maturity
+
+    def update(self, candle: Candle) -> Optional[Value]:
+        value = self._operand.update(candle)
+        if value is None:
+            return None
+        return -value if self._op == "-" else not value  # type: ignore
+
+
+class Binary(Node):
+    def __init__(self, op: str, left: Node, right: Node) -> None:
+        self._op = op
+        self._left = left
+        self._right = right
+
+    @property
+    def maturity(self) -> int:
+        return max(self._left.maturity, self._right.maturity)
+
+    def update(self, candle: Candle) -> Optional[Value]:
+        # Both sides are always updated to keep indicator state in sync.
+        left = self._left.update(candle)
+        right = self._right.update(candle)
+        if left is None or right is None:
+            return None
+        if self._op in {"and", "&&"}:
+            return bool(left) and bool(right)
+        if self._op in {"or", "||"}:
+            return bool(left) or bool(right)
+        if self._op in _COMPARISONS:
+            return _COMPARISONS[self._op](left, right)
+        # Division by zero, such as `high - low` of a flat candle, gives no value for the candle.
+        if self._op == "/" and right == 0:
+            return None
+        return _ARITHMETIC[self._op](left, right)
+
+
+class Conditional(Node):
+    def __init__(self, condition: Node, then: Node, else_: Node) -> None:
+        self._condition = condition
+        self._then = then
+        self._else = else_
+
+    @property
+    def maturity(self) -> int:
+        return max(self._condition.maturity, self._then.maturity, self._else.maturity)
+
+    def update(self, candle: Candle) -> Optional[Value]:
+        condition = self._condition.update(candle)
+        then = self._then.update(candle)
+        else_ = self._else.update(candle)
+        if condition is None:
+            return None
+        return then if condition else else_
+
+
+class _Parser:
+    def __init__(self, tokens: list[str]) -> None:
+        self._tokens = tokens
+        self._pos = 0
+
+    def parse(self) -> Node:
+        result = self._expression()
+        if self._peek() is not None:
+            raise ValueError(f"Unexpected token: {self._peek()}")
+        return result
+
+    def _peek(self) -> Optional[str]:
+        return self._tokens[self._pos] if self._pos < len(self._tokens) else None
+
+    def _next(self) -> str:
+        token = self._peek()
+        if token is None:
+            raise ValueError("Unexpected end of expression")
+        self._pos += 1
+        return token
+
+    def _expect(self, token: str) -> None:
+        if (actual := self._next()) != token:
+            raise ValueError(f"Expected {token}; got {actual}")
+
+    def _expression(self) -> Node:
+        condition = self._or()
+        if self._peek() == "?":
+            self._next()
+            then = self._expression()
+            self._expect(":")
+            return Conditional(condition, then, self._expression())
+        return condition
+
+    def _or(self) -> Node:
+        result = self._and()
+        while self._peek() in {"or", "||"}:
+            result = Binary(self._next(), result, self._and())
+        return result
+
+    def _and(self) -> Node:
+        result = self._comparison()
+        while self._peek() in {"and", "&&"}:
+            result = Binary(self._next(), result, self._comparison())
+        return result
+
+    def _comparison(self) -> Node:
+        result = self._sum()
+        if self._peek() in _COMPARISONS:
+            result = Binary(self._next(), result, self._sum())
+        return result
+
+    def _sum(self) -> Node:
+        result = self._product()
+        while self._peek() in {"+", "-"}:
+            result = Binary(self._next(), result, self._product())
+        return result
+
+    def _product(self) -> Node:
+        result = self._unary()
+        while self._peek() in {"*", "/"}:
+            result = Binary(self._next(), result, self._unary())
+        return result
+
+    def _unary(self) -> Node:
+        if self._peek() in {"-", "!", "not"}:
+            op = self._next()
+            return Unary("-" if op == "-" else "not", self._unary())
+        return self._primary()
+
+    def _primary(self) -> Node:
+        token = self._next()
+        if token == "(":
+            result = self._expression()
+            self._expect(")")
+            return result
+        if token[0].isdigit():
+            return Constant(Decimal(token))
+        if token[0].isalpha() or token[0] == "_":
+            if self._peek() == "(":
+                self._next()
+                input = self._expression()
+                args = []
+                while self._peek() == ",":
+                    self._next()
+                    arg = self._next()
+                    try:
+                        args.append(Decimal(arg))
+                    except InvalidOperation:
+                        raise ValueError(
+                            f"Expected a number at token {self._pos - 1}; got {arg}"
+                        ) from None
+                self._expect(")")
+                return Indicator(token, input, args)
+            if token in _CANDLE_FIELDS:
+                return Field(token)
+            if token in _ADVICES:
+                return Constant(_ADVICES[token])
+            raise ValueError(f"Unknown name: {token}")
+        raise ValueError(f"Unexpected token: {token}")
@@ -0,0 +1,86 @@
+import inspect
+import re
+from decimal import Decimal
+from enum import Enum
+from typing import Any, Optional
+
+from juno import json
+from juno.constraints import Choice, Constraint, Int, Uniform
+
+from .strategy import Strategy
+
+
+# Resolves all constructor parameters of a strategy, filling in defaults for the ones not
+# specified. The result can be used as a strategy config as is.
+def export_params(type_: type[Strategy], kwargs: dict[str, Any]) -> dict[str, Any]:
+    result = {}
+    for name, parameter in inspect.signature(type_.__init__).parameters.items():
+        if name == "self" or parameter.kind in (
+            inspect.Parameter.VAR_POSITIONAL,
+            inspect.Parameter.VAR_KEYWORD,
+        ):
+            continue
+        if name in kwargs:
+            result[name] = kwargs[name]
+        elif parameter.default is not inspect.Parameter.empty:
+            result[name] = parameter.default
+        else:
+            raise ValueError(f"Missing required parameter {name} for {type_.__name__}")
+    unknown = kwargs.keys() - result.keys()
+    if len(unknown) > 0:
+        raise ValueError(f"Unknown parameter(s) for {type_.__name__}: {', '.join(unknown)}")
+    return result
+
+
+# Renders strategy parameters as Pine Script inputs so that they can be transferred to TradingView.
+# Only the inputs are generated; the strategy logic itself has to be written by hand. Parameters
+# which have no Pine equivalent, such as nested strategy configs, are included as comments.
+def export_pine(type_: type[Strategy], params: dict[str, Any]) -> str:
+    meta = type_.meta()
+    lines = [
+        "//@version=5",
+        f'strategy("{_title(type_.__name__)}", overlay=true)',
+        "",
+    ]
+    for name, value in params.items():
+        label = meta.labels.get(name, name.replace("_", " ").capitalize())
+        input_ = _pine_input(value, label, meta.constraints.get(name))
+        if input_ is None:
+            lines.append(f"// {name} = {json.dumps(value)}")
+        else:
+            lines.append(f"{name} = {input_}")
+    return "\n".join(lines) + "\n"
+
+
+def _title(name: str) -> str:
+    # Separates words in a type name: "FourWeekRule" -> "Four Week Rule", "DoubleMA" ->
+    # "Double MA".
+    return re.sub(r"(?<=[a-z0-9])(?=[A-Z])", " ", name)
+
+
+def _pine_input(value: Any, label: str, constraint: Optional[Constraint]) -> Optional[str]:
+    title = json.dumps(label)
+    if isinstance(value, bool):
+        return f"input.bool({str(value).lower()}, title={title})"
+    if isinstance(value, Enum):
+        value = value.value
+    if isinstance(value, int):
+        bounds = (
+            f", minval={constraint.min}, maxval={constraint.max - 1}"
+            if isinstance(constraint, Int)
+            else ""
+        )
+        return f"input.int({value}, title={title}{bounds})"
+    if isinstance(value, (Decimal, float)):
+        bounds = (
+            f", minval={constraint.min}, maxval={constraint.max}"
+            if isinstance(constraint, Uniform)
+            else ""
+        )
+        return f"input.float({value}, title={title}{bounds})"
+    if isinstance(value, str):
+        options = (
+            f", options={json.dumps(constraint.choices)}" if isinstance(constraint, Choice) else ""
+        )
+        return f"input.string({json.dumps(value)}, title={title}{options})"
+    return None
@@ -1,5 +1,6 @@
 import asyncio
 import logging
+from random import Random
 
 from juno import Advice, Candle, CandleMeta
 
@@ -39,6 +40,25 @@ class Fixed(Signal):
         self._t1 = maturity
         self._t2 = maturity + max(self._mid_trend.maturity, self._persistence.maturity) - 1
 
+    @staticmethod
+    def random_advices(
+        length: int, num_positions: int, sides: list[Advice], random: Random
+    ) -> list[Advice]:
+        """
+        Generates advices for `num_positions` non-overlapping positions at random times and of
+        random durations, each on a random side chosen from `sides`. Useful as a baseline of
+        random trading with a similar trade frequency to some other strategy.
+        """
+        if 2 * num_positions > length:
+            raise ValueError(f"Cannot fit {num_positions} positions into {length} advices")
+        result = [Advice.NONE] * length
+        times = sorted(random.sample(range(length), 2 * num_positions))
+        for open_, close in zip(times[::2], times[1::2]):
+            side = random.choice(sides)
+            result[open_:close] = [side] * (close - open_)
+            result[close] = Advice.LIQUIDATE
+        return result
+
     @property
     def advice(self) -> Advice:
         return self._advice
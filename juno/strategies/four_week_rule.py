@@ -1,14 +1,11 @@
 from __future__ import annotations
 
-from collections import deque
 from dataclasses import dataclass
-from decimal import Decimal
 
 from juno import Advice, Candle, CandleMeta, indicators
 from juno.constraints import Int
-from juno.indicators import MA
-from juno.inspect import Constructor, get_module_type
-from juno.math import minmax
+from juno.indicators import MA, registry
+from juno.inspect import Constructor
 
 from .strategy import Signal, Strategy, ma_choices
 
@@ -39,10 +36,15 @@ class FourWeekRule(Signal):
                 "period": Int(2, 100),
                 "ma": ma_choices,
                 "ma_period": Int(2, 100),
-            }
+            },
+            labels={
+                "period": "Breakout period",
+                "ma": "Exit moving average",
+                "ma_period": "Exit moving average period",
+            },
         )
 
-    _prices: deque[Decimal]
+    _donchian: indicators.DonchianChannel
     _ma: MA
     _advice: Advice = Advice.NONE
     _t: int = 0
@@ -54,8 +56,8 @@ class FourWeekRule(Signal):
         ma: str = "ema",
         ma_period: int = 14,  # Normally half the period.
     ) -> None:
-        self._prices = deque(maxlen=period)
-        self._ma = get_module_type(indicators, ma)(ma_period)
+        self._donchian = indicators.DonchianChannel(period)
+        self._ma = registry.create_ma(ma, ma_period)
         self._t1 = period + 1
 
     @property
@@ -76,14 +78,13 @@ class FourWeekRule(Signal):
         self._ma.update(candle.close)
 
         if self._t >= self._t1:
-            lowest, highest = minmax(self._prices)
-            if candle.close >= highest:
+            if candle.close >= self._donchian.upper:
                 self._advice = Advice.LONG
-            elif candle.close <= lowest:
+            elif candle.close <= self._donchian.lower:
                 self._advice = Advice.SHORT
             elif (self._advice is Advice.LONG and candle.close <= self._ma.value) or (
                 self._advice is Advice.SHORT and candle.close >= self._ma.value
             ):
                 self._advice = Advice.LIQUIDATE
 
-        self._prices.append(candle.close)
+        self._donchian.update(candle.close, candle.close)
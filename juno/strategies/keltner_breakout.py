@@ -0,0 +1,57 @@
+from decimal import Decimal
+
+from juno import Advice, Candle, CandleMeta, indicators
+from juno.constraints import Int, Uniform
+
+from .strategy import Signal, Strategy
+
+
+# Signals a long position when a candle close price is above the upper Keltner band and a short
+# position when it is below the lower band. Signals liquidation when the price crosses back over
+# the middle line.
+class KeltnerBreakout(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "period": Int(1, 101),
+                "atr_period": Int(2, 101),
+                "multiplier": Uniform(Decimal("0.5"), Decimal("5.0")),
+            }
+        )
+
+    _keltner: indicators.KeltnerChannel
+    _advice: Advice = Advice.NONE
+
+    def __init__(
+        self,
+        period: int = 20,
+        atr_period: int = 10,
+        multiplier: Decimal = Decimal("2.0"),
+    ) -> None:
+        self._keltner = indicators.KeltnerChannel(period, atr_period, multiplier)
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._keltner.maturity
+
+    @property
+    def mature(self) -> bool:
+        return self._keltner.mature
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        self._keltner.update(candle.high, candle.low, candle.close)
+
+        if self._keltner.mature:
+            if candle.close > self._keltner.upper:
+                self._advice = Advice.LONG
+            elif candle.close < self._keltner.lower:
+                self._advice = Advice.SHORT
+            elif (self._advice is Advice.LONG and candle.close <= self._keltner.middle) or (
+                self._advice is Advice.SHORT and candle.close >= self._keltner.middle
+            ):
+                self._advice = Advice.LIQUIDATE
@@ -1,4 +1,5 @@
 import operator
+from decimal import Decimal
 
 from juno import Advice, Candle, CandleMeta, indicators
 from juno.constraints import Int, Pair
@@ -16,7 +17,12 @@ class Macd(Signal):
                 ("short_period", "long_period"): Pair(Int(1, 100), operator.lt, Int(2, 101)),
                 "signal_period": Int(1, 101),
                 "persistence": Int(0, 10),
-            }
+            },
+            labels={
+                "short_period": "Fast length",
+                "long_period": "Slow length",
+                "signal_period": "Signal smoothing",
+            },
         )
 
     _macd: indicators.Macd
@@ -42,6 +48,14 @@ class Macd(Signal):
     def mature(self) -> bool:
         return self._macd.mature
 
+    @property
+    def snapshot(self) -> dict[str, Decimal]:
+        return {
+            "macd": self._macd.value,
+            "signal": self._macd.signal,
+            "histogram": self._macd.histogram,
+        }
+
     def update(self, candle: Candle, _: CandleMeta) -> None:
         self._macd.update(candle.close)
 
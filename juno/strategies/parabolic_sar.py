@@ -0,0 +1,43 @@
+from decimal import Decimal
+
+from juno import Advice, Candle, CandleMeta, indicators
+from juno.constraints import Uniform
+
+from .strategy import Signal, Strategy
+
+
+# Signals a long position when the price crosses above the parabolic SAR and a short position when
+# it crosses below.
+class ParabolicSar(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "step": Uniform(Decimal("0.01"), Decimal("0.10")),
+                "max_": Uniform(Decimal("0.10"), Decimal("0.50")),
+            }
+        )
+
+    _psar: indicators.ParabolicSar
+    _advice: Advice = Advice.NONE
+
+    def __init__(self, step: Decimal = Decimal("0.02"), max_: Decimal = Decimal("0.2")) -> None:
+        self._psar = indicators.ParabolicSar(step, max_)
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._psar.maturity
+
+    @property
+    def mature(self) -> bool:
+        return self._psar.mature
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        self._psar.update(candle.high, candle.low)
+
+        if self._psar.mature:
+            self._advice = Advice.LONG if self._psar.long else Advice.SHORT
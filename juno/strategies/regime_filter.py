@@ -0,0 +1,99 @@
+import statistics
+from collections import deque
+from decimal import Decimal
+from typing import Any, Optional
+
+from juno import Advice, Candle, CandleMeta, strategies
+from juno.config import init_module_instance
+from juno.indicators import Atr
+
+from .strategy import Signal
+
+
+# Generic signal gated by a volatility regime.
+#
+# Volatility is measured either as ATR relative to close ('atr') or as the standard deviation of
+# close-to-close returns ('stdev') over `period`. Its percentile rank among the last `lookback`
+# measurements must lie within `[min_percentile, max_percentile]` for the signal to be valid.
+# Otherwise, liquidate is signaled to stay out of the market during a low/high volatility regime.
+class RegimeFilter(Signal):
+    _advice: Advice = Advice.NONE
+    _sig: Signal
+    _measure: str
+    _atr: Atr
+    _returns: deque[Decimal]
+    _volatilities: deque[Decimal]
+    _min_percentile: Decimal
+    _max_percentile: Decimal
+    _prev_close: Optional[Decimal] = None
+    _t: int = 0
+    _t1: int
+
+    def __init__(
+        self,
+        sig: dict[str, Any],
+        measure: str = "atr",
+        period: int = 14,
+        lookback: int = 100,
+        min_percentile: Decimal = Decimal("0.2"),
+        max_percentile: Decimal = Decimal("1.0"),
+    ) -> None:
+        assert measure in {"atr", "stdev"}
+        assert period > 1
+        assert lookback > 0
+        assert 0 <= min_percentile <= max_percentile <= 1
+
+        self._sig = init_module_instance(strategies, sig)
+        self._measure = measure
+        self._atr = Atr(period)
+        self._returns = deque(maxlen=period)
+        self._volatilities = deque(maxlen=lookback)
+        self._min_percentile = min_percentile
+        self._max_percentile = max_percentile
+        # Returns require an extra candle compared to ATR.
+        volatility_maturity = period if measure == "atr" else period + 1
+        self._t1 = max(self._sig.maturity, volatility_maturity + lookback - 1)
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, candle: Candle, meta: CandleMeta) -> None:
+        self._t = min(self._t + 1, self._t1)
+
+        self._sig.update(candle, meta)
+        volatility = self._update_volatility(candle)
+        if volatility is not None:
+            self._volatilities.append(volatility)
+
+        if self.mature:
+            percentile = Decimal(
+                sum(1 for v in self._volatilities if v <= self._volatilities[-1])
+            ) / len(self._volatilities)
+            self._advice = (
+                self._sig.advice
+                if self._min_percentile <= percentile <= self._max_percentile
+                else Advice.LIQUIDATE
+            )
+
+    def _update_volatility(self, candle: Candle) -> Optional[Decimal]:
+        if self._measure == "atr":
+            self._atr.update(candle.high, candle.low, candle.close)
+            return self._atr.value / candle.close if self._atr.mature else None
+
+        if self._prev_close is not None:
+            self._returns.append(candle.close / self._prev_close - 1)
+        self._prev_close = candle.close
+        return (
+            statistics.pstdev(self._returns)
+            if len(self._returns) == self._returns.maxlen
+            else None
+        )
@@ -45,6 +45,10 @@ class Rsi(Oscillator):
     def mature(self) -> bool:
         return self.indicator.mature
 
+    @property
+    def snapshot(self) -> dict[str, Decimal]:
+        return {"rsi": self.indicator.value}
+
     @property
     def overbought(self) -> bool:
         return self.indicator.mature and self.indicator.value >= self._up_threshold
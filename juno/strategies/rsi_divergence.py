@@ -0,0 +1,75 @@
+from collections import deque
+from decimal import Decimal
+
+from juno import Advice, Candle, CandleMeta, indicators
+from juno.constraints import Int, Uniform
+
+from .strategy import Signal, Strategy
+
+
+# Signals a long position on a bullish divergence: the close makes a new low within the lookback
+# window while RSI is higher than it was at the previous low.
+# Signals a short position on a bearish divergence: the close makes a new high within the
+# lookback window while RSI is lower than it was at the previous high.
+# The RSI difference must be at least `min_divergence` points for a divergence to count.
+class RsiDivergence(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "period": Int(2, 101),
+                "lookback": Int(2, 101),
+                "min_divergence": Uniform(Decimal("0.0"), Decimal("50.0")),
+            }
+        )
+
+    _rsi: indicators.Rsi
+    _min_divergence: Decimal
+    _window: deque[tuple[Decimal, Decimal]]  # [close, rsi]
+    _advice: Advice = Advice.NONE
+    _t: int = 0
+    _t1: int
+
+    def __init__(
+        self,
+        period: int = 14,
+        lookback: int = 14,
+        min_divergence: Decimal = Decimal("5.0"),
+    ) -> None:
+        assert period > 1
+        assert lookback > 1
+        assert min_divergence >= 0
+
+        self._rsi = indicators.Rsi(period)
+        self._min_divergence = min_divergence
+        self._window = deque(maxlen=lookback)
+        self._t1 = self._rsi.maturity + lookback
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._t1
+
+    @property
+    def mature(self) -> bool:
+        return self._t >= self._t1
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        self._t = min(self._t + 1, self._t1)
+
+        rsi = self._rsi.update(candle.close)
+        if not self._rsi.mature:
+            return
+
+        if self._t >= self._t1:
+            low_close, low_rsi = min(self._window, key=lambda x: x[0])
+            high_close, high_rsi = max(self._window, key=lambda x: x[0])
+            if candle.close < low_close and rsi - low_rsi >= self._min_divergence:
+                self._advice = Advice.LONG
+            elif candle.close > high_close and high_rsi - rsi >= self._min_divergence:
+                self._advice = Advice.SHORT
+
+        self._window.append((candle.close, rsi))
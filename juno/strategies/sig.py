@@ -1,9 +1,18 @@
-from typing import Any
+from decimal import Decimal
+from typing import Any, Optional
 
-from juno import Advice, Candle, CandleMeta, strategies
+from juno import Advice, Candle, CandleMeta, Interval, strategies
 from juno.config import init_module_instance
 
-from .strategy import Changed, Maturity, MidTrend, MidTrendPolicy, Persistence, Signal
+from .strategy import (
+    Changed,
+    Maturity,
+    MidTrend,
+    MidTrendPolicy,
+    Persistence,
+    Signal,
+    persistence_level,
+)
 
 
 # Generic signal with additional persistence and mid trend filters.
@@ -24,10 +33,15 @@ class Sig(Signal):
         persistence: int = 0,
         extra_maturity: int = 0,
         changed_enabled: bool = False,
+        persistence_duration: Optional[Interval] = None,  # Overrides `persistence`.
+        interval: Optional[Interval] = None,  # Of main candles. Required for duration.
     ) -> None:
         self._sig = init_module_instance(strategies, sig)
         self._mid_trend = MidTrend(mid_trend_policy)
-        self._persistence = Persistence(level=persistence, return_previous=False)
+        self._persistence = Persistence(
+            level=persistence_level(persistence, persistence_duration, interval),
+            return_previous=False,
+        )
         self._extra_maturity = Maturity(maturity=extra_maturity)
         self._changed = Changed(enabled=changed_enabled)
         self._t1 = max(
@@ -47,6 +61,10 @@ class Sig(Signal):
     def mature(self) -> bool:
         return self._t >= self._t1
 
+    @property
+    def snapshot(self) -> dict[str, Decimal]:
+        return {f"sig.{k}": v for k, v in self._sig.snapshot.items()}
+
     def update(self, candle: Candle, meta: CandleMeta) -> None:
         self._t = min(self._t + 1, self._t1)
 
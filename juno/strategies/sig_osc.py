@@ -1,9 +1,17 @@
-from typing import Any
+from decimal import Decimal
+from typing import Any, Optional
 
-from juno import Advice, Candle, CandleMeta, strategies
+from juno import Advice, Candle, CandleMeta, Interval, strategies
 from juno.config import init_module_instance
 
-from .strategy import MidTrend, MidTrendPolicy, Oscillator, Persistence, Signal
+from .strategy import (
+    MidTrend,
+    MidTrendPolicy,
+    Oscillator,
+    Persistence,
+    Signal,
+    persistence_level,
+)
 
 
 # Generic signal with additional oscillator, persistence and mid trend filters.
@@ -28,6 +36,8 @@ class SigOsc(Signal):
         osc_filter: str = "enforce",
         mid_trend_policy: MidTrendPolicy = MidTrendPolicy.CURRENT,
         persistence: int = 0,
+        persistence_duration: Optional[Interval] = None,  # Overrides `persistence`.
+        interval: Optional[Interval] = None,  # Of main candles. Required for duration.
     ) -> None:
         assert osc_filter in {"enforce", "prevent"}
 
@@ -35,7 +45,10 @@ class SigOsc(Signal):
         self._osc = init_module_instance(strategies, osc)
         self._osc_filter = osc_filter
         self._mid_trend = MidTrend(mid_trend_policy)
-        self._persistence = Persistence(level=persistence, return_previous=False)
+        self._persistence = Persistence(
+            level=persistence_level(persistence, persistence_duration, interval),
+            return_previous=False,
+        )
         self._t1 = (
             max(self._sig.maturity, self._osc.maturity)
             + max(self._mid_trend.maturity, self._persistence.maturity)
@@ -54,6 +67,13 @@ class SigOsc(Signal):
     def mature(self) -> bool:
         return self._t >= self._t1
 
+    @property
+    def snapshot(self) -> dict[str, Decimal]:
+        return {
+            **{f"sig.{k}": v for k, v in self._sig.snapshot.items()},
+            **{f"osc.{k}": v for k, v in self._osc.snapshot.items()},
+        }
+
     def update(self, candle: Candle, meta: CandleMeta) -> None:
         self._t = min(self._t + 1, self._t1)
 
@@ -3,10 +3,10 @@ from __future__ import annotations
 from dataclasses import dataclass
 from decimal import Decimal
 
-from juno import Advice, Candle, CandleMeta, indicators
+from juno import Advice, Candle, CandleMeta
 from juno.constraints import Int
-from juno.indicators import MA, Ema2
-from juno.inspect import Constructor, get_module_type
+from juno.indicators import MA, Ema2, registry
+from juno.inspect import Constructor
 
 from .strategy import Signal, Strategy, ma_choices
 
@@ -34,7 +34,11 @@ class SingleMA(Signal):
             constraints={
                 "ma": ma_choices,
                 "period": Int(1, 100),
-            }
+            },
+            labels={
+                "ma": "Moving average",
+                "period": "Moving average period",
+            },
         )
 
     _ma: MA
@@ -48,7 +52,7 @@ class SingleMA(Signal):
         ma: str = Ema2.__name__.lower(),
         period: int = 50,  # Daily.
     ) -> None:
-        self._ma = get_module_type(indicators, ma)(period)
+        self._ma = registry.create_ma(ma, period)
         self._t1 = self._ma.maturity + 1
 
     @property
@@ -63,6 +67,10 @@ class SingleMA(Signal):
     def mature(self) -> bool:
         return self._t >= self._t1
 
+    @property
+    def snapshot(self) -> dict[str, Decimal]:
+        return {"ma": self._ma.value}
+
     def update(self, candle: Candle, _: CandleMeta) -> None:
         self._t = min(self._t + 1, self._t1)
 
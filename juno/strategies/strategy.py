@@ -2,13 +2,14 @@ from __future__ import annotations
 
 from abc import ABC, abstractmethod
 from dataclasses import dataclass, field
+from decimal import Decimal
 from enum import IntEnum
 from typing import Any, Optional, Union
 
-from juno import Advice, Candle
+from juno import Advice, Candle, Interval
 from juno.common import CandleMeta
 from juno.constraints import Choice, Constraint
-from juno.indicators import Alma, Dema, Ema, Ema2, Kama, Sma, Smma
+from juno.indicators import registry
 
 
 class MidTrendPolicy(IntEnum):
@@ -17,7 +18,7 @@ class MidTrendPolicy(IntEnum):
     IGNORE = 2  # Will skip advice for on-going trend. Needs to see a new trend starting.
 
 
-ma_choices = Choice([i.__name__.lower() for i in [Alma, Dema, Ema, Ema2, Kama, Sma, Smma]])
+ma_choices = Choice(registry.ma_names())
 mid_trend_policy_choices = Choice(
     [
         MidTrendPolicy.CURRENT,
@@ -116,6 +117,19 @@ class Persistence:
         return result
 
 
+# Persistence is measured in candles, which means a different duration for every interval. If a
+# duration is given instead, it is converted to the number of candles of the interval, rounding
+# up.
+def persistence_level(
+    persistence: int, persistence_duration: Optional[Interval], interval: Optional[Interval]
+) -> int:
+    if persistence_duration is None:
+        return persistence
+    if interval is None:
+        raise ValueError("Interval required to convert persistence duration to candles")
+    return -(-persistence_duration // interval)
+
+
 class Changed:
     """Pass an advice only if was changed on current tick."""
 
@@ -156,6 +170,9 @@ class Strategy(ABC):
     @dataclass(frozen=True)
     class Meta:
         constraints: dict[Union[str, tuple[str, ...]], Constraint] = field(default_factory=dict)
+        # Human-readable parameter names used when exporting parameters. Parameters without a
+        # label are named after their argument.
+        labels: dict[str, str] = field(default_factory=dict)
 
     @staticmethod
     def meta() -> Strategy.Meta:
@@ -175,6 +192,12 @@ class Strategy(ABC):
     def extra_candles(self) -> list[CandleMeta]:
         return []
 
+    # Named internal values as of the last update, such as indicator outputs. Only used for
+    # visualizing what the strategy computed, hence not required to be implemented.
+    @property
+    def snapshot(self) -> dict[str, Decimal]:
+        return {}
+
     @abstractmethod
     def update(self, candle: Candle, meta: CandleMeta) -> None:
         pass
@@ -0,0 +1,63 @@
+from decimal import Decimal
+
+from juno import Advice, Candle, CandleMeta, indicators
+from juno.constraints import Int, Uniform
+
+from .strategy import Signal, Strategy
+
+
+# Signals a long position when the slope of a rolling linear regression rises above the up
+# threshold and a short position when it falls below the down threshold. The slope is relative to
+# the regression value, i.e. the fractional price change per candle, so that thresholds do not
+# depend on the price level. Signals liquidation when the slope returns between the thresholds.
+class TrendSlope(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "period": Int(2, 201),
+                "up_threshold": Uniform(Decimal("0.0"), Decimal("0.01")),
+                "down_threshold": Uniform(Decimal("-0.01"), Decimal("0.0")),
+            }
+        )
+
+    _linreg: indicators.LinReg
+    _up_threshold: Decimal
+    _down_threshold: Decimal
+    _advice: Advice = Advice.NONE
+
+    def __init__(
+        self,
+        period: int = 20,
+        up_threshold: Decimal = Decimal("0.001"),
+        down_threshold: Decimal = Decimal("-0.001"),
+    ) -> None:
+        assert up_threshold >= down_threshold
+
+        self._linreg = indicators.LinReg(period)
+        self._up_threshold = up_threshold
+        self._down_threshold = down_threshold
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._linreg.maturity
+
+    @property
+    def mature(self) -> bool:
+        return self._linreg.mature
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        self._linreg.update(candle.close)
+
+        if self._linreg.mature and self._linreg.value > 0:
+            slope = self._linreg.slope / self._linreg.value
+            if slope > self._up_threshold:
+                self._advice = Advice.LONG
+            elif slope < self._down_threshold:
+                self._advice = Advice.SHORT
+            elif self._advice in {Advice.LONG, Advice.SHORT}:
+                self._advice = Advice.LIQUIDATE
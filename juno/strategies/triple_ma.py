@@ -3,10 +3,10 @@ from __future__ import annotations
 import operator
 from dataclasses import dataclass
 
-from juno import Advice, Candle, CandleMeta, indicators
+from juno import Advice, Candle, CandleMeta
 from juno.constraints import Int, Triple
-from juno.indicators import MA, Ema
-from juno.inspect import Constructor, get_module_type
+from juno.indicators import MA, Ema, registry
+from juno.inspect import Constructor
 
 from .strategy import Signal, Strategy, ma_choices
 
@@ -49,7 +49,15 @@ class TripleMA(Signal):
                     operator.lt,
                     Int(3, 101),
                 ),
-            }
+            },
+            labels={
+                "short_ma": "Short moving average",
+                "medium_ma": "Medium moving average",
+                "long_ma": "Long moving average",
+                "short_period": "Short moving average period",
+                "medium_period": "Medium moving average period",
+                "long_period": "Long moving average period",
+            },
         )
 
     _short_ma: MA
@@ -69,9 +77,9 @@ class TripleMA(Signal):
         assert short_period > 0
         assert short_period < medium_period < long_period
 
-        self._short_ma = get_module_type(indicators, short_ma)(short_period)
-        self._medium_ma = get_module_type(indicators, medium_ma)(medium_period)
-        self._long_ma = get_module_type(indicators, long_ma)(long_period)
+        self._short_ma = registry.create_ma(short_ma, short_period)
+        self._medium_ma = registry.create_ma(medium_ma, medium_period)
+        self._long_ma = registry.create_ma(long_ma, long_period)
 
     @property
     def advice(self) -> Advice:
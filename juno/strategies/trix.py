@@ -0,0 +1,43 @@
+from juno import Advice, Candle, CandleMeta, indicators
+from juno.constraints import Int
+
+from .strategy import Signal, Strategy
+
+
+# Signals a long position when TRIX crosses above the zero line and a short position when it
+# crosses below.
+class Trix(Signal):
+    @staticmethod
+    def meta() -> Strategy.Meta:
+        return Strategy.Meta(
+            constraints={
+                "period": Int(2, 51),
+            }
+        )
+
+    _trix: indicators.Trix
+    _advice: Advice = Advice.NONE
+
+    def __init__(self, period: int = 15) -> None:
+        self._trix = indicators.Trix(period)
+
+    @property
+    def advice(self) -> Advice:
+        return self._advice
+
+    @property
+    def maturity(self) -> int:
+        return self._trix.maturity
+
+    @property
+    def mature(self) -> bool:
+        return self._trix.mature
+
+    def update(self, candle: Candle, _: CandleMeta) -> None:
+        self._trix.update(candle.close)
+
+        if self._trix.mature:
+            if self._trix.value > 0:
+                self._advice = Advice.LONG
+            elif self._trix.value < 0:
+                self._advice = Advice.SHORT
@@ -1,6 +1,7 @@
 from .basic import Basic
 from .legacy import Legacy
 from .noop import Noop
+from .scale_out import ScaleOut
 from .take_profit import TakeProfit
 from .trending import Trending
 
@@ -8,6 +9,7 @@ __all__ = [
     "Basic",
     "Legacy",
     "Noop",
+    "ScaleOut",
     "TakeProfit",
     "Trending",
 ]
@@ -0,0 +1,85 @@
+from decimal import Decimal
+from typing import Optional
+
+from juno import Candle
+
+from .take_profit import TakeProfit
+
+
+# Closes `fraction` of the position when the first threshold is reached and the remainder when
+# the second threshold is reached. The partial close is only signaled once per position.
+class ScaleOut(TakeProfit):
+    _fraction: Decimal
+    _first_up_factor: Decimal
+    _first_down_factor: Decimal
+    _second_up_factor: Decimal
+    _second_down_factor: Decimal
+    _close_at_position: Decimal = Decimal("0.0")
+    _close: Decimal = Decimal("0.0")
+    _partial_up_taken: bool = False
+    _partial_down_taken: bool = False
+    _partial_up_hit: bool = False
+    _partial_down_hit: bool = False
+
+    def __init__(
+        self,
+        first_threshold: Decimal,
+        second_threshold: Decimal,
+        fraction: Decimal = Decimal("0.5"),
+        first_down_threshold: Optional[Decimal] = None,
+        second_down_threshold: Optional[Decimal] = None,
+    ) -> None:
+        if first_down_threshold is None:
+            first_down_threshold = first_threshold
+        if second_down_threshold is None:
+            second_down_threshold = second_threshold
+        assert 0 <= first_threshold <= second_threshold
+        assert 0 <= first_down_threshold <= second_down_threshold
+        assert 0 < fraction < 1
+        self._fraction = fraction
+        self._first_up_factor = 1 + first_threshold
+        self._first_down_factor = 1 - first_down_threshold
+        self._second_up_factor = 1 + second_threshold
+        self._second_down_factor = 1 - second_down_threshold
+
+    @property
+    def upside_hit(self) -> bool:
+        return self._close >= self._close_at_position * self._second_up_factor
+
+    @property
+    def downside_hit(self) -> bool:
+        return self._close <= self._close_at_position * self._second_down_factor
+
+    @property
+    def partial_upside_hit(self) -> bool:
+        return self._partial_up_hit
+
+    @property
+    def partial_downside_hit(self) -> bool:
+        return self._partial_down_hit
+
+    @property
+    def partial_fraction(self) -> Decimal:
+        return self._fraction
+
+    def clear(self, candle: Candle) -> None:
+        self._close_at_position = candle.close
+        self._partial_up_taken = False
+        self._partial_down_taken = False
+        self._partial_up_hit = False
+        self._partial_down_hit = False
+
+    def update(self, candle: Candle) -> None:
+        self._close = candle.close
+
+        self._partial_up_hit = (
+            not self._partial_up_taken
+            and self._close >= self._close_at_position * self._first_up_factor
+        )
+        self._partial_up_taken = self._partial_up_taken or self._partial_up_hit
+
+        self._partial_down_hit = (
+            not self._partial_down_taken
+            and self._close <= self._close_at_position * self._first_down_factor
+        )
+        self._partial_down_taken = self._partial_down_taken or self._partial_down_hit
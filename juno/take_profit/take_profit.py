@@ -1,4 +1,5 @@
 from abc import ABC, abstractmethod
+from decimal import Decimal
 
 from juno import Candle
 
@@ -14,6 +15,19 @@ class TakeProfit(ABC):
     def downside_hit(self) -> bool:
         pass
 
+    # Partial take profits close `partial_fraction` of an open position and keep the rest open.
+    @property
+    def partial_upside_hit(self) -> bool:
+        return False
+
+    @property
+    def partial_downside_hit(self) -> bool:
+        return False
+
+    @property
+    def partial_fraction(self) -> Decimal:
+        return Decimal("0.0")
+
     @abstractmethod
     def clear(self, candle: Candle) -> None:
         pass
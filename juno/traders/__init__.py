@@ -1,13 +1,17 @@
-from .basic import Basic, BasicConfig, BasicState
+from .basic import AdviceTraceEntry, Basic, BasicConfig, BasicState, StrategySnapshot
+from .hook import TraderHook
 from .multi import Multi, MultiConfig, MultiState
 from .trader import Trader
 
 __all__ = [
+    "AdviceTraceEntry",
     "Basic",
     "BasicConfig",
     "BasicState",
     "Multi",
     "MultiConfig",
     "MultiState",
+    "StrategySnapshot",
     "Trader",
+    "TraderHook",
 ]
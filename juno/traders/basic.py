@@ -1,6 +1,6 @@
 import asyncio
 import logging
-from dataclasses import dataclass, field
+from dataclasses import dataclass, field, replace
 from decimal import Decimal
 from typing import Awaitable, Callable, Literal, Optional, TypeVar, Union
 from uuid import uuid4
@@ -10,7 +10,10 @@ from juno import (
     BadOrder,
     Candle,
     CandleType,
+    Fees,
+    Fill,
     Interval,
+    Interval_,
     Symbol,
     Symbol_,
     Timestamp,
@@ -23,13 +26,28 @@ from juno.components import Chandler, Events, Informant, Orderbook, User
 from juno.custodians import Custodian, Stub
 from juno.exchanges import Exchange
 from juno.inspect import Constructor
+from juno.math import round_down
 from juno.positioner import Positioner, SimulatedPositioner
+from juno.statistics import StreamingStatistics
 from juno.stop_loss import Noop as NoopStopLoss
 from juno.stop_loss import StopLoss
 from juno.strategies import Changed, Signal
 from juno.take_profit import Noop as NoopTakeProfit
 from juno.take_profit import TakeProfit
-from juno.trading import CloseReason, Position, StartMixin, TradingMode, TradingSummary
+from juno.trading import (
+    CloseReason,
+    FeeDiscount,
+    FeeSchedule,
+    FillModel,
+    Liquidity,
+    MissedCandlePolicy,
+    Position,
+    PriceImpact,
+    StartMixin,
+    TradingMode,
+    TradingSession,
+    TradingSummary,
+)
 
 from .trader import Trader
 
@@ -38,6 +56,24 @@ _log = logging.getLogger(__name__)
 T = TypeVar("T")
 
 
+# Debugging aid describing what happened on a main candle. Raw advice is given by the strategy,
+# while advice is what remains after filtering out repeated advice. Actions are any of `open_long`,
+# `open_short`, `scale_in`, `close` and `close_partial`, in the order taken.
+@dataclass(frozen=True)
+class AdviceTraceEntry:
+    time: Timestamp
+    raw_advice: Advice
+    advice: Advice
+    actions: tuple[str, ...] = ()
+
+
+# Internal values of the strategy after a main candle. See `Strategy.snapshot`.
+@dataclass(frozen=True)
+class StrategySnapshot:
+    time: Timestamp
+    values: dict[str, Decimal]
+
+
 @dataclass(frozen=True)
 class BasicConfig:
     exchange: str
@@ -57,6 +93,27 @@ class BasicConfig:
     close_on_exit: bool = True  # Whether to close open position on exit.
     custodian: str = "stub"
     candle_type: CandleType = "regular"
+    missed_candle_policy: MissedCandlePolicy = MissedCandlePolicy.IGNORE
+    # Simulates a margin call for short positions. None disables the simulation.
+    maintenance_margin_rate: Optional[Decimal] = None
+    # Number of times a position can be scaled into when the advice changes to its direction again.
+    max_entries: int = 1
+    entry_fraction: Decimal = Decimal("1.0")  # Share of available quote used per entry.
+    session: Optional[TradingSession] = None  # None means positions can be opened any time.
+    price_impact: Optional[PriceImpact] = None  # Only applies to backtesting.
+    fill_model: FillModel = FillModel()  # Only applies to backtesting.
+    fee_schedule: FeeSchedule = FeeSchedule()  # Only applies to backtesting.
+    fee_discount: Optional[FeeDiscount] = None  # None means fees are paid in traded assets.
+    trace_advice: bool = False  # Records an advice trace entry for every main candle.
+    snapshot_strategy: bool = False  # Records a strategy snapshot for every main candle.
+    record_equity: bool = False  # Records the equity at the close of every main candle.
+    # Share of realized profit added back to trading capital. The rest is set aside and not traded
+    # with. 1 means fully compounding and 0 means trading with fixed capital. Losses are always
+    # taken from trading capital.
+    reinvestment: Decimal = Decimal("1.0")
+    # Excludes the period before the strategy is mature from statistics. Has no effect when the
+    # strategy is fully warmed up before start through `adjusted_start`.
+    exclude_warmup: bool = False
 
     @property
     def base_asset(self) -> str:
@@ -81,16 +138,35 @@ class BasicState:
     stop_loss: StopLoss
     take_profit: TakeProfit
 
+    warmup_end: Timestamp = 0  # Candle time from which the strategy is mature.
     changed: Changed = field(default_factory=lambda: Changed(True))
+    previous_advice: Advice = Advice.NONE  # Strategy advice of the previous main candle.
     open_new_positions: bool = True  # Whether new positions can be opened.
     positions: list[Position.Closed] = field(default_factory=list)
     open_position: Optional[Position.Open] = None
+    open_position_entries: int = 0
+    # Price range seen while the position has been open. Determines its MAE and MFE.
+    open_position_low: Decimal = Decimal("0.0")
+    open_position_high: Decimal = Decimal("0.0")
+    set_aside: Decimal = Decimal("0.0")  # Realized profit excluded from trading capital.
+    # Quote volume of fills within the fee schedule window. Determines the fee tier. Only recorded
+    # when backtesting.
+    traded_volume: list[tuple[Timestamp, Decimal]] = field(default_factory=list)
+    # Online portfolio statistics, updated on every candle after start.
+    statistics: StreamingStatistics = field(default_factory=StreamingStatistics)
+    advice_trace: list[AdviceTraceEntry] = field(default_factory=list)
+    strategy_snapshots: list[StrategySnapshot] = field(default_factory=list)
+    equity: list[tuple[Timestamp, Decimal]] = field(default_factory=list)
     first_candle: Optional[Candle] = None
     last_candle: Optional[Candle] = None
 
     id: str = field(default_factory=lambda: str(uuid4()))
     running: bool = False
 
+    @property
+    def statistics_start(self) -> Timestamp:
+        return max(self.start, self.warmup_end) if self.config.exclude_warmup else self.start
+
     @property
     def open_positions(self) -> list[Position.Open]:
         return [self.open_position] if self.open_position else []
@@ -171,11 +247,10 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
         if not state.last_candle:
             raise ValueError("No candle received yet")
 
-        return [
-            await process_task_on_queue(
-                queue, self._open_position(state, short, state.last_candle)
-            )
-        ]
+        position = await process_task_on_queue(
+            queue, self._open_position(state, short, state.last_candle)
+        )
+        return [] if position is None else [position]
 
     async def close_positions(
         self, state: BasicState, symbols: list[str], reason: CloseReason
@@ -209,6 +284,9 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
         assert config.start is None or config.start >= 0
         assert config.end > 0
         assert config.start is None or config.end > config.start
+        assert config.max_entries > 0
+        assert 0 < config.entry_fraction <= 1
+        assert 0 <= config.reinvestment <= 1
 
         _, filters = self._informant.get_fees_filters(config.exchange, config.symbol)
         assert filters.spot
@@ -234,6 +312,24 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
             candle_type=config.candle_type,
             interval=config.interval,
         )
+        if next_ < start:
+            # Only warm up the strategy with candles which are actually available.
+            first_candle = await self._chandler.get_first_candle(
+                config.exchange, config.symbol, config.interval
+            )
+            next_ = min(max(next_, first_candle.time), start)
+        warmup_end = Trader.warmup_end(
+            start=start,
+            adjusted_start=next_,
+            strategy_maturity=strategy.maturity,
+            candle_type=config.candle_type,
+            interval=config.interval,
+        )
+        if warmup_end > start:
+            _log.info(
+                "strategy not warmed up before start; warm-up ends at "
+                f"{Timestamp_.format(warmup_end)}"
+            )
 
         return BasicState(
             config=config,
@@ -241,6 +337,7 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
             start=start,
             real_start=real_start,
             next_=next_,
+            warmup_end=warmup_end,
             quote=quote,
             starting_quote=quote,
             strategy=strategy,
@@ -250,6 +347,9 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
             take_profit=(
                 NoopTakeProfit() if config.take_profit is None else config.take_profit.construct()
             ),
+            statistics=StreamingStatistics(
+                periods_per_year=max(Interval_.YEAR // config.interval, 1)
+            ),
         )
 
     async def run(self, state: BasicState) -> TradingSummary:
@@ -299,13 +399,24 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
         config = state.config
         is_main_candle = candle_meta == (config.symbol, config.interval, config.candle_type)
 
+        if is_main_candle and state.last_candle:
+            await self._process_missed_candles(state, candle, candle_meta)
+
         await self._events.emit(config.channel, "candle", candle)
 
         if is_main_candle:
             state.stop_loss.update(candle)
             state.take_profit.update(candle)
+            if state.open_position:
+                state.open_position_low = min(state.open_position_low, candle.low)
+                state.open_position_high = max(state.open_position_high, candle.high)
 
         state.strategy.update(candle, candle_meta)
+        if is_main_candle and config.snapshot_strategy:
+            snapshot = StrategySnapshot(time=candle.time, values=state.strategy.snapshot)
+            state.strategy_snapshots.append(snapshot)
+            await self._events.emit(config.channel, "snapshot", snapshot)
+
         advice = Advice.NONE
         if is_main_candle:
             # Make sure strategy doesn't give advice during "adjusted start" period.
@@ -317,14 +428,17 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
             _log.debug(f"received advice: {advice.name}")
             if advice is not Advice.NONE:
                 assert state.strategy.mature
+                await self._events.emit(config.channel, "advice", advice)
 
         queue = self._queues[state.id]
         coro: Optional[Awaitable]
+        actions: list[str] = []
 
         # Close existing position if requested.
         await queue.join()
         if state.open_position:
             coro = None
+            num_positions = len(state.positions)
 
             if isinstance(state.open_position, Position.OpenLong):
                 if advice in {Advice.SHORT, Advice.LIQUIDATE}:
@@ -337,8 +451,18 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
                     assert advice is not Advice.LONG
                     _log.info(f"upside take profit hit at {config.take_profit}; selling")
                     coro = self._close_position(state, CloseReason.TAKE_PROFIT, candle)
+                elif state.take_profit.partial_upside_hit:
+                    _log.info(f"partial upside take profit hit at {config.take_profit}; selling")
+                    coro = self._close_partial_position(
+                        state, state.take_profit.partial_fraction, candle
+                    )
             elif isinstance(state.open_position, Position.OpenShort):
-                if advice in {Advice.LONG, Advice.LIQUIDATE}:
+                if is_main_candle and Trader.is_margin_called(
+                    state.open_position, config.maintenance_margin_rate, candle
+                ):
+                    _log.info(f"margin called at {config.maintenance_margin_rate}; liquidating")
+                    coro = self._close_position(state, CloseReason.LIQUIDATED, candle)
+                elif advice in {Advice.LONG, Advice.LIQUIDATE}:
                     coro = self._close_position(state, CloseReason.STRATEGY, candle)
                 elif state.stop_loss.downside_hit:
                     assert advice is not Advice.SHORT
@@ -348,13 +472,47 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
                     assert advice is not Advice.SHORT
                     _log.info(f"downside take profit hit at {config.take_profit}; selling")
                     coro = self._close_position(state, CloseReason.TAKE_PROFIT, candle)
+                elif state.take_profit.partial_downside_hit:
+                    _log.info(f"partial downside take profit hit at {config.take_profit}; buying")
+                    coro = self._close_partial_position(
+                        state, state.take_profit.partial_fraction, candle
+                    )
 
             if coro:
                 await process_task_on_queue(queue, coro)
+                if len(state.positions) > num_positions:
+                    # Position is kept open only if a part of it was closed.
+                    actions.append("close_partial" if state.open_position else "close")
+
+        # Positions are acted upon at the close of the candle.
+        in_session = config.session is None or config.session.allows(candle.time + config.interval)
+
+        # Scale into existing position if the advice changed to its direction again.
+        await queue.join()
+        if (
+            state.open_position
+            and state.open_new_positions
+            and in_session
+            and state.open_position_entries < config.max_entries
+            and is_main_candle
+            and state.next_ >= state.start
+        ):
+            short = isinstance(state.open_position, Position.OpenShort)
+            entry_advice = Advice.SHORT if short else Advice.LONG
+            if (
+                state.strategy.advice is entry_advice
+                and state.previous_advice is not entry_advice
+            ):
+                num_entries = state.open_position_entries
+                await process_task_on_queue(queue, self._increase_position(state, short, candle))
+                if state.open_position_entries > num_entries:
+                    actions.append("scale_in")
+        if is_main_candle:
+            state.previous_advice = state.strategy.advice
 
         # Open new position if requested.
         await queue.join()
-        if not state.open_position and state.open_new_positions:
+        if not state.open_position and state.open_new_positions and in_session:
             coro = None
 
             if config.long and advice is Advice.LONG:
@@ -364,53 +522,174 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
 
             if coro:
                 await process_task_on_queue(queue, coro)
+                if state.open_position:
+                    short = isinstance(state.open_position, Position.OpenShort)
+                    actions.append("open_short" if short else "open_long")
 
             state.stop_loss.clear(candle)
             state.take_profit.clear(candle)
 
+        if is_main_candle and state.next_ >= state.statistics_start:
+            equity = state.quote + state.set_aside + (
+                state.open_position.value(candle.close) if state.open_position else 0
+            )
+            state.statistics.update(equity)
+            if config.record_equity:
+                state.equity.append((candle.time + config.interval, equity))
+            await self._events.emit(
+                config.channel, "equity", candle.time + config.interval, equity
+            )
+
+        if is_main_candle and config.trace_advice:
+            state.advice_trace.append(
+                AdviceTraceEntry(
+                    time=candle.time,
+                    raw_advice=state.strategy.advice,
+                    advice=advice,
+                    actions=tuple(actions),
+                )
+            )
+
         if not state.first_candle:
             _log.info(f"first {config.candle_type} candle: {candle}")
             state.first_candle = candle
         state.last_candle = candle
-        state.next_ = candle.time + config.interval
+        state.next_ = Timestamp_.next(candle.time, config.interval)
+
+    async def _process_missed_candles(
+        self,
+        state: BasicState,
+        candle: Candle,
+        candle_meta: CandleMeta,
+    ) -> None:
+        config = state.config
+        assert state.last_candle
+
+        num_missed = (
+            Timestamp_.num_intervals(state.last_candle.time, candle.time, config.interval) - 1
+        )
+        if num_missed <= 0:
+            return
+
+        _log.info(
+            f"missed {num_missed} {config.candle_type} candle(s); applying "
+            f"{config.missed_candle_policy.name} policy"
+        )
+        if config.missed_candle_policy is MissedCandlePolicy.RESTART:
+            state.strategy = config.strategy.construct()
+        elif config.missed_candle_policy is MissedCandlePolicy.LAST:
+            for _ in range(num_missed):
+                last_candle = state.last_candle
+                assert last_candle
+                await self._tick(
+                    state,
+                    last_candle._replace(time=Timestamp_.next(last_candle.time, config.interval)),
+                    candle_meta,
+                )
+        elif config.missed_candle_policy is MissedCandlePolicy.INTERPOLATE:
+            for missed_candle in Candle.interpolate(state.last_candle, candle, config.interval):
+                await self._tick(state, missed_candle, candle_meta)
 
     async def _open_position(
         self,
         state: BasicState,
         short: bool,
         candle: Candle,
-    ) -> Position.Open:
+    ) -> Optional[Position.Open]:
         config = state.config
         assert not state.open_position
 
+        position = await self._open_entry(state, short, candle)
+        if position is None:
+            _log.info("maker entry not filled; skipping")
+            return None
+
+        state.quote -= position.cost
+        state.open_position = position
+        state.open_position_entries = 1
+        state.open_position_low = state.open_position_high = Fill.mean_price(position.fills)
+
+        await self._events.emit(
+            config.channel, "positions_opened", [state.open_position], self.build_summary(state)
+        )
+        return position
+
+    async def _increase_position(
+        self,
+        state: BasicState,
+        short: bool,
+        candle: Candle,
+    ) -> None:
+        config = state.config
+        assert state.open_position
+
+        try:
+            position = await self._open_entry(state, short, candle)
+        except BadOrder:
+            _log.warning("unable to scale into position; skipping")
+            return
+        if position is None:
+            _log.info("maker entry not filled; skipping")
+            return
+
+        state.quote -= position.cost
+        state.open_position = state.open_position.increase(position)  # type: ignore
+        state.open_position_entries += 1
+        _log.info(
+            f"scaled into position; entry {state.open_position_entries} of {config.max_entries}"
+        )
+
+        await self._events.emit(
+            config.channel, "positions_opened", [state.open_position], self.build_summary(state)
+        )
+
+    async def _open_entry(
+        self,
+        state: BasicState,
+        short: bool,
+        candle: Candle,
+    ) -> Optional[Position.Open]:
+        config = state.config
+        time = candle.time + config.interval
+
+        maker = config.fill_model.maker_entry_filled(config.symbol, time)
+        if (
+            config.mode is TradingMode.BACKTEST
+            and config.fill_model.entry is Liquidity.MAKER
+            and not maker
+        ):
+            return None
+
+        quote = state.quote
+        if config.entry_fraction < 1:
+            asset_info = self._informant.get_asset_info(config.exchange, config.quote_asset)
+            quote = round_down(quote * config.entry_fraction, asset_info.precision)
+
         (position,) = (
             self._simulated_positioner.open_simulated_positions(
                 exchange=config.exchange,
                 entries=[
                     (
                         config.symbol,
-                        state.quote,
+                        quote,
                         short,
-                        candle.time + config.interval,
-                        candle.close,
+                        time,
+                        Trader.simulated_open_price(config.price_impact, candle, quote, short),
                     )
                 ],
+                maker=maker,
+                fees=self._tiered_fees(state, time),
+                fee_discount=config.fee_discount,
             )
             if config.mode is TradingMode.BACKTEST
             else await self._positioner.open_positions(
                 exchange=config.exchange,
                 custodian=config.custodian,
                 mode=config.mode,
-                entries=[(config.symbol, state.quote, short)],
+                entries=[(config.symbol, quote, short)],
             )
         )
-
-        state.quote -= position.cost
-        state.open_position = position
-
-        await self._events.emit(
-            config.channel, "positions_opened", [state.open_position], self.build_summary(state)
-        )
+        self._record_traded_volume(state, time, position.fills)
         return position
 
     async def _close_position(
@@ -424,9 +703,35 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
 
         assert open_position
 
+        # Only strategy exits can rest on the book. Risk exits always execute as taker.
+        time = candle.time + config.interval
+        maker = reason is CloseReason.STRATEGY and config.fill_model.maker_exit_filled(
+            config.symbol, time
+        )
+
         (position,) = (
             self._simulated_positioner.close_simulated_positions(
-                entries=[(open_position, reason, candle.time + config.interval, candle.close)],
+                entries=[
+                    (
+                        open_position,
+                        reason,
+                        time,
+                        Trader.simulated_close_price(
+                            config.price_impact,
+                            Trader.exit_candle(
+                                state.stop_loss,
+                                candle,
+                                open_position,
+                                reason,
+                                config.maintenance_margin_rate,
+                            ),
+                            open_position,
+                        ),
+                    )
+                ],
+                maker=maker,
+                fees=self._tiered_fees(state, time),
+                fee_discount=config.fee_discount,
             )
             if config.mode is TradingMode.BACKTEST
             else await self._positioner.close_positions(
@@ -435,9 +740,13 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
                 entries=[(open_position, reason)],
             )
         )
+        self._record_traded_volume(state, time, position.close_fills)
+        position = self._with_excursions(state, open_position, position)
 
         state.quote += position.gain
+        self._set_aside_profit(state, position)
         state.open_position = None
+        state.open_position_entries = 0
         state.positions.append(position)
 
         await self._events.emit(
@@ -445,6 +754,95 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
         )
         return position
 
+    async def _close_partial_position(
+        self,
+        state: BasicState,
+        fraction: Decimal,
+        candle: Candle,
+    ) -> Optional[Position.Closed]:
+        config = state.config
+        open_position = state.open_position
+
+        assert open_position
+
+        if isinstance(open_position, Position.OpenLong):
+            # Align the closed part with the lot size so that no dust is left unaccounted for.
+            _, filters = self._informant.get_fees_filters(config.exchange, config.symbol)
+            size = filters.size.round_down(open_position.base_gain * fraction)
+            if size == 0 or size >= open_position.base_gain:
+                _log.warning("unable to close a part of the position; skipping")
+                return None
+            fraction = size / open_position.base_gain
+
+        part, rest = open_position.split(fraction)  # type: ignore
+        time = candle.time + config.interval
+
+        (position,) = (
+            self._simulated_positioner.close_simulated_positions(
+                entries=[
+                    (
+                        part,
+                        CloseReason.TAKE_PROFIT,
+                        time,
+                        Trader.simulated_close_price(config.price_impact, candle, part),
+                    )
+                ],
+                fees=self._tiered_fees(state, time),
+                fee_discount=config.fee_discount,
+            )
+            if config.mode is TradingMode.BACKTEST
+            else await self._positioner.close_positions(
+                custodian=config.custodian,
+                mode=config.mode,
+                entries=[(part, CloseReason.TAKE_PROFIT)],
+            )
+        )
+        self._record_traded_volume(state, time, position.close_fills)
+        position = self._with_excursions(state, part, position)
+
+        state.quote += position.gain
+        self._set_aside_profit(state, position)
+        state.open_position = rest
+        state.positions.append(position)
+        _log.info(f"closed {fraction} of position; remainder kept open")
+
+        await self._events.emit(
+            config.channel, "positions_closed", [position], self.build_summary(state)
+        )
+        return position
+
+    # Fees of the symbol at the tier reached by the volume traded within the fee schedule window.
+    def _tiered_fees(self, state: BasicState, time: Timestamp) -> Fees:
+        config = state.config
+        fees, _ = self._informant.get_fees_filters(config.exchange, config.symbol)
+        window_start = time - config.fee_schedule.window
+        state.traded_volume = [(t, v) for t, v in state.traded_volume if t >= window_start]
+        volume = sum((v for _, v in state.traded_volume), Decimal("0.0"))
+        return config.fee_schedule.select(fees, volume)
+
+    def _record_traded_volume(self, state: BasicState, time: Timestamp, fills: list[Fill]) -> None:
+        # Fee tiers are only evaluated, and the volume pruned, in backtest mode.
+        if state.config.mode is not TradingMode.BACKTEST:
+            return
+        state.traded_volume.append((time, sum((f.quote for f in fills), Decimal("0.0"))))
+
+    def _with_excursions(
+        self, state: BasicState, open_position: Position.Open, position: Position.Closed
+    ) -> Position.Closed:
+        mae, mfe = open_position.excursions(state.open_position_low, state.open_position_high)
+        return replace(position, mae=mae, mfe=mfe)
+
+    def _set_aside_profit(self, state: BasicState, position: Position.Closed) -> None:
+        config = state.config
+        if config.reinvestment >= 1 or position.profit <= 0:
+            return
+
+        asset_info = self._informant.get_asset_info(config.exchange, config.quote_asset)
+        amount = round_down(position.profit * (1 - config.reinvestment), asset_info.precision)
+        state.quote -= amount
+        state.set_aside += amount
+        _log.info(f"set aside {amount} {config.quote_asset} of realized profit")
+
     def build_summary(self, state: BasicState) -> TradingSummary:
         config = state.config
         start = state.start if config.mode is TradingMode.BACKTEST else state.real_start
@@ -454,6 +852,7 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
                 if state.last_candle
                 else start + config.interval
             )
+            start = min(state.statistics_start, end)
         else:  # Paper or live.
             end = min(self._get_time_ms(), config.end)
 
@@ -464,4 +863,12 @@ class Basic(Trader[BasicConfig, BasicState], StartMixin):
                 state.config.quote_asset: state.starting_quote,
             },
             positions=list(state.positions),
+            set_aside=(
+                {state.config.quote_asset: state.set_aside} if state.set_aside > 0 else {}
+            ),
+            discount_fee_cost=(
+                Decimal("0.0")
+                if config.fee_discount is None
+                else config.fee_discount.cost(state.positions)
+            ),
         )
@@ -0,0 +1,38 @@
+from decimal import Decimal
+
+from juno import Advice, Candle, Timestamp
+from juno.components import Events
+from juno.trading import Position, TradingSummary
+
+
+# Base for custom logging, equity recording or alerting without modifying the trader loop.
+# Override the callbacks of interest and `register` the hook to the channel of a trader.
+#
+# Candle, advice and equity callbacks are only invoked by the basic trader.
+class TraderHook:
+    def register(self, events: Events, channel: str) -> None:
+        events.on(channel, "candle")(self.on_candle)
+        events.on(channel, "advice")(self.on_advice)
+        events.on(channel, "equity")(self.on_equity)
+        events.on(channel, "positions_opened")(self.on_positions_opened)
+        events.on(channel, "positions_closed")(self.on_positions_closed)
+
+    async def on_candle(self, candle: Candle) -> None:
+        pass
+
+    async def on_advice(self, advice: Advice) -> None:
+        pass
+
+    # Mark-to-market value of the quote and open position at the close of the candle.
+    async def on_equity(self, time: Timestamp, value: Decimal) -> None:
+        pass
+
+    async def on_positions_opened(
+        self, positions: list[Position.Open], summary: TradingSummary
+    ) -> None:
+        pass
+
+    async def on_positions_closed(
+        self, positions: list[Position.Closed], summary: TradingSummary
+    ) -> None:
+        pass
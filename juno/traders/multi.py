@@ -9,7 +9,18 @@ from uuid import uuid4
 
 from more_itertools import take
 
-from juno import Advice, Asset, Candle, CandleType, Interval, Symbol, Timestamp, Timestamp_
+from juno import (
+    Advice,
+    Asset,
+    Candle,
+    CandleType,
+    Fill,
+    Interval,
+    Symbol,
+    Symbol_,
+    Timestamp,
+    Timestamp_,
+)
 from juno.asyncio import (
     Event,
     SlotBarrier,
@@ -18,18 +29,28 @@ from juno.asyncio import (
     process_task_on_queue,
 )
 from juno.brokers import Broker
-from juno.components import Chandler, Events, Informant, Orderbook, User
+from juno.components import Chandler, Events, Informant, Orderbook, Prices, User
 from juno.custodians import Custodian, Stub
 from juno.exchanges import Exchange
 from juno.inspect import Constructor
-from juno.math import rpstdev, split
+from juno.math import round_down, rpstdev, split
 from juno.positioner import Positioner, SimulatedPositioner
 from juno.stop_loss import Noop as NoopStopLoss
 from juno.stop_loss import StopLoss
 from juno.strategies import Changed, Signal
 from juno.take_profit import Noop as NoopTakeProfit
 from juno.take_profit import TakeProfit
-from juno.trading import CloseReason, Position, StartMixin, TradingMode, TradingSummary
+from juno.trading import (
+    CloseReason,
+    Conversion,
+    MissedCandlePolicy,
+    Position,
+    PriceImpact,
+    StartMixin,
+    TradingMode,
+    TradingSession,
+    TradingSummary,
+)
 
 from .trader import Trader
 
@@ -64,9 +85,22 @@ class MultiConfig:
     position_count: int = 2
     allowed_age_drift: int = 0
     quote_asset: str = "btc"
+    # Additional quote assets of symbols to track. Positions in such symbols are funded by
+    # converting from `quote_asset` at stored prices. Only supported in backtest mode.
+    quote_assets: list[str] = field(default_factory=list)
     repick_symbols: bool = True
     custodian: str = "stub"
     candle_type: CandleType = "regular"
+    missed_candle_policy: MissedCandlePolicy = MissedCandlePolicy.LAST
+    # Simulates a margin call for short positions. None disables the simulation.
+    maintenance_margin_rate: Optional[Decimal] = None
+    session: Optional[TradingSession] = None  # None means positions can be opened any time.
+    price_impact: Optional[PriceImpact] = None  # Only applies to backtesting.
+    max_open_positions: Optional[int] = None  # None means up to `position_count`.
+    # Maximum portfolio heat as a share of realized equity. Heat is the total open risk, i.e. the
+    # quote lost if every open position hit its stop loss. Positions without a price based stop
+    # loss risk their whole cost. None disables the limit.
+    max_heat: Optional[Decimal] = None
 
 
 @dataclass
@@ -82,6 +116,11 @@ class MultiState:
     real_start: Timestamp
     open_new_positions: bool = True  # Whether new positions can be opened.
     positions: list[Position.Closed] = field(default_factory=list)
+    # Prices of additional quote assets in quote asset for every interval from candle start.
+    conversion_prices: dict[Asset, list[Decimal]] = field(default_factory=dict)
+    conversion_fees: dict[Asset, Decimal] = field(default_factory=dict)
+    conversions: list[Conversion] = field(default_factory=list)
+    skipped_entries: int = 0  # Entries not taken due to open position or heat limits.
 
     id: str = field(default_factory=lambda: str(uuid4()))
     running: bool = False
@@ -102,11 +141,13 @@ class _SymbolState:
     take_profit: TakeProfit
     open_position: Optional[Position.Open] = None
     allocated_quote: Decimal = Decimal("0.0")
+    converted_quote: Decimal = Decimal("0.0")  # Unspent quote in the symbol's quote asset.
     first_candle: Optional[Candle] = None
     last_candle: Optional[Candle] = None
     advice: Advice = Advice.NONE
     advice_age: int = 0
     reason: CloseReason = CloseReason.STRATEGY
+    entry_skipped: bool = False  # Whether an entry on the prevailing advice has been skipped.
 
     @property
     def ready(self) -> bool:
@@ -133,9 +174,11 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         get_time_ms: Callable[[], int] = Timestamp_.now,
         exchanges: Optional[list[Exchange]] = None,
         orderbook: Optional[Orderbook] = None,
+        prices: Optional[Prices] = None,
     ) -> None:
         self._chandler = chandler
         self._informant = informant
+        self._prices = prices
         self._broker = broker
         if (
             user is not None
@@ -174,9 +217,15 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         assert config.start is None or config.end > config.start
         assert config.position_count > 0
         assert config.position_count <= config.track_count
+        assert config.max_open_positions is None or config.max_open_positions > 0
+        assert config.max_heat is None or config.max_heat > 0
         assert len(config.track) <= config.track_count
         assert not list(set(config.track) & set(config.track_exclude))  # No common elements.
         assert config.allowed_age_drift >= 0
+        assert len(config.quote_assets) == 0 or (
+            config.mode is TradingMode.BACKTEST and self._prices is not None
+        )
+        assert config.quote_asset not in config.quote_assets
 
         symbols = await self._find_top_symbols(config)
 
@@ -189,10 +238,23 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
             exchange=config.exchange, asset=config.quote_asset, amount=config.quote
         )
         position_quote = quote / config.position_count
-        for symbol in symbols:
+        for symbol in (s for s in symbols if Symbol_.assets(s)[1] == config.quote_asset):
             _, filters = self._informant.get_fees_filters(config.exchange, symbol)
             assert position_quote > filters.price.min
 
+        conversion_prices = (
+            await self._prices.map_asset_prices(
+                exchange=config.exchange,
+                assets=config.quote_assets,
+                start=start,
+                end=config.end,
+                interval=config.interval,
+                target_asset=config.quote_asset,
+            )
+            if self._prices is not None and len(config.quote_assets) > 0
+            else {}
+        )
+
         return MultiState(
             config=config,
             close_on_exit=config.close_on_exit,
@@ -205,6 +267,25 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
             ),
             start=start if config.mode is TradingMode.BACKTEST else real_start,
             symbol_states={s: self._create_symbol_state(s, start, config) for s in symbols},
+            conversion_prices=conversion_prices,
+            conversion_fees={
+                a: self._get_conversion_fee(config.exchange, a, config.quote_asset)
+                for a in config.quote_assets
+            },
+        )
+
+    def _get_conversion_fee(self, exchange: str, asset: Asset, target_asset: Asset) -> Decimal:
+        # Taker fee of the symbol converting between the assets. Assets without a common symbol
+        # are converted through btc, same as when mapping prices.
+        symbols = set(self._informant.list_symbols(exchange))
+        for symbol in [f"{asset}-{target_asset}", f"{target_asset}-{asset}"]:
+            if symbol in symbols:
+                fees, _ = self._informant.get_fees_filters(exchange, symbol)
+                return fees.taker
+        if "btc" in {asset, target_asset}:
+            raise ValueError(f"No symbol to convert between {asset} and {target_asset}")
+        return 1 - (1 - self._get_conversion_fee(exchange, asset, "btc")) * (
+            1 - self._get_conversion_fee(exchange, "btc", target_asset)
         )
 
     def _split_quote(
@@ -276,10 +357,10 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         return self.build_summary(state)
 
     async def _find_top_symbols(self, config: MultiConfig) -> list[str]:
-        symbol_pattern = f"*-{config.quote_asset}"
+        symbol_patterns = [f"*-{a}" for a in [config.quote_asset] + config.quote_assets]
         tickers = self._informant.map_tickers(
             config.exchange,
-            symbol_patterns=[symbol_pattern],
+            symbol_patterns=symbol_patterns,
             exclude_symbol_patterns=config.track_exclude,
             spot=True,
             isolated_margin=True,
@@ -305,7 +386,7 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
                 else f" with required start at {Timestamp_.format(config.track_required_start)}"
             )
             raise ValueError(
-                f"Exchange only supports {len(tickers)} symbols matching pattern {symbol_pattern} "
+                f"Exchange only supports {len(tickers)} symbols matching {symbol_patterns} "
                 f"while {config.track_count} requested{required_start_msg}"
             )
         # Compose.
@@ -427,6 +508,22 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         count = sum(1 for ss in state.symbol_states.values() if ss.open_position)
         assert count <= config.position_count
         available = config.position_count - count
+        allowed = (
+            available
+            if config.max_open_positions is None
+            else min(available, config.max_open_positions - count)
+        )
+        heat = sum(
+            (self._get_open_risk(state, ss) for ss in state.symbol_states.values()),
+            Decimal("0.0"),
+        )
+        # Profit of positions quoted in other assets is only realized through conversions, which
+        # the summary accounts for.
+        max_heat = (
+            None
+            if config.max_heat is None
+            else config.max_heat * (state.starting_quote + self.build_summary(state).profit)
+        )
         if state.open_new_positions:
             for symbol_state in (ss for ss in state.symbol_states.values() if ss.ready):
                 if available == 0:
@@ -436,15 +533,38 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
                     continue
 
                 assert symbol_state.last_candle
+                if config.session is not None and not config.session.allows(
+                    symbol_state.last_candle.time + config.interval
+                ):
+                    continue
+
                 advice_age_valid = (
                     symbol_state.changed.prevailing_advice_age - 1
                 ) <= config.allowed_age_drift
                 if config.long and symbol_state.advice is Advice.LONG and advice_age_valid:
-                    to_process.append((symbol_state, False))
-                    available -= 1
+                    short = False
                 elif config.short and symbol_state.advice is Advice.SHORT and advice_age_valid:
-                    to_process.append((symbol_state, True))
-                    available -= 1
+                    short = True
+                else:
+                    continue
+
+                risk = self._get_entry_risk(symbol_state, state.quotes[len(to_process)], short)
+                if allowed <= 0 or (max_heat is not None and heat + risk > max_heat):
+                    _log.info(
+                        f"skipping {symbol_state.symbol} entry; open positions {count} and heat "
+                        f"{heat} at limit"
+                    )
+                    # An entry may be retried on the same advice within the allowed age drift.
+                    if not symbol_state.entry_skipped:
+                        symbol_state.entry_skipped = True
+                        state.skipped_entries += 1
+                    continue
+
+                to_process.append((symbol_state, short))
+                available -= 1
+                allowed -= 1
+                count += 1
+                heat += risk
 
         if len(to_process) > 0:
             await process_task_on_queue(queue, self._open_positions(state, to_process))
@@ -460,7 +580,8 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         _log.info(f"tracking {symbol_state.symbol} candles")
 
         last_candle: Optional[Candle] = None
-        async for candle in self._chandler.stream_candles_fill_missing_with_none(
+        num_pending_missed = 0  # Missed candles waiting for the next candle to interpolate to.
+        async for optional_candle in self._chandler.stream_candles_fill_missing_with_none(
             exchange=config.exchange,
             symbol=symbol_state.symbol,
             interval=config.interval,
@@ -468,47 +589,103 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
             end=config.end,
         ):
             # Skip initial empty candles.
-            if not last_candle and not candle:
+            if not last_candle and not optional_candle:
                 continue
 
-            if not candle:
+            if optional_candle:
+                candles = (
+                    Candle.interpolate(last_candle, optional_candle, config.interval)
+                    if last_candle and num_pending_missed > 0
+                    else []
+                )
+                candles.append(optional_candle)
+                num_pending_missed = 0
+            else:
                 assert last_candle
-                # TODO: Not nice. We should proceed a symbol state without updating a strategy with
-                # a previous candle.
-                candle = last_candle
-
-            current = symbol_state.next_
-            if current < symbol_state.start:
-                # Do not signal position manager during warm-up (adjusted start) period.
-                advice, _reason = self._process_candle(state, symbol_state, candle)
-                if advice is not Advice.NONE:
-                    msg = (
-                        f"received {symbol_state.symbol} advice {advice.name} during strategy "
-                        f"warm-up period: actual start {Timestamp_.format(symbol_state.start)};"
-                        f"current {Timestamp_.format(current)}"
+                policy = config.missed_candle_policy
+                if policy is MissedCandlePolicy.INTERPOLATE:
+                    num_pending_missed += 1
+                    continue
+                if policy in {MissedCandlePolicy.IGNORE, MissedCandlePolicy.RESTART}:
+                    await self._skip_missed_candle(
+                        state, symbol_state, candles_updated, ready, policy
                     )
-                    _log.warning(msg)
-                    await self._events.emit(config.channel, "message", msg)
-            else:
-                # Perform empty ticks when missing initial candles.
-                assert candle
-                initial_missed = False
-                if (time_diff := candle.time - symbol_state.next_) > 0:
-                    assert not initial_missed
-                    assert symbol_state.next_ <= symbol_state.start
-                    initial_missed = True
-                    num_missed = time_diff // config.interval
-                    _log.info(f"missed {num_missed} initial {symbol_state.symbol} candles")
-                    for _ in range(num_missed):
-                        await self._process_advice(
-                            symbol_state, candles_updated, ready, Advice.NONE, CloseReason.STRATEGY
-                        )
+                    continue
+                candles = [last_candle._replace(time=symbol_state.next_)]
 
-                advice, reason = self._process_candle(state, symbol_state, candle)
-                await self._process_advice(symbol_state, candles_updated, ready, advice, reason)
-            if candle:
+            for candle in candles:
+                await self._track_candle(state, symbol_state, candles_updated, ready, candle)
                 last_candle = candle
 
+        # Nothing to interpolate towards at the end of the stream; repeat the last candle instead.
+        for _ in range(num_pending_missed):
+            assert last_candle
+            last_candle = last_candle._replace(time=symbol_state.next_)
+            await self._track_candle(state, symbol_state, candles_updated, ready, last_candle)
+
+    async def _track_candle(
+        self,
+        state: MultiState,
+        symbol_state: _SymbolState,
+        candles_updated: SlotBarrier,
+        ready: Event,
+        candle: Candle,
+    ) -> None:
+        config = state.config
+
+        current = symbol_state.next_
+        if current < symbol_state.start:
+            # Do not signal position manager during warm-up (adjusted start) period.
+            advice, _reason = self._process_candle(state, symbol_state, candle)
+            if advice is not Advice.NONE:
+                msg = (
+                    f"received {symbol_state.symbol} advice {advice.name} during strategy "
+                    f"warm-up period: actual start {Timestamp_.format(symbol_state.start)};"
+                    f"current {Timestamp_.format(current)}"
+                )
+                _log.warning(msg)
+                await self._events.emit(config.channel, "message", msg)
+        else:
+            # Perform empty ticks when missing initial candles.
+            if candle.time > symbol_state.next_:
+                assert symbol_state.next_ <= symbol_state.start
+                num_missed = Timestamp_.num_intervals(
+                    symbol_state.next_, candle.time, config.interval
+                )
+                _log.info(f"missed {num_missed} initial {symbol_state.symbol} candles")
+                for _ in range(num_missed):
+                    await self._process_advice(
+                        symbol_state, candles_updated, ready, Advice.NONE, CloseReason.STRATEGY
+                    )
+
+            advice, reason = self._process_candle(state, symbol_state, candle)
+            await self._process_advice(symbol_state, candles_updated, ready, advice, reason)
+
+    async def _skip_missed_candle(
+        self,
+        state: MultiState,
+        symbol_state: _SymbolState,
+        candles_updated: SlotBarrier,
+        ready: Event,
+        policy: MissedCandlePolicy,
+    ) -> None:
+        config = state.config
+
+        if policy is MissedCandlePolicy.RESTART:
+            _log.info(f"missed {symbol_state.symbol} candle; restarting strategy")
+            symbol_state.strategy = config.symbol_strategies.get(
+                symbol_state.symbol, config.strategy
+            ).construct()
+
+        current = symbol_state.next_
+        symbol_state.next_ += config.interval
+        state.next_ = max(state.next_, symbol_state.next_)
+        # Do not signal position manager during warm-up (adjusted start) period.
+        if current >= symbol_state.start:
+            await self._process_advice(
+                symbol_state, candles_updated, ready, Advice.NONE, CloseReason.STRATEGY
+            )
+
     def _process_candle(
         self, state: MultiState, symbol_state: _SymbolState, candle: Candle
     ) -> tuple[Advice, CloseReason]:
@@ -541,7 +718,16 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
             Advice.LONG,
             Advice.LIQUIDATE,
         }:
-            if symbol_state.stop_loss.downside_hit:
+            if Trader.is_margin_called(
+                symbol_state.open_position, config.maintenance_margin_rate, candle
+            ):
+                _log.info(
+                    f"{symbol_state.symbol} margin called at {config.maintenance_margin_rate}; "
+                    "liquidating"
+                )
+                advice = Advice.LIQUIDATE
+                reason = CloseReason.LIQUIDATED
+            elif symbol_state.stop_loss.downside_hit:
                 _log.info(
                     f"{symbol_state.symbol} downside stop loss hit at {config.stop_loss} "
                     f"(trailing: {config.trail_stop_loss}); liquidating"
@@ -565,7 +751,7 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
             _log.info(f"{symbol_state.symbol} first {config.candle_type} candle: {candle}")
             symbol_state.first_candle = candle
         symbol_state.last_candle = candle
-        symbol_state.next_ = candle.time + config.interval
+        symbol_state.next_ = Timestamp_.next(candle.time, config.interval)
         state.next_ = max(state.next_, symbol_state.next_)
 
         return advice, reason
@@ -580,15 +766,17 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
     ) -> None:
         _log.debug(f"{symbol_state.symbol} received advice: {advice.name} {reason.name}")
 
-        # If the advice is overridden by stop loss or take profit, we don't want to affect the
-        # strategy related `changed` filter.
-        if reason in {CloseReason.STOP_LOSS, CloseReason.TAKE_PROFIT}:
+        # If the advice is overridden by stop loss, take profit or margin call, we don't want to
+        # affect the strategy related `changed` filter.
+        if reason in {CloseReason.STOP_LOSS, CloseReason.TAKE_PROFIT, CloseReason.LIQUIDATED}:
             symbol_state.advice = advice
         else:
             # We use prevailing advice here because the configuration may allow an action based
             # on an advice given in the past.
             symbol_state.changed.update(advice)
             symbol_state.advice = symbol_state.changed.prevailing_advice
+            if symbol_state.changed.prevailing_advice_age == 1:
+                symbol_state.entry_skipped = False
 
         symbol_state.reason = reason
 
@@ -674,6 +862,20 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         for symbol_state, _ in entries:
             assert symbol_state.last_candle
             symbol_state.allocated_quote = state.quotes.pop(0)
+        quotes = [ss.allocated_quote for ss, _ in entries]
+        # Convert quotes of symbols quoted in other assets.
+        for i, (symbol_state, _) in enumerate(entries):
+            quote_asset = Symbol_.assets(symbol_state.symbol)[1]
+            if quote_asset != config.quote_asset:
+                symbol_state.allocated_quote -= quotes[i]
+                quotes[i] = self._convert(
+                    state,
+                    symbol_state.last_candle.time + config.interval,  # type: ignore
+                    config.quote_asset,
+                    quote_asset,
+                    quotes[i],
+                )
+                symbol_state.converted_quote = quotes[i]
 
         positions = (
             self._simulated_positioner.open_simulated_positions(
@@ -681,12 +883,14 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
                 entries=[
                     (
                         ss.symbol,
-                        ss.allocated_quote,
+                        quote,
                         short,
                         ss.last_candle.time + config.interval,  # type: ignore
-                        ss.last_candle.close,  # type: ignore
+                        Trader.simulated_open_price(
+                            config.price_impact, ss.last_candle, quote, short  # type: ignore
+                        ),
                     )
-                    for ss, short in entries
+                    for (ss, short), quote in zip(entries, quotes)
                 ],
             )
             if config.mode is TradingMode.BACKTEST
@@ -694,12 +898,15 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
                 exchange=config.exchange,
                 custodian=config.custodian,
                 mode=config.mode,
-                entries=[(ss.symbol, ss.allocated_quote, short) for ss, short in entries],
+                entries=[(ss.symbol, quote, short) for (ss, short), quote in zip(entries, quotes)],
             )
         )
 
         for (symbol_state, _), position in zip(entries, positions):
-            symbol_state.allocated_quote -= position.cost
+            if Symbol_.assets(symbol_state.symbol)[1] == config.quote_asset:
+                symbol_state.allocated_quote -= position.cost
+            else:
+                symbol_state.converted_quote -= position.cost
             symbol_state.open_position = position
 
         await self._events.emit(
@@ -707,6 +914,31 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         )
         return positions
 
+    # Quote lost if the position was closed at the current stop loss level.
+    def _get_open_risk(self, state: MultiState, symbol_state: _SymbolState) -> Decimal:
+        position = symbol_state.open_position
+        if position is None:
+            return Decimal("0.0")
+        short = isinstance(position, Position.OpenShort)
+        risk = position.cost * _stop_distance(
+            symbol_state.stop_loss, Fill.mean_price(position.fills), short
+        )
+        quote_asset = Symbol_.assets(position.symbol)[1]
+        if quote_asset == state.config.quote_asset:
+            return risk
+        assert symbol_state.last_candle
+        return risk * self._get_conversion_price(
+            state, symbol_state.last_candle.time + state.config.interval, quote_asset
+        )
+
+    def _get_entry_risk(
+        self, symbol_state: _SymbolState, allocated_quote: Decimal, short: bool
+    ) -> Decimal:
+        assert symbol_state.last_candle
+        return allocated_quote * _stop_distance(
+            symbol_state.stop_loss, symbol_state.last_candle.close, short
+        )
+
     async def _close_positions(
         self,
         state: MultiState,
@@ -731,7 +963,17 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
                         ss.open_position,
                         reason,
                         ss.last_candle.time + config.interval,  # type: ignore
-                        ss.last_candle.close,  # type: ignore
+                        Trader.simulated_close_price(
+                            config.price_impact,
+                            Trader.exit_candle(
+                                ss.stop_loss,
+                                ss.last_candle,  # type: ignore
+                                ss.open_position,  # type: ignore
+                                reason,
+                                config.maintenance_margin_rate,
+                            ),
+                            ss.open_position,  # type: ignore
+                        ),
                     )
                     for ss, reason in entries
                 ],
@@ -745,7 +987,18 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         )
 
         for (symbol_state, _), position in zip(entries, positions):
-            symbol_state.allocated_quote += position.gain
+            quote_asset = Symbol_.assets(symbol_state.symbol)[1]
+            if quote_asset == config.quote_asset:
+                symbol_state.allocated_quote += position.gain
+            else:
+                symbol_state.allocated_quote += self._convert(
+                    state,
+                    position.close_time,
+                    quote_asset,
+                    config.quote_asset,
+                    symbol_state.converted_quote + position.gain,
+                )
+                symbol_state.converted_quote = Decimal("0.0")
             state.quotes.append(symbol_state.allocated_quote)
             symbol_state.allocated_quote = Decimal("0.0")
 
@@ -757,6 +1010,44 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
         )
         return positions
 
+    def _convert(
+        self,
+        state: MultiState,
+        time: Timestamp,
+        from_asset: Asset,
+        to_asset: Asset,
+        amount: Decimal,
+    ) -> Decimal:
+        config = state.config
+        foreign_asset = to_asset if from_asset == config.quote_asset else from_asset
+        price = self._get_conversion_price(state, time, foreign_asset)
+        fee_rate = state.conversion_fees[foreign_asset]
+        asset_info = self._informant.get_asset_info(config.exchange, to_asset)
+
+        gross = amount / price if to_asset == foreign_asset else amount * price
+        fee = round_down(gross * fee_rate, asset_info.precision)
+        result = round_down(gross - fee, asset_info.precision)
+
+        state.conversions.append(
+            Conversion(
+                time=time,
+                from_asset=from_asset,
+                from_amount=amount,
+                to_asset=to_asset,
+                to_amount=result,
+                fee=fee,
+            )
+        )
+        _log.info(f"converted {amount} {from_asset} to {result} {to_asset} at {price}")
+        return result
+
+    # Price of the foreign asset in the primary quote asset.
+    def _get_conversion_price(self, state: MultiState, time: Timestamp, asset: Asset) -> Decimal:
+        # Prices start with the open of the first candle. Conversion happens at the close of the
+        # last candle, which is the open of the next.
+        prices = state.conversion_prices[asset]
+        return prices[min((time - state.candle_start) // state.config.interval, len(prices) - 1)]
+
     def build_summary(self, state: MultiState) -> TradingSummary:
         config = state.config
         if config.end is not None and config.end <= state.real_start:  # Backtest.
@@ -776,4 +1067,14 @@ class Multi(Trader[MultiConfig, MultiState], StartMixin):
                 state.config.quote_asset: state.starting_quote,
             },
             positions=list(state.positions),
+            conversions=list(state.conversions),
+            skipped_entries=state.skipped_entries,
         )
+
+
+# Relative distance from the price to the stop loss level against the position.
+def _stop_distance(stop_loss: StopLoss, price: Decimal, short: bool) -> Decimal:
+    level = stop_loss.downside_level if short else stop_loss.upside_level
+    if level is None or price == 0:
+        return Decimal("1.0")
+    return max((level - price) if short else (price - level), Decimal("0.0")) / price
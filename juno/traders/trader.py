@@ -1,11 +1,13 @@
 import logging
 from abc import ABC, abstractmethod
+from decimal import Decimal
 from typing import Generic, Literal, Optional, TypeVar, Union
 
-from juno import CandleType, Interval, Timestamp
+from juno import Candle, CandleType, Interval, Timestamp
 from juno.brokers import Broker
 from juno.primitives.timestamp import Timestamp_
-from juno.trading import CloseReason, Position, TradingSummary
+from juno.stop_loss import StopLoss
+from juno.trading import CloseReason, Position, PriceImpact, TradingSummary
 
 TC = TypeVar("TC")
 TS = TypeVar("TS")
@@ -77,3 +79,82 @@ class Trader(ABC, Generic[TC, TS]):
             start = Timestamp_.floor(adjusted_start, interval)
             _log.info(f"adjusted start set to {Timestamp_.format(start)}")
         return start
+
+    @staticmethod
+    def warmup_end(
+        start: Timestamp,
+        adjusted_start: Timestamp,
+        strategy_maturity: int,
+        candle_type: CandleType,
+        interval: Interval,
+    ) -> Timestamp:
+        # Time of the first candle on which the strategy is mature, taking into account any
+        # candles fetched before start to warm it up.
+        num_warmup_candles = strategy_maturity - 1
+        if candle_type == "heikin-ashi":
+            num_warmup_candles += 1
+        num_prewarmed_candles = max(start - adjusted_start, 0) // interval
+        return start + max(num_warmup_candles - num_prewarmed_candles, 0) * interval
+
+    @staticmethod
+    def is_margin_called(
+        position: Position.Open, maintenance_margin_rate: Optional[Decimal], candle: Candle
+    ) -> bool:
+        # Only short positions are leveraged. The high of the candle reaching the liquidation
+        # price is enough to liquidate.
+        return (
+            maintenance_margin_rate is not None
+            and isinstance(position, Position.OpenShort)
+            and candle.high >= position.liquidation_price(maintenance_margin_rate)
+        )
+
+    @staticmethod
+    def simulated_price(
+        price_impact: Optional[PriceImpact], candle: Candle, size: Decimal, buy: bool
+    ) -> Decimal:
+        if price_impact is None:
+            return candle.close
+        return price_impact.apply(candle.close, size, candle.volume, buy)
+
+    @staticmethod
+    def simulated_open_price(
+        price_impact: Optional[PriceImpact], candle: Candle, quote: Decimal, short: bool
+    ) -> Decimal:
+        # Size is estimated from quote at close price.
+        return Trader.simulated_price(price_impact, candle, quote / candle.close, not short)
+
+    @staticmethod
+    def exit_candle(
+        stop_loss: StopLoss,
+        candle: Candle,
+        position: Position.Open,
+        reason: CloseReason,
+        maintenance_margin_rate: Optional[Decimal],
+    ) -> Candle:
+        # Margin calls fill at the liquidation price rather than at the close of the candle, or at
+        # the open if the candle gapped past it.
+        if (
+            reason is CloseReason.LIQUIDATED
+            and maintenance_margin_rate is not None
+            and isinstance(position, Position.OpenShort)
+        ):
+            return candle._replace(
+                close=max(candle.open, position.liquidation_price(maintenance_margin_rate))
+            )
+        # Wick-aware stop losses fill at the stop price rather than at the close of the candle.
+        if reason is not CloseReason.STOP_LOSS:
+            return candle
+        price = (
+            stop_loss.downside_exit_price
+            if isinstance(position, Position.OpenShort)
+            else stop_loss.upside_exit_price
+        )
+        return candle if price is None else candle._replace(close=price)
+
+    @staticmethod
+    def simulated_close_price(
+        price_impact: Optional[PriceImpact], candle: Candle, position: Position.Open
+    ) -> Decimal:
+        if isinstance(position, Position.OpenShort):
+            return Trader.simulated_price(price_impact, candle, position.borrowed, True)
+        return Trader.simulated_price(price_impact, candle, position.base_gain, False)
@@ -2,16 +2,28 @@ from __future__ import annotations
 
 import logging
 from abc import ABC, abstractmethod
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from decimal import Decimal
 from enum import IntEnum
+from random import Random
 from types import ModuleType
 from typing import Optional, Sequence, Union
 
-from juno import AssetInfo, Fill, Interval, Symbol, Symbol_, Timestamp, Timestamp_
+from juno import (
+    Asset,
+    AssetInfo,
+    Fees,
+    Fill,
+    Interval,
+    Interval_,
+    Symbol,
+    Symbol_,
+    Timestamp,
+    Timestamp_,
+)
 from juno.asyncio import gather_dict
 from juno.components import Chandler
-from juno.math import annualized
+from juno.math import annualized, kahan_sum
 
 _log = logging.getLogger(__name__)
 
@@ -21,6 +33,7 @@ class CloseReason(IntEnum):
     STOP_LOSS = 1
     CANCELLED = 2
     TAKE_PROFIT = 3
+    LIQUIDATED = 4  # Margin call.
 
 
 class TradingMode(IntEnum):
@@ -29,6 +42,120 @@ class TradingMode(IntEnum):
     LIVE = 2
 
 
+class MissedCandlePolicy(IntEnum):
+    IGNORE = 0  # Skip the gap. Strategy is not updated for missed candles.
+    RESTART = 1  # Reconstruct the strategy. It has to mature again after the gap.
+    LAST = 2  # Update strategy with the last received candle for every missed candle.
+    INTERPOLATE = 3  # Update strategy with candles linearly interpolated over the gap.
+
+
+# Restricts the times at which new positions can be opened. Hours (0-23) and weekdays (0 is
+# Monday) are in UTC. Empty tuples allow any time. Open positions can always be closed.
+@dataclass(frozen=True)
+class TradingSession:
+    hours: tuple[int, ...] = ()
+    weekdays: tuple[int, ...] = ()
+
+    def __post_init__(self) -> None:
+        assert all(0 <= h <= 23 for h in self.hours)
+        assert all(0 <= d <= 6 for d in self.weekdays)
+
+    def allows(self, time: Timestamp) -> bool:
+        dt = Timestamp_.to_datetime_utc(time)
+        return (len(self.hours) == 0 or dt.hour in self.hours) and (
+            len(self.weekdays) == 0 or dt.weekday() in self.weekdays
+        )
+
+
+# Market impact model for simulated fills. The fill price worsens linearly by `coefficient` times
+# the participation rate, which is the order size relative to candle volume (capped at 1). For
+# example, with a coefficient of 0.1, buying 10% of the candle volume raises the price by 1%.
+@dataclass(frozen=True)
+class PriceImpact:
+    coefficient: Decimal
+
+    def __post_init__(self) -> None:
+        # Keeps sell prices positive even at full participation.
+        assert 0 <= self.coefficient < 1
+
+    def apply(self, price: Decimal, size: Decimal, volume: Decimal, buy: bool) -> Decimal:
+        participation = min(size / volume, Decimal("1.0")) if volume > 0 else Decimal("1.0")
+        impact = self.coefficient * participation
+        return price * (1 + impact) if buy else price * (1 - impact)
+
+
+class Liquidity(IntEnum):
+    TAKER = 0  # Market order filled immediately.
+    MAKER = 1  # Limit order resting on the book; may not get filled.
+
+
+# Exchange fill assumptions for simulated orders by signal type. Strategy entries and exits can
+# execute either as taker or maker. A maker order is filled with `maker_fill_probability`. An
+# unfilled maker entry is skipped while an unfilled maker exit falls back to a taker order. The
+# draws are seeded by `seed`, symbol and time so that backtests are reproducible.
+@dataclass(frozen=True)
+class FillModel:
+    entry: Liquidity = Liquidity.TAKER
+    exit: Liquidity = Liquidity.TAKER
+    maker_fill_probability: Decimal = Decimal("1.0")
+    seed: int = 0
+
+    def __post_init__(self) -> None:
+        assert 0 <= self.maker_fill_probability <= 1
+
+    def maker_entry_filled(self, symbol: Symbol, time: Timestamp) -> bool:
+        return self.entry is Liquidity.MAKER and self._maker_filled(symbol, time, "entry")
+
+    def maker_exit_filled(self, symbol: Symbol, time: Timestamp) -> bool:
+        return self.exit is Liquidity.MAKER and self._maker_filled(symbol, time, "exit")
+
+    def _maker_filled(self, symbol: Symbol, time: Timestamp, side: str) -> bool:
+        random = Random(f"{self.seed}:{symbol}:{time}:{side}")
+        return Decimal(str(random.random())) < self.maker_fill_probability
+
+
+# Selects the fee tier used when backtesting. A static `level` picks a tier directly, 0 being the
+# base rates. Otherwise the tier follows the quote volume traded by the backtest itself within the
+# trailing `window`, similar to how exchanges assign VIP levels.
+@dataclass(frozen=True)
+class FeeSchedule:
+    level: Optional[int] = None
+    window: Interval = 30 * Interval_.DAY
+
+    def select(self, fees: Fees, volume: Decimal) -> Fees:
+        return fees.for_volume(volume) if self.level is None else fees.at_level(self.level)
+
+
+# Pays trading fees in a separate asset, such as BNB on Binance, at a discounted rate. The fee is
+# converted to the discount asset using a fixed price of the discount asset in the quote asset of
+# the symbol. Only applies to backtesting.
+@dataclass(frozen=True)
+class FeeDiscount:
+    asset: Asset
+    price: Decimal  # Of discount asset in quote asset.
+    rate: Decimal = Decimal("0.25")  # Share of the fee waived.
+
+    def __post_init__(self) -> None:
+        if self.price <= 0:
+            raise ValueError("Discount asset price must be positive")
+        if not 0 <= self.rate <= 1:
+            raise ValueError("Discount rate must be between 0 and 1")
+
+    def fee(self, quote_fee: Decimal) -> Decimal:
+        return quote_fee * (1 - self.rate) / self.price
+
+    # Quote value of the fees paid in the discount asset over the positions.
+    def cost(self, positions: list[Position.Closed]) -> Decimal:
+        fees = sum(
+            (
+                Fill.all_fees(p.open_fills + p.close_fills).get(self.asset, Decimal("0.0"))
+                for p in positions
+            ),
+            Decimal("0.0"),
+        )
+        return fees * self.price
+
+
 def _calculate_roi(profit: Decimal, cost: Decimal) -> Decimal:
     # When dividing two decimals, the value may be `Decimal('0')`. For correct
     # serialization, we always want our decimal values to have at least a single decimal
@@ -40,6 +167,32 @@ def _calculate_roi(profit: Decimal, cost: Decimal) -> Decimal:
     return roi
 
 
+def _split_fills(fills: list[Fill], fraction: Decimal) -> tuple[list[Fill], list[Fill]]:
+    # Splits every fill proportionally. Remainders are computed by subtraction so that no amount
+    # is lost.
+    part = [
+        Fill(
+            price=f.price,
+            size=f.size * fraction,
+            quote=f.quote * fraction,
+            fee=f.fee * fraction,
+            fee_asset=f.fee_asset,
+        )
+        for f in fills
+    ]
+    rest = [
+        Fill(
+            price=f.price,
+            size=f.size - p.size,
+            quote=f.quote - p.quote,
+            fee=f.fee - p.fee,
+            fee_asset=f.fee_asset,
+        )
+        for f, p in zip(fills, part)
+    ]
+    return part, rest
+
+
 class Position(ModuleType):
     # TODO: Add support for external token fees (i.e BNB)
     @dataclass(frozen=True)
@@ -61,6 +214,9 @@ class Position(ModuleType):
         annualized_roi: Decimal
         dust: Decimal
         duration: Interval
+        # Maximum adverse and favorable excursions while open, relative to the mean entry price.
+        mae: Decimal = Decimal("0.0")
+        mfe: Decimal = Decimal("0.0")
 
         def __post_init__(self) -> None:
             if self.open_time < 0:
@@ -155,6 +311,53 @@ class Position(ModuleType):
                 ),
             )
 
+        # Mark-to-market value in quote asset.
+        def value(self, price: Decimal) -> Decimal:
+            return self.base_gain * price
+
+        def split(self, fraction: Decimal) -> tuple[Position.OpenLong, Position.OpenLong]:
+            # Splits the position in two to allow closing only a part of it. Cost, fees and gains
+            # are attributed proportionally.
+            assert 0 < fraction < 1
+            part_fills, rest_fills = _split_fills(self.fills, fraction)
+            part_cost = self.cost * fraction
+            part_base_gain = self.base_gain * fraction
+            return (
+                Position.OpenLong(
+                    exchange=self.exchange,
+                    symbol=self.symbol,
+                    time=self.time,
+                    fills=part_fills,
+                    cost=part_cost,
+                    base_gain=part_base_gain,
+                ),
+                Position.OpenLong(
+                    exchange=self.exchange,
+                    symbol=self.symbol,
+                    time=self.time,
+                    fills=rest_fills,
+                    cost=self.cost - part_cost,
+                    base_gain=self.base_gain - part_base_gain,
+                ),
+            )
+
+        def excursions(self, low: Decimal, high: Decimal) -> tuple[Decimal, Decimal]:
+            # Adverse and favorable excursions for the price range seen while open.
+            price = Fill.mean_price(self.fills)
+            return max(1 - low / price, Decimal("0.0")), max(high / price - 1, Decimal("0.0"))
+
+        def increase(self, other: Position.OpenLong) -> Position.OpenLong:
+            # Scales into the position. Entry time of the position is kept.
+            assert self.symbol == other.symbol
+            return Position.OpenLong(
+                exchange=self.exchange,
+                symbol=self.symbol,
+                time=self.time,
+                fills=self.fills + other.fills,
+                cost=self.cost + other.cost,
+                base_gain=self.base_gain + other.base_gain,
+            )
+
         def close(
             self,
             time: Timestamp,
@@ -197,6 +400,9 @@ class Position(ModuleType):
         annualized_roi: Decimal
         dust: Decimal
         duration: Interval
+        # Maximum adverse and favorable excursions while open, relative to the mean entry price.
+        mae: Decimal = Decimal("0.0")
+        mfe: Decimal = Decimal("0.0")
 
         def __post_init__(self) -> None:
             if self.open_time < 0:
@@ -232,8 +438,8 @@ class Position(ModuleType):
                 )
             )
             profit = gain - cost
-            # TODO: Because we don't simulate margin call liquidation, ROI can go negative.
-            # For now, we simply cap the value to min -1.
+            # Unless margin call liquidation is simulated by the trader, ROI can go below -1.
+            # We simply cap the value to min -1.
             roi = max(_calculate_roi(profit, cost), Decimal("-1.0"))
 
             duration = close_time - open_time
@@ -298,6 +504,71 @@ class Position(ModuleType):
                 base_gain=borrowed,
             )
 
+        # Mark-to-market value in quote asset. Borrow interest is not accounted for.
+        def value(self, price: Decimal) -> Decimal:
+            base_asset, quote_asset = Symbol_.assets(self.symbol)
+            proceeds = Fill.total_quote(self.fills) - Fill.total_quote_fee(
+                self.fills, base_asset, quote_asset
+            )
+            return self.collateral + proceeds - self.borrowed * price
+
+        def split(self, fraction: Decimal) -> tuple[Position.OpenShort, Position.OpenShort]:
+            # Splits the position in two to allow closing only a part of it. Collateral, borrowed
+            # amount and fees are attributed proportionally.
+            assert 0 < fraction < 1
+            part_fills, rest_fills = _split_fills(self.fills, fraction)
+            part_collateral = self.collateral * fraction
+            part_borrowed = self.borrowed * fraction
+            return (
+                Position.OpenShort.build(
+                    exchange=self.exchange,
+                    symbol=self.symbol,
+                    collateral=part_collateral,
+                    borrowed=part_borrowed,
+                    time=self.time,
+                    fills=part_fills,
+                ),
+                Position.OpenShort.build(
+                    exchange=self.exchange,
+                    symbol=self.symbol,
+                    collateral=self.collateral - part_collateral,
+                    borrowed=self.borrowed - part_borrowed,
+                    time=self.time,
+                    fills=rest_fills,
+                ),
+            )
+
+        def increase(self, other: Position.OpenShort) -> Position.OpenShort:
+            # Scales into the position. Entry time of the position is kept, meaning interest is
+            # calculated for the whole borrowed amount from the first entry.
+            assert self.symbol == other.symbol
+            return Position.OpenShort(
+                exchange=self.exchange,
+                symbol=self.symbol,
+                collateral=self.collateral + other.collateral,
+                borrowed=self.borrowed + other.borrowed,
+                time=self.time,
+                fills=self.fills + other.fills,
+                cost=self.cost + other.cost,
+                base_gain=self.base_gain + other.base_gain,
+            )
+
+        def liquidation_price(self, maintenance_margin_rate: Decimal) -> Decimal:
+            # Price at which equity (assets - debt) falls below the maintenance margin requirement
+            # (debt * rate). Assets are the collateral plus the quote received from selling the
+            # borrowed base. Interest is not accounted for.
+            _, quote_asset = Symbol_.assets(self.symbol)
+            assets = self.collateral + sum(
+                (f.quote - (f.fee if f.fee_asset == quote_asset else 0) for f in self.fills),
+                Decimal("0.0"),
+            )
+            return assets / (self.borrowed * (1 + maintenance_margin_rate))
+
+        def excursions(self, low: Decimal, high: Decimal) -> tuple[Decimal, Decimal]:
+            # Adverse and favorable excursions for the price range seen while open.
+            price = Fill.mean_price(self.fills)
+            return max(high / price - 1, Decimal("0.0")), max(1 - low / price, Decimal("0.0"))
+
         def close(
             self,
             interest: Decimal,
@@ -325,12 +596,31 @@ class Position(ModuleType):
     Closed = Union[Long, Short]
 
 
+# Exchange of one asset for another. Funds positions quoted in an asset other than the starting
+# asset and converts their gains back.
+@dataclass(frozen=True)
+class Conversion:
+    time: Timestamp
+    from_asset: Asset
+    from_amount: Decimal
+    to_asset: Asset
+    to_amount: Decimal  # Fee deducted.
+    fee: Decimal  # In to asset.
+
+
 @dataclass(frozen=True)
 class TradingSummary:
     start: Timestamp
     end: Timestamp
     starting_assets: dict[str, Decimal]
     positions: list[Position.Closed]
+    conversions: list[Conversion] = field(default_factory=list)
+    # Realized profit excluded from trading capital by asset. Already included in `profit`.
+    set_aside: dict[str, Decimal] = field(default_factory=dict)
+    # Entries not taken due to portfolio limits, such as the maximum open positions or heat.
+    skipped_entries: int = 0
+    # Quote value of `discount_fees`. Subtracted from `profit`.
+    discount_fee_cost: Decimal = Decimal("0.0")
 
     def __post_init__(self) -> None:
         if self.start < 0:
@@ -344,7 +634,57 @@ class TradingSummary:
 
     @property
     def profit(self) -> Decimal:
-        return sum((p.profit for p in self.positions), Decimal("0.0"))
+        if len(self.conversions) == 0:
+            return (
+                kahan_sum((p.profit for p in self.positions), Decimal("0.0"))
+                - self.discount_fee_cost
+            )
+
+        # Profit of positions quoted in other assets is realized through conversions.
+        # TODO: assumes only single starting asset.
+        asset = list(self.starting_assets.keys())[0]
+        return kahan_sum(
+            [p.profit for p in self.positions if Symbol_.assets(p.symbol)[1] == asset]
+            + [c.to_amount for c in self.conversions if c.to_asset == asset]
+            + [-c.from_amount for c in self.conversions if c.from_asset == asset],
+            Decimal("0.0"),
+        ) - self.discount_fee_cost
+
+    @property
+    def fees(self) -> Decimal:
+        # Fees paid in quote asset terms. Fees paid in base asset are converted using fill price.
+        result = Decimal("0.0")
+        for p in self.positions:
+            base_asset, quote_asset = Symbol_.assets(p.symbol)
+            result += Fill.total_quote_fee(p.open_fills + p.close_fills, base_asset, quote_asset)
+        return result
+
+    @property
+    def discount_fees(self) -> dict[str, Decimal]:
+        # Fees paid in assets other than the base or quote asset of a position. See `FeeDiscount`.
+        # These are paid from a separate balance and hence not included in `fees`. Their cost is
+        # subtracted from `profit` through `discount_fee_cost`.
+        result: dict[str, Decimal] = {}
+        for p in self.positions:
+            assets = Symbol_.assets(p.symbol)
+            for asset, fee in Fill.all_fees(p.open_fills + p.close_fills).items():
+                if asset not in assets:
+                    result[asset] = result.get(asset, Decimal("0.0")) + fee
+        return result
+
+    @property
+    def volume(self) -> Decimal:
+        # Total traded quote, counting both opening and closing fills.
+        return sum(
+            (Fill.total_quote(p.open_fills + p.close_fills) for p in self.positions),
+            Decimal("0.0"),
+        )
+
+    @property
+    def turnover(self) -> Decimal:
+        # Traded volume relative to starting quote.
+        # TODO: assumes only single starting asset.
+        return self.volume / list(self.starting_assets.values())[0]
 
 
 class StartMixin(ABC):
@@ -398,7 +738,7 @@ class StartMixin(ABC):
                     exchange, latest_symbol, smallest_interval
                 )
                 if smallest_latest_first_candle.time > latest_first_candle.time:
-                    result += interval
+                    result = Timestamp_.next(result, interval)
         else:
             result = Timestamp_.floor(start, interval)
 
@@ -25,6 +25,8 @@ setup(
         "api": [
             "aiohttp",
             "aiohttp_cors",
+            "brotli",
+            "msgpack",
         ],
         "dev": [
             "black",
@@ -49,6 +51,9 @@ setup(
         "plotly": [
             "plotly",
         ],
+        "redis": [
+            "redis",
+        ],
         "slack": [
             "slack_sdk",
         ],
@@ -1,7 +1,17 @@
 import asyncio
 from collections import defaultdict
 
-from juno import AssetInfo, BorrowInfo, Candle, Fees, Filters, components, storages
+from juno import (
+    AssetInfo,
+    BorrowInfo,
+    Candle,
+    Fees,
+    Filters,
+    Interval_,
+    Timestamp_,
+    components,
+    storages,
+)
 
 
 class Chandler(components.Chandler):
@@ -55,6 +65,16 @@ class Chandler(components.Chandler):
         return self.candle_intervals
 
 
+class Prices(components.Prices):
+    def __init__(self, prices={}):
+        self.prices = prices
+
+    async def map_asset_prices(
+        self, exchange, assets, start, end, interval=Interval_.DAY, target_asset="usdt"
+    ):
+        return {a: self.prices[a] for a in assets}
+
+
 class Informant(components.Informant):
     def __init__(
         self,
@@ -126,6 +146,7 @@ class Storage(storages.Memory):
         super().__init__(*args, **kwargs)
         self.stored_time_series_and_span = asyncio.Event()
         self.store_time_series_and_span_calls = []
+        self.stream_time_series_calls = []
         self.get_calls = []
         self.set_calls = []
 
@@ -135,6 +156,11 @@ class Storage(storages.Memory):
         self.stored_time_series_and_span.set()
         await asyncio.sleep(0)
 
+    async def stream_time_series(self, shard, key, type_, start=0, end=Timestamp_.MAX_TIME):
+        self.stream_time_series_calls.append((shard, key, type_, start, end))
+        async for item in super().stream_time_series(shard, key, type_, start, end):
+            yield item
+
     async def get(self, shard, key, type_):
         result = await super().get(shard, key, type_)
         self.get_calls.append((shard, key, type_, result))
@@ -143,3 +169,40 @@ class Storage(storages.Memory):
     async def set(self, shard, key, item):
         await super().set(shard, key, item)
         self.set_calls.append((shard, key, item, None))
+
+
+# Subset of `redis.asyncio.Redis` used by the Redis storage. Expiry is not simulated; use `expire`
+# to drop a key as if its TTL had passed.
+class RedisClient:
+    def __init__(self):
+        self.values = {}
+        self.sorted_sets = defaultdict(dict)
+        self.set_calls = []
+
+    async def get(self, name):
+        return self.values.get(name)
+
+    async def set(self, name, value, px=None):
+        self.values[name] = value.encode("utf-8")
+        self.set_calls.append((name, px))
+
+    async def exists(self, *names):
+        return sum(1 for name in names if name in self.values)
+
+    async def zadd(self, name, mapping):
+        self.sorted_sets[name].update(mapping)
+
+    async def zrangebyscore(self, name, min, max):
+        upper = float(max[1:]) if max.startswith("(") else float(max)
+        return [
+            member.encode("utf-8")
+            for member, score in sorted(self.sorted_sets[name].items(), key=lambda kv: kv[1])
+            if score >= float(min) and (score < upper if max.startswith("(") else score <= upper)
+        ]
+
+    async def zrem(self, name, *members):
+        for member in members:
+            self.sorted_sets[name].pop(member, None)
+
+    def expire(self, name):
+        del self.values[name]
@@ -1,3 +1,5 @@
+import pytest
+
 from juno.primitives.symbol import Symbol_
 
 
@@ -19,3 +21,28 @@ def test_swap() -> None:
 
 def test_iter_assets() -> None:
     assert list(Symbol_.iter_assets(["eth-btc", "ltc-btc"])) == ["eth", "btc", "ltc", "btc"]
+
+
+@pytest.mark.parametrize(
+    "symbol,expected_output",
+    [
+        ("eth-btc", True),
+        ("ETH-BTC", False),
+        ("eth/btc", False),
+        ("ethbtc", False),
+        ("eth-btc-usdt", False),
+    ],
+)
+def test_is_valid(symbol: str, expected_output: bool) -> None:
+    assert Symbol_.is_valid(symbol) == expected_output
+
+
+@pytest.mark.parametrize("symbol", ["eth-btc", "ETH-BTC", "eth/btc", " Eth_Btc "])
+def test_normalize(symbol: str) -> None:
+    assert Symbol_.normalize(symbol) == "eth-btc"
+
+
+@pytest.mark.parametrize("symbol", ["ethbtc", "eth--btc", "eth-btc-usdt", ""])
+def test_normalize_invalid(symbol: str) -> None:
+    with pytest.raises(ValueError):
+        Symbol_.normalize(symbol)
@@ -41,6 +41,10 @@ def test_parse(input_: str, expected_output: Timestamp) -> None:
         [1577836800000, Interval_.WEEK, 1578268800000],
         # 2020-01-02T00:00:00Z -> 2020-02-01T00:00:00Z
         [1577923200000, Interval_.MONTH, 1580515200000],
+        # 2020-01-01T00:00:00Z -> 2020-01-01T00:00:00Z
+        [1577836800000, Interval_.MONTH, 1577836800000],
+        # 2020-02-15T00:00:00Z -> 2020-04-01T00:00:00Z
+        [1581724800000, 3 * Interval_.MONTH, 1585699200000],
     ],
 )
 def test_ceil(timestamp: Timestamp, interval: Interval, expected_output: int) -> None:
@@ -56,6 +60,10 @@ def test_ceil(timestamp: Timestamp, interval: Interval, expected_output: int) ->
         [1577836800000, Interval_.WEEK, 1577664000000],
         # 2020-01-02T00:00:00Z -> 2020-01-01T00:00:00Z
         [1577923200000, Interval_.MONTH, 1577836800000],
+        # 2020-02-15T00:00:00Z -> 2020-01-01T00:00:00Z
+        [1581724800000, 3 * Interval_.MONTH, 1577836800000],
+        # 2020-06-15T00:00:00Z -> 2020-01-01T00:00:00Z
+        [1592179200000, Interval_.YEAR, 1577836800000],
     ],
 )
 def test_floor(timestamp: Timestamp, interval: Interval, expected_output: Timestamp) -> None:
@@ -68,7 +76,47 @@ def test_floor(timestamp: Timestamp, interval: Interval, expected_output: Timest
         [1, 1, True],
         [Interval_.SEC, Interval_.SEC, True],
         [Interval_.SEC, Interval_.MIN, False],
+        # 2020-02-01T00:00:00Z
+        [1580515200000, Interval_.MONTH, True],
+        [1580515200000 + Interval_.MONTH, Interval_.MONTH, False],
     ],
 )
 def test_is_in_interval(timestamp: Timestamp, interval: Interval, expected_output: bool) -> None:
     assert Timestamp_.is_in_interval(timestamp, interval) == expected_output
+
+
+@pytest.mark.parametrize(
+    "week_start,expected_output",
+    [
+        # 2020-01-01T00:00:00Z (Wednesday) -> 2019-12-30T00:00:00Z (Monday)
+        [0, 1577664000000],
+        # 2020-01-01T00:00:00Z (Wednesday) -> 2019-12-29T00:00:00Z (Sunday)
+        [6, 1577577600000],
+        # 2020-01-01T00:00:00Z (Wednesday) -> 2020-01-01T00:00:00Z (Wednesday)
+        [2, 1577836800000],
+    ],
+)
+def test_floor_week_start(week_start: int, expected_output: Timestamp) -> None:
+    assert Timestamp_.floor(1577836800000, Interval_.WEEK, week_start) == expected_output
+
+
+@pytest.mark.parametrize(
+    "timestamp,interval,expected_output",
+    [
+        [0, Interval_.DAY, Interval_.DAY],
+        # 2020-01-15T00:00:00Z -> 2020-02-01T00:00:00Z
+        [1579046400000, Interval_.MONTH, 1580515200000],
+        # 2020-02-01T00:00:00Z -> 2020-03-01T00:00:00Z
+        [1580515200000, Interval_.MONTH, 1583020800000],
+        # 2020-12-01T00:00:00Z -> 2021-01-01T00:00:00Z
+        [1606780800000, Interval_.MONTH, 1609459200000],
+    ],
+)
+def test_next(timestamp: Timestamp, interval: Interval, expected_output: Timestamp) -> None:
+    assert Timestamp_.next(timestamp, interval) == expected_output
+
+
+def test_num_intervals() -> None:
+    # 2020-01-01T00:00:00Z - 2021-01-01T00:00:00Z
+    assert Timestamp_.num_intervals(1577836800000, 1609459200000, Interval_.MONTH) == 12
+    assert Timestamp_.num_intervals(1577836800000, 1609459200000, Interval_.DAY) == 366
@@ -0,0 +1,141 @@
+import math
+from decimal import Decimal
+from typing import Iterable, Optional
+
+from juno import Advice, Candle
+from juno.common import CandleMeta
+from juno.strategies import Signal
+
+# Generators of synthetic candle series with known shapes for exercising strategy behavior.
+# Candles open at the previous close and have their high and low a `spread` away from the body.
+
+_DEFAULT_META: CandleMeta = ("eth-btc", 1, "regular")
+
+
+def from_closes(
+    closes: Iterable[Decimal],
+    start: int = 0,
+    interval: int = 1,
+    spread: Decimal = Decimal("0.5"),
+    volume: Decimal = Decimal("1.0"),
+) -> list[Candle]:
+    candles = []
+    prev_close: Optional[Decimal] = None
+    for i, close in enumerate(closes):
+        open_ = close if prev_close is None else prev_close
+        candles.append(
+            Candle(
+                time=start + i * interval,
+                open=open_,
+                high=max(open_, close) + spread,
+                low=min(open_, close) - spread,
+                close=close,
+                volume=volume,
+            )
+        )
+        prev_close = close
+    return candles
+
+
+def flat(count: int, price: Decimal = Decimal("100.0"), **kwargs) -> list[Candle]:
+    return from_closes((price for _ in range(count)), **kwargs)
+
+
+# Moves the close price by a constant `step` every candle. Negative step for a downtrend.
+def trending(
+    count: int,
+    start_price: Decimal = Decimal("100.0"),
+    step: Decimal = Decimal("1.0"),
+    **kwargs,
+) -> list[Candle]:
+    return from_closes((start_price + i * step for i in range(count)), **kwargs)
+
+
+# Oscillates the close price around `mean` along a sine wave with the given `period` in candles.
+def mean_reverting(
+    count: int,
+    mean: Decimal = Decimal("100.0"),
+    amplitude: Decimal = Decimal("10.0"),
+    period: int = 20,
+    **kwargs,
+) -> list[Candle]:
+    return from_closes(
+        (
+            mean + amplitude * Decimal(str(round(math.sin(2 * math.pi * i / period), 8)))
+            for i in range(count)
+        ),
+        **kwargs,
+    )
+
+
+# Shifts all prices from candle at `index` onwards by `gap`, leaving a price gap between the close
+# of the previous candle and the open of the candle at `index`.
+def with_gap(candles: list[Candle], index: int, gap: Decimal) -> list[Candle]:
+    return candles[:index] + [
+        Candle(
+            time=c.time,
+            open=c.open + gap,
+            high=c.high + gap,
+            low=c.low + gap,
+            close=c.close + gap,
+            volume=c.volume,
+        )
+        for c in candles[index:]
+    ]
+
+
+# Joins series into a single continuous one. Each following series is re-timed to continue from
+# the last candle and shifted so that it opens at the previous close.
+def chain(*series: list[Candle], interval: int = 1) -> list[Candle]:
+    result: list[Candle] = []
+    for candles in series:
+        if len(candles) == 0:
+            continue
+        if len(result) == 0:
+            result.extend(candles)
+            continue
+        last = result[-1]
+        shift = last.close - candles[0].open
+        result.extend(
+            Candle(
+                time=last.time + (i + 1) * interval,
+                open=c.open + shift,
+                high=c.high + shift,
+                low=c.low + shift,
+                close=c.close + shift,
+                volume=c.volume,
+            )
+            for i, c in enumerate(candles)
+        )
+    return result
+
+
+def advices(
+    signal: Signal, candles: Iterable[Candle], meta: CandleMeta = _DEFAULT_META
+) -> list[Advice]:
+    result = []
+    for candle in candles:
+        signal.update(candle, meta)
+        result.append(signal.advice if signal.mature else Advice.NONE)
+    return result
+
+
+# Collapses consecutive repeated advices and drops `Advice.NONE`, leaving only the sequence of
+# advice changes. Makes assertions independent of exact candle indices.
+def advice_changes(advices: Iterable[Advice]) -> list[Advice]:
+    result: list[Advice] = []
+    for advice in advices:
+        if advice is Advice.NONE or (len(result) > 0 and result[-1] is advice):
+            continue
+        result.append(advice)
+    return result
+
+
+def assert_advices(
+    signal: Signal,
+    candles: Iterable[Candle],
+    expected: list[Advice],
+    meta: CandleMeta = _DEFAULT_META,
+) -> None:
+    output = advice_changes(advices(signal, candles, meta))
+    assert output == expected, f"expected advice changes {expected} but got {output}"
@@ -0,0 +1,85 @@
+from decimal import Decimal
+
+import pytest
+
+from juno import Candle
+from juno.candle_csv import CandleCsvFormat, CandleRowError, parse_candles_csv
+
+
+def test_parse_kaiko() -> None:
+    text = """timestamp,open,high,low,close,volume
+0,1.0,2.0,0.5,1.5,10.0
+1000,1.5,1.0,2.0,1.5,10.0
+2000,,,,,0
+2500,1.0,1.0,1.0,1.0,1.0
+3000,1.5,3.0,1.0,2.0,5.0
+"""
+
+    candles, errors = parse_candles_csv(text, CandleCsvFormat.KAIKO, 1000)
+
+    assert candles == [
+        Candle(
+            time=0,
+            open=Decimal("1.0"),
+            high=Decimal("2.0"),
+            low=Decimal("0.5"),
+            close=Decimal("1.5"),
+            volume=Decimal("10.0"),
+        ),
+        Candle(
+            time=3000,
+            open=Decimal("1.5"),
+            high=Decimal("3.0"),
+            low=Decimal("1.0"),
+            close=Decimal("2.0"),
+            volume=Decimal("5.0"),
+        ),
+    ]
+    assert errors == [
+        CandleRowError(row=2, reason="high below low"),
+        CandleRowError(row=3, reason="malformed value"),
+        CandleRowError(row=4, reason="time not aligned to interval"),
+    ]
+
+
+def test_parse_non_finite() -> None:
+    text = """timestamp,open,high,low,close,volume
+0,NaN,1.0,1.0,1.0,1.0
+1000,1.0,Infinity,1.0,1.0,1.0
+2000,1.0,1.0,1.0,1.0,-inf
+3000,1.0,1.0,1.0,1.0,1.0
+"""
+
+    candles, errors = parse_candles_csv(text, CandleCsvFormat.KAIKO, 1000)
+
+    assert [c.time for c in candles] == [3000]
+    assert errors == [
+        CandleRowError(row=1, reason="non-finite value"),
+        CandleRowError(row=2, reason="non-finite value"),
+        CandleRowError(row=3, reason="non-finite value"),
+    ]
+
+
+def test_parse_cryptocompare() -> None:
+    text = """time,high,low,open,volumefrom,volumeto,close
+60,2.0,0.5,1.0,10.0,15.0,1.5
+"""
+
+    candles, errors = parse_candles_csv(text, CandleCsvFormat.CRYPTOCOMPARE, 60_000)
+
+    assert candles == [
+        Candle(
+            time=60_000,
+            open=Decimal("1.0"),
+            high=Decimal("2.0"),
+            low=Decimal("0.5"),
+            close=Decimal("1.5"),
+            volume=Decimal("10.0"),
+        ),
+    ]
+    assert errors == []
+
+
+def test_parse_missing_columns() -> None:
+    with pytest.raises(ValueError):
+        parse_candles_csv("time,open,close\n0,1.0,1.0\n", CandleCsvFormat.KAIKO, 1)
@@ -0,0 +1,35 @@
+import asyncio
+
+from juno import Candle
+from juno.components import CandleSyncEntry, CandleSyncer, CandleSyncStatus
+
+from . import fakes
+
+
+async def test_sync_status() -> None:
+    chandler = fakes.Chandler(
+        candles={("exchange", "eth-btc", 1): [Candle(time=0), Candle(time=1)]},
+        first_candle=Candle(time=0),
+    )
+    entry = CandleSyncEntry(exchange="exchange", symbol="eth-btc", interval=1)
+
+    async with CandleSyncer(
+        chandler=chandler,
+        entries=[entry],
+        get_time_ms=lambda: 2,
+    ) as candle_syncer:
+        assert candle_syncer.list_statuses() == [
+            CandleSyncStatus(exchange="exchange", symbol="eth-btc", interval=1)
+        ]
+
+        await asyncio.sleep(0)
+
+        assert candle_syncer.list_statuses() == [
+            CandleSyncStatus(
+                exchange="exchange",
+                symbol="eth-btc",
+                interval=1,
+                last_sync=2,
+                synced_until=2,
+            )
+        ]
@@ -18,7 +18,7 @@ from juno import (
     Trade,
 )
 from juno.asyncio import cancel, resolved_stream
-from juno.components import Chandler
+from juno.components import CandleStoreReport, Chandler
 from juno.storages import Storage
 from tests.mocks import mock_exchange, mock_stream_values, mock_trades
 
@@ -665,6 +665,55 @@ async def test_list_candles(
     assert len(candles) == 2
 
 
+async def test_store_candles(storage: fakes.Storage, mocker: MockerFixture) -> None:
+    exchange = mock_exchange(mocker, candle_intervals=[1])
+    chandler = Chandler(storage=storage, exchanges=[exchange])
+    shard = Storage.key(exchange.name, "eth-btc", 1)
+    await storage.store_time_series_and_span(shard, "candle", [Candle(time=2)], 2, 3)
+
+    candles = [
+        Candle(time=0),
+        Candle(time=1, close=Decimal("1.0")),
+        Candle(time=1, close=Decimal("2.0")),  # Duplicate.
+        Candle(time=2),  # Overlapping.
+        Candle(time=3, high=Decimal("1.0"), low=Decimal("2.0")),  # Invalid.
+        Candle(time=4),
+    ]
+
+    with pytest.raises(ValueError):
+        await chandler.store_candles(exchange.name, "eth-btc", 1, candles)
+
+    report = await chandler.store_candles(exchange.name, "eth-btc", 1, candles, repair=True)
+
+    assert report == CandleStoreReport(stored=3, duplicates=1, invalid=1, overlapping=1)
+    stored = await list_async(storage.stream_time_series(shard, "candle", Candle, 0, 5))
+    assert stored == [
+        Candle(time=0),
+        Candle(time=1, close=Decimal("2.0")),
+        Candle(time=2),
+        Candle(time=4),
+    ]
+    # The gap left by the invalid candle is not marked as covered.
+    spans = await list_async(storage.stream_time_series_spans(shard, "candle", 0, 5))
+    assert spans == [(0, 3), (4, 5)]
+
+
+async def test_store_candles_dry_run(storage: fakes.Storage, mocker: MockerFixture) -> None:
+    exchange = mock_exchange(mocker, candle_intervals=[1])
+    chandler = Chandler(storage=storage, exchanges=[exchange])
+    shard = Storage.key(exchange.name, "eth-btc", 1)
+    await storage.store_time_series_and_span(shard, "candle", [Candle(time=1)], 1, 2)
+
+    # Overlaps are reported instead of raised.
+    report = await chandler.store_candles(
+        exchange.name, "eth-btc", 1, [Candle(time=0), Candle(time=1)], dry_run=True
+    )
+
+    assert report == CandleStoreReport(stored=1, overlapping=1)
+    stored = await list_async(storage.stream_time_series(shard, "candle", Candle, 0, 2))
+    assert stored == [Candle(time=1)]
+
+
 async def test_map_symbol_interval_candles(storage: fakes.Storage, mocker: MockerFixture) -> None:
     exchange = mock_exchange(
         mocker,
@@ -687,6 +736,38 @@ async def test_map_symbol_interval_candles(storage: fakes.Storage, mocker: Mocke
     assert len(candles) == 4
 
 
+async def test_list_top_symbols_by_quote_volume(
+    storage: fakes.Storage, mocker: MockerFixture
+) -> None:
+    exchange = mock_exchange(mocker, candle_intervals=[1])
+    candles = {
+        # Mean quote volume 2.
+        "eth-btc": [
+            Candle(time=0, close=Decimal("1.0"), volume=Decimal("2.0")),
+            Candle(time=1, close=Decimal("2.0"), volume=Decimal("1.0")),
+        ],
+        # Mean quote volume 3. Larger base volume but cheaper.
+        "ltc-btc": [
+            Candle(time=0, close=Decimal("0.5"), volume=Decimal("6.0")),
+            Candle(time=1, close=Decimal("0.5"), volume=Decimal("6.0")),
+        ],
+        # Mean quote volume 2.5. Missing candle counts as zero volume.
+        "xmr-btc": [
+            Candle(time=1, close=Decimal("1.0"), volume=Decimal("5.0")),
+        ],
+    }
+    exchange.stream_historical_candles.side_effect = (
+        lambda symbol, interval, start, end: resolved_stream(*candles[symbol])
+    )
+    chandler = Chandler(storage=storage, exchanges=[exchange])
+
+    output = await chandler.list_top_symbols_by_quote_volume(
+        exchange.name, ["eth-btc", "ltc-btc", "xmr-btc"], 2, 1, 0, 2
+    )
+
+    assert output == ["ltc-btc", "xmr-btc"]
+
+
 @pytest.mark.parametrize(
     "intervals,patterns,expected_output",
     [
@@ -1,10 +1,11 @@
 from decimal import Decimal
+from random import Random
 from typing import Optional
 
 import pytest
 
 from juno import Interval_, Timestamp_
-from juno.common import Candle, Fill
+from juno.common import Candle, Fees, FeeTier, Fill
 
 
 @pytest.mark.parametrize(
@@ -88,6 +89,83 @@ def test_heikin_ashi(previous: Candle, current: Candle, expected_output: Candle)
     _assert_candle(output, expected_output, tolerance=10**-2)
 
 
+def test_interpolate() -> None:
+    previous = Candle(time=0, close=Decimal("1.0"))
+    next_ = Candle(time=4, close=Decimal("3.0"))
+
+    output = Candle.interpolate(previous, next_, 1)
+
+    assert output == [
+        Candle(
+            time=1,
+            open=Decimal("1.0"),
+            high=Decimal("1.5"),
+            low=Decimal("1.0"),
+            close=Decimal("1.5"),
+        ),
+        Candle(
+            time=2,
+            open=Decimal("1.5"),
+            high=Decimal("2.0"),
+            low=Decimal("1.5"),
+            close=Decimal("2.0"),
+        ),
+        Candle(
+            time=3,
+            open=Decimal("2.0"),
+            high=Decimal("2.5"),
+            low=Decimal("2.0"),
+            close=Decimal("2.5"),
+        ),
+    ]
+    assert Candle.interpolate(previous, previous._replace(time=1), 1) == []
+
+
+def test_interpolate_monthly() -> None:
+    previous = Candle(time=Timestamp_.parse("2020-01-01"), close=Decimal("1.0"))
+    next_ = Candle(time=Timestamp_.parse("2020-04-01"), close=Decimal("4.0"))
+
+    output = Candle.interpolate(previous, next_, Interval_.MONTH)
+
+    assert [c.time for c in output] == [
+        Timestamp_.parse("2020-02-01"),
+        Timestamp_.parse("2020-03-01"),
+    ]
+    assert [c.close for c in output] == [Decimal("2.0"), Decimal("3.0")]
+
+
+def test_merge() -> None:
+    output = Candle.merge(
+        [
+            Candle(
+                time=0,
+                open=Decimal("2.0"),
+                high=Decimal("3.0"),
+                low=Decimal("1.0"),
+                close=Decimal("2.5"),
+                volume=Decimal("1.0"),
+            ),
+            Candle(
+                time=1,
+                open=Decimal("2.5"),
+                high=Decimal("4.0"),
+                low=Decimal("2.0"),
+                close=Decimal("3.5"),
+                volume=Decimal("2.0"),
+            ),
+        ]
+    )
+
+    assert output == Candle(
+        time=0,
+        open=Decimal("2.0"),
+        high=Decimal("4.0"),
+        low=Decimal("1.0"),
+        close=Decimal("3.5"),
+        volume=Decimal("3.0"),
+    )
+
+
 def test_gen_regular() -> None:
     input = [
         Candle(
@@ -243,6 +321,28 @@ def test_gen_heikin_ashi() -> None:
         _assert_candle(output_candle, expected_output_candle, tolerance=10**-2)
 
 
+def test_gen_perturbed() -> None:
+    candle = Candle(
+        time=0,
+        open=Decimal("1.0"),
+        high=Decimal("1.05"),
+        low=Decimal("0.95"),
+        close=Decimal("1.0"),
+    )
+
+    gen_perturbed = Candle.gen_perturbed(
+        [Decimal("-0.2"), Decimal("0.2")], Decimal("0.5"), Random(1)
+    )
+    outputs = []
+    for _ in range(10):
+        next(gen_perturbed)
+        outputs.append(gen_perturbed.send(candle))
+
+    assert {c.close for c in outputs} == {Decimal("0.9"), Decimal("1.1")}
+    assert all(c.low <= c.close <= c.high for c in outputs)
+    assert all(c.time == candle.time and c.open == candle.open for c in outputs)
+
+
 def test_fill_from_cumulative() -> None:
     assert Fill.from_cumulative(
         fills=[
@@ -275,3 +375,38 @@ def _assert_candle(obtained: Candle, expected: Candle, tolerance: Optional[float
     assert obtained.low == pytest.approx(expected.low, abs=tolerance)
     assert obtained.close == pytest.approx(expected.close, abs=tolerance)
     assert obtained.volume == expected.volume
+
+
+_TIERED_FEES = Fees(
+    maker=Decimal("0.1"),
+    taker=Decimal("0.2"),
+    tiers=[
+        FeeTier(volume=Decimal("10.0"), maker=Decimal("0.05"), taker=Decimal("0.1")),
+        FeeTier(volume=Decimal("100.0"), maker=Decimal("0.0"), taker=Decimal("0.05")),
+    ],
+)
+
+
+@pytest.mark.parametrize(
+    "volume,expected_output",
+    [
+        (Decimal("0.0"), Fees(maker=Decimal("0.1"), taker=Decimal("0.2"))),
+        (Decimal("10.0"), Fees(maker=Decimal("0.05"), taker=Decimal("0.1"))),
+        (Decimal("99.9"), Fees(maker=Decimal("0.05"), taker=Decimal("0.1"))),
+        (Decimal("1000.0"), Fees(maker=Decimal("0.0"), taker=Decimal("0.05"))),
+    ],
+)
+def test_fees_for_volume(volume: Decimal, expected_output: Fees) -> None:
+    assert _TIERED_FEES.for_volume(volume) == expected_output
+
+
+def test_fees_at_level() -> None:
+    assert _TIERED_FEES.at_level(0) == Fees(maker=Decimal("0.1"), taker=Decimal("0.2"))
+    assert _TIERED_FEES.at_level(2) == Fees(maker=Decimal("0.0"), taker=Decimal("0.05"))
+    with pytest.raises(ValueError):
+        _TIERED_FEES.at_level(3)
+
+
+def test_fees_tiers_out_of_order() -> None:
+    with pytest.raises(ValueError):
+        Fees(tiers=list(reversed(_TIERED_FEES.tiers)))
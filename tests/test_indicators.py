@@ -1,11 +1,17 @@
+import inspect
+import os
+import random
 from decimal import Decimal
-from typing import TypedDict
+from typing import Any, Callable, TypedDict
 
 import pytest
 
-from juno import indicators
+from juno import Candle, indicators
+from juno.indicators import registry
 from juno.path import full_path, load_yaml_file
 
+_FIXTURE_SUFFIX = "_indicators.yaml"
+
 
 class IndicatorData(TypedDict):
     inputs: list[list[str]]
@@ -17,18 +23,13 @@ IndicatorSources = dict[str, dict[str, IndicatorData]]
 
 @pytest.fixture(scope="module")
 def data() -> IndicatorSources:
-    # Load inputs / expected outputs for all indicators.
+    # Load inputs / expected outputs for all indicators. Every `<source>_indicators.yaml` file in
+    # the data directory is picked up as a source of reference vectors.
+    data_dir = full_path(__file__, "./data")
     return {
-        file[: file.index("_indicators.yaml")]: load_yaml_file(
-            full_path(__file__, f"./data/{file}")
-        )
-        for file in [
-            "quant_connect_indicators.yaml",
-            "stock_charts_indicators.yaml",
-            "stock_market_indicators.yaml",
-            "trading_view_indicators.yaml",
-            "tulip_indicators.yaml",
-        ]
+        file[: -len(_FIXTURE_SUFFIX)]: load_yaml_file(os.path.join(data_dir, file))
+        for file in sorted(os.listdir(data_dir))
+        if file.endswith(_FIXTURE_SUFFIX)
     }
 
 
@@ -64,6 +65,16 @@ def test_atr2_wma(data: IndicatorSources) -> None:
     _assert(indicators.Atr2(3, "wma"), data["trading_view"]["atr2_wma"], 2)
 
 
+def test_awesome_oscillator() -> None:
+    target = indicators.AwesomeOscillator(2, 3)
+    target.update(Decimal("2.0"), Decimal("0.0"))
+    target.update(Decimal("4.0"), Decimal("2.0"))
+    assert not target.mature
+    # Median prices 1, 3 and 5: short sma of 4 minus long sma of 3.
+    assert target.update(Decimal("6.0"), Decimal("4.0")) == Decimal("1.0")
+    assert target.mature
+
+
 def test_bbands(data: IndicatorSources) -> None:
     _assert(indicators.Bbands(5, Decimal("2.0")), data["tulip"]["bbands"], 4)
 
@@ -112,6 +123,24 @@ def test_dm(data: IndicatorSources) -> None:
     _assert(indicators.DM(14), data["tulip"]["dm"], 4)
 
 
+def test_donchian_channel() -> None:
+    target = indicators.DonchianChannel(3)
+    target.update(Decimal("3.0"), Decimal("1.0"))
+    target.update(Decimal("5.0"), Decimal("2.0"))
+    assert not target.mature
+    assert target.update(Decimal("4.0"), Decimal("3.0")) == (
+        Decimal("1.0"),
+        Decimal("3.0"),
+        Decimal("5.0"),
+    )
+    assert target.mature
+    assert target.update(Decimal("2.0"), Decimal("2.0")) == (
+        Decimal("2.0"),
+        Decimal("3.5"),
+        Decimal("5.0"),
+    )
+
+
 def test_dx(data: IndicatorSources) -> None:
     _assert(indicators.DX(14), data["tulip"]["dx"], 4)
 
@@ -129,6 +158,24 @@ def test_kvo(data: IndicatorSources) -> None:
     _assert(indicators.Kvo(34, 55), data["tulip"]["kvo"], 3)
 
 
+def test_linreg() -> None:
+    target = indicators.LinReg(3, Decimal("2.0"))
+    target.update(Decimal("1.0"))
+    target.update(Decimal("2.0"))
+    assert not target.mature
+    assert target.update(Decimal("3.0")) == (Decimal("3.0"), Decimal("3.0"), Decimal("3.0"))
+    assert target.mature
+    assert target.slope == Decimal("1.0")
+    assert target.intercept == Decimal("1.0")
+
+    # Fitted line through 2, 3, 1 is 2.5 - 0.5 * x with residuals -0.5, 1 and -0.5.
+    lower, middle, upper = target.update(Decimal("1.0"))
+    assert target.slope == Decimal("-0.5")
+    assert target.intercept == Decimal("2.5")
+    assert middle == Decimal("1.5")
+    assert upper - middle == middle - lower == 2 * Decimal("0.5").sqrt()
+
+
 def test_lsma(data: IndicatorSources) -> None:
     _assert(indicators.Lsma(5), data["trading_view"]["lsma"], 2)
 
@@ -137,6 +184,20 @@ def test_macd(data: IndicatorSources) -> None:
     _assert(indicators.Macd(12, 26, 9), data["tulip"]["macd"], 9)
 
 
+def test_mfi() -> None:
+    target = indicators.Mfi(2)
+    target.update(Decimal("3.0"), Decimal("1.0"), Decimal("2.0"), Decimal("1.0"))  # Tp 2.
+    target.update(Decimal("4.0"), Decimal("2.0"), Decimal("3.0"), Decimal("2.0"))  # Tp 3, +6.
+    assert not target.mature
+    # Tp 2, -6.
+    assert target.update(Decimal("3.0"), Decimal("1.0"), Decimal("2.0"), Decimal("3.0")) == 50
+    assert target.mature
+    # Tp 4, +4. Oldest flow drops out of the window.
+    assert target.update(Decimal("5.0"), Decimal("3.0"), Decimal("4.0"), Decimal("1.0")) == 40
+    # Tp 4, no flow.
+    assert target.update(Decimal("5.0"), Decimal("3.0"), Decimal("4.0"), Decimal("1.0")) == 100
+
+
 def test_obv(data: IndicatorSources) -> None:
     _assert(indicators.Obv(), data["tulip"]["obv"], 4)
 
@@ -161,6 +222,19 @@ def test_stochrsi(data: IndicatorSources) -> None:
     _assert(indicators.StochRsi(5), data["tulip"]["stochrsi"], 4)
 
 
+def test_trix() -> None:
+    target = indicators.Trix(3)
+    assert target.maturity == 7
+    for _ in range(7):
+        target.update(Decimal("10.0"))
+    assert target.mature
+    assert target.value == 0
+    assert target.update(Decimal("11.0")) > 0
+    for _ in range(3):
+        target.update(Decimal("9.0"))
+    assert target.value < 0
+
+
 def test_tsi(data: IndicatorSources) -> None:
     # Precision should be 2 but it's drifting off.
     _assert(indicators.Tsi(25, 13), data["stock_charts"]["tsi"], 1)
@@ -174,6 +248,114 @@ def test_zlsma(data: IndicatorSources) -> None:
     _assert(indicators.Zlsma(2), data["trading_view"]["zlsma"], 2)
 
 
+@pytest.mark.parametrize(
+    "factory",
+    [
+        lambda: indicators.Adx(14),
+        lambda: indicators.Adxr(14),
+        lambda: indicators.Alma(9, 6),
+        lambda: indicators.Atr(4),
+        lambda: indicators.Atr2(3, "ema"),
+        lambda: indicators.AwesomeOscillator(2, 5),
+        lambda: indicators.Bbands(5, Decimal("2.0")),
+        lambda: indicators.Cci(5),
+        lambda: indicators.Cci2(5),
+        lambda: indicators.ChaikinOscillator(3, 10),
+        lambda: indicators.ChandelierExit(2, 2, 2, 3, True),
+        lambda: indicators.DarvasBox(5),
+        lambda: indicators.Dema(5),
+        lambda: indicators.DI(14),
+        lambda: indicators.DM(14),
+        lambda: indicators.DonchianChannel(5),
+        lambda: indicators.DX(14),
+        lambda: indicators.Ema(5),
+        lambda: indicators.Ema2(5),
+        lambda: indicators.Kama(4),
+        lambda: indicators.KeltnerChannel(5, 4, Decimal("2.0")),
+        lambda: indicators.Kvo(34, 55),
+        lambda: indicators.LinReg(5),
+        lambda: indicators.Lsma(5),
+        lambda: indicators.Macd(12, 26, 9),
+        lambda: indicators.Mfi(5),
+        lambda: indicators.Mmi(5),
+        lambda: indicators.Momersion(5),
+        lambda: indicators.Obv(),
+        lambda: indicators.Obv2(21),
+        lambda: indicators.ParabolicSar(),
+        lambda: indicators.Rsi(5),
+        lambda: indicators.Sma(5),
+        lambda: indicators.Smma(5),
+        lambda: indicators.Stoch(5, 3, 3),
+        lambda: indicators.StochRsi(5),
+        lambda: indicators.Trix(5),
+        lambda: indicators.Tsi(25, 13),
+        lambda: indicators.Wma(3),
+        lambda: indicators.Zlsma(2),
+    ],
+)
+@pytest.mark.parametrize("seed", [0, 1, 2])
+def test_invariants(factory: Callable[[], Any], seed: int) -> None:
+    indicator = factory()
+    inputs = list(inspect.signature(indicator.update).parameters.keys())
+    for i, candle in enumerate(_random_candles(seed, 200)):
+        outputs = indicator.update(*(_candle_input(candle, input_) for input_ in inputs))
+        if not isinstance(outputs, tuple):
+            outputs = (outputs,)
+
+        # An indicator may mature later than its advertised maturity but never earlier.
+        if i + 1 < indicator.maturity:
+            assert not indicator.mature, f"Mature at index {i} before maturity"
+        if indicator.mature:
+            assert not any(
+                isinstance(output, Decimal) and not output.is_finite() for output in outputs
+            ), f"Non-finite output at index {i}: {outputs}"
+    assert indicator.mature
+
+
+def _random_candles(seed: int, count: int) -> list[Candle]:
+    rnd = random.Random(seed)
+    result = []
+    close = Decimal("100.0")
+    for time in range(count):
+        open_ = close
+        close = max(open_ + Decimal(str(round(rnd.uniform(-5.0, 5.0), 2))), Decimal("1.0"))
+        high = max(open_, close) + Decimal(str(round(rnd.uniform(0.0, 2.0), 2)))
+        low = min(open_, close) - Decimal(str(round(rnd.uniform(0.0, 2.0), 2)))
+        low = max(low, Decimal("0.5"))
+        volume = Decimal(str(round(rnd.uniform(1.0, 100.0), 2)))
+        result.append(
+            Candle(time=time, open=open_, high=high, low=low, close=close, volume=volume)
+        )
+    return result
+
+
+def test_registry() -> None:
+    infos = registry.all_()
+
+    assert set(infos.keys()) == {
+        getattr(indicators, n).__name__.lower() for n in indicators.__all__ if n != "MA"
+    }
+    assert infos["bbands"].inputs == ["price"]
+    assert infos["bbands"].outputs == 3
+    assert [p.name for p in infos["bbands"].parameters] == ["period", "stddev"]
+    assert infos["mfi"].inputs == ["high", "low", "close", "volume"]
+    assert infos["mfi"].parameters[0].default == 14
+    assert infos["obv"].parameters == []
+    assert registry.get("Ema").ma
+    assert not registry.get("rsi").ma
+    assert isinstance(registry.create_ma("sma", 3), indicators.Sma)
+
+
+@pytest.mark.parametrize("name", ["foo", "rsi"])
+def test_registry_create_ma_invalid(name: str) -> None:
+    with pytest.raises(ValueError):
+        registry.create_ma(name, 3)
+
+
+def _candle_input(candle: Candle, name: str) -> Decimal:
+    return candle.close if name == "price" else getattr(candle, name)
+
+
 def _assert(indicator, data: IndicatorData, precision: int) -> None:
     inputs = data["inputs"]
     expected_outputs = data["outputs"]
@@ -146,3 +146,18 @@ async def test_get_asset_info(mocker: MockerFixture, storage: Storage) -> None:
     async with Informant(storage=storage, exchanges=[exchange]) as informant:
         assert informant.get_asset_info(exchange.name, "btc").precision == 2
         assert informant.get_asset_info(exchange.name, "eth").precision == 1
+
+
+async def test_set_and_sync_exchange_info(mocker: MockerFixture, storage: Storage) -> None:
+    exchange_info = ExchangeInfo(fees={"__all__": Fees(maker=Decimal("0.1"))})
+    exchange = mock_exchange(mocker, exchange_info=exchange_info)
+
+    async with Informant(storage=storage, exchanges=[exchange]) as informant:
+        override = ExchangeInfo(fees={"__all__": Fees(maker=Decimal("0.2"))})
+        await informant.set_exchange_info(exchange.name, override)
+        assert informant.get_exchange_info(exchange.name) == override
+        assert informant.get_fees_filters(exchange.name, "eth-btc")[0].maker == Decimal("0.2")
+
+        assert await informant.sync_exchange_info(exchange.name) == exchange_info
+        assert informant.get_exchange_info(exchange.name) == exchange_info
+        assert exchange.get_exchange_info.call_count == 2
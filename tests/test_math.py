@@ -1,3 +1,5 @@
+import functools
+import operator
 from decimal import Decimal
 from typing import TypeVar
 
@@ -144,6 +146,28 @@ def test_rpstdev() -> None:
     assert math.rpstdev([Decimal("10.0"), Decimal("30.0")]) == Decimal("0.5")
 
 
+def test_kahan_sum() -> None:
+    values = [1.0] + [1e-16] * 10_000
+    # Naive summation loses the small values. Built-in `sum` of floats is compensated since 3.12.
+    assert functools.reduce(operator.add, values) == 1.0
+    assert math.kahan_sum(values, 0.0) == pytest.approx(1.000000000001, abs=1e-15)
+
+
+def test_kahan_sum_decimal() -> None:
+    values = [Decimal("1e30"), Decimal("1.0"), Decimal("-1e30")] * 1000
+    assert sum(values, Decimal("0.0")) != Decimal("1000")
+    assert math.kahan_sum(values, Decimal("0.0")) == Decimal("1000")
+
+
+def test_welford() -> None:
+    welford = math.Welford()
+    for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]:
+        welford.update(value)
+    assert welford.count == 8
+    assert welford.mean == pytest.approx(5.0)
+    assert welford.variance == pytest.approx(4.0)
+
+
 @pytest.mark.parametrize(
     "precision,expected_output",
     [
@@ -0,0 +1,44 @@
+import pytest
+
+from juno.metrics import Registry
+
+
+def test_render() -> None:
+    registry = Registry()
+    counter = registry.counter("requests_total", "Requests.")
+    gauge = registry.gauge("in_progress", "In progress.")
+    histogram = registry.histogram("duration_seconds", "Duration.", buckets=[0.1, 1.0])
+
+    counter.inc(route="/a")
+    counter.inc(2, route="/a")
+    counter.inc(route='/"b"')
+    gauge.inc()
+    gauge.dec()
+    histogram.observe(0.1)
+    histogram.observe(0.5)
+    histogram.observe(5.0)
+
+    assert counter.get(route="/a") == 3.0
+    assert registry.render() == (
+        "# HELP requests_total Requests.\n"
+        "# TYPE requests_total counter\n"
+        'requests_total{route="/a"} 3.0\n'
+        'requests_total{route="/\\"b\\""} 1.0\n'
+        "# HELP in_progress In progress.\n"
+        "# TYPE in_progress gauge\n"
+        "in_progress 0.0\n"
+        "# HELP duration_seconds Duration.\n"
+        "# TYPE duration_seconds histogram\n"
+        'duration_seconds_bucket{le="0.1"} 1\n'
+        'duration_seconds_bucket{le="1.0"} 2\n'
+        'duration_seconds_bucket{le="+Inf"} 3\n'
+        "duration_seconds_sum 5.6\n"
+        "duration_seconds_count 3\n"
+    )
+
+
+def test_register_twice() -> None:
+    registry = Registry()
+    registry.counter("requests_total", "Requests.")
+    with pytest.raises(ValueError):
+        registry.gauge("requests_total", "Requests.")
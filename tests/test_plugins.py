@@ -1,12 +1,13 @@
 from decimal import Decimal
-from typing import Any
+from typing import Any, Optional
 
 import pytest
 from pytest_mock import MockerFixture
 
-from juno import AssetInfo, Candle, Fill, Interval_
+from juno import AssetInfo, Candle, Fill, Interval_, json
 from juno.components import Chandler, Events, Informant
 from juno.path import full_path
+from juno.plugins.alerts import Alerts
 from juno.trading import CloseReason, Position, TradingSummary
 
 
@@ -43,6 +44,113 @@ async def test_slack(request, config: dict[str, Any]) -> None:
     await send_test_events(events)
 
 
+@pytest.mark.manual
+@pytest.mark.plugin
+async def test_alerts(request, config: dict[str, Any]) -> None:
+    skip_non_configured(request, config)
+
+    events = Events()
+    async with Alerts(events=events, config=config) as alerts:
+        await alerts.activate("agent", "test")
+
+        await send_test_events(events)
+
+
+@pytest.mark.parametrize(
+    "alerts_config",
+    [
+        {},
+        {"webhook_urls": "https://example.com/hook"},
+        {"telegram": {"chat_id": "1"}},
+        {"webhook_urls": ["https://example.com/hook"], "max_attempts": 0},
+        {"webhook_urls": ["https://example.com/hook"], "timeout": 0},
+    ],
+)
+def test_alerts_invalid_config(alerts_config: dict[str, Any]) -> None:
+    with pytest.raises(ValueError):
+        Alerts(events=Events(), config={"alerts": alerts_config})
+
+
+async def test_alerts_messages() -> None:
+    events = Events()
+    alerts = Alerts(
+        events=events,
+        config={
+            "alerts": {
+                "webhook_urls": ["https://example.com/hook"],
+                "telegram": {"token": "secret", "chat_id": "1"},
+            }
+        },
+    )
+    session = _StubSession()
+    alerts._session = session  # type: ignore
+    await alerts.activate("agent", "test")
+
+    await events.emit("agent", "message", "a" * 5000)
+    await alerts.flush()
+
+    assert session.posts == [
+        (
+            "https://example.com/hook",
+            {"agent": "agent", "agent_type": "test", "event": "message", "data": "a" * 5000},
+        ),
+        (
+            "https://api.telegram.org/botsecret/sendMessage",
+            {"chat_id": "1", "text": "Agent agent message:\n\"" + "a" * 4074},
+        ),
+        (
+            "https://api.telegram.org/botsecret/sendMessage",
+            {"chat_id": "1", "text": "a" * 926 + "\""},
+        ),
+    ]
+
+
+async def test_alerts_failure_not_logging_secrets(mocker: MockerFixture) -> None:
+    log = mocker.patch("juno.plugins.alerts._log")
+    events = Events()
+    alerts = Alerts(
+        events=events,
+        config={
+            "alerts": {
+                "telegram": {"token": "secret", "chat_id": "1"},
+                "max_attempts": 2,
+                "timeout": 0.01,
+            }
+        },
+    )
+    session = _StubSession(status=500)
+    alerts._session = session  # type: ignore
+    await alerts.activate("agent", "test")
+
+    await events.emit("agent", "message", "hello")
+    await alerts.flush()
+
+    assert len(session.posts) == 2
+    assert log.warning.call_count == 2
+    assert all("secret" not in str(call) for call in log.warning.call_args_list)
+
+
+class _StubResponse:
+    def __init__(self, status: int) -> None:
+        self.status = status
+
+    async def __aenter__(self) -> "_StubResponse":
+        return self
+
+    async def __aexit__(self, *args: Any) -> None:
+        pass
+
+
+class _StubSession:
+    def __init__(self, status: int = 200) -> None:
+        self.posts: list[tuple[str, Any]] = []
+        self._status = status
+
+    def post(self, url: str, data: str, headers: Optional[dict[str, str]] = None) -> _StubResponse:
+        self.posts.append((url, json.loads(data)))
+        return _StubResponse(self._status)
+
+
 async def send_test_events(events: Events):
     trading_summary = TradingSummary(
         start=0,
@@ -73,6 +73,100 @@ async def test_map_asset_prices(
     assert output == expected_output
 
 
+async def test_map_asset_prices_reversed_symbol_and_cached(mocker: MockerFixture) -> None:
+    candles = [
+        Candle(time=0, open=Decimal("2.0"), close=Decimal("1.0")),
+        Candle(time=1, close=Decimal("2.0")),
+        Candle(time=2, close=Decimal("4.0")),
+    ]
+    chandler = mock_chandler(
+        mocker,
+        candles=candles,
+        first_candle=candles[0],
+        last_candle=candles[-1],
+    )
+    prices = Prices(
+        informant=mock_informant(mocker, symbols=["eur-usdt"]),
+        chandler=chandler,
+        get_time_ms=lambda: 3,
+    )
+    for _ in range(2):
+        output = await prices.map_asset_prices(
+            exchange="exchange",
+            assets=["usdt"],
+            interval=1,
+            target_asset="eur",
+            start=0,
+            end=3,
+        )
+        assert output == {
+            "usdt": [Decimal("0.5"), Decimal("1.0"), Decimal("0.5"), Decimal("0.25")],
+        }
+    chandler.stream_candles_fill_missing_with_none.assert_called_once()
+
+
+async def test_map_asset_prices_reversed_symbol_zero_price(mocker: MockerFixture) -> None:
+    candles = [
+        Candle(time=0, open=Decimal("2.0"), close=Decimal("0.0")),
+        Candle(time=1, close=Decimal("4.0")),
+    ]
+    prices = Prices(
+        informant=mock_informant(mocker, symbols=["eur-usdt"]),
+        chandler=mock_chandler(
+            mocker,
+            candles=candles,
+            first_candle=candles[0],
+            last_candle=candles[-1],
+        ),
+    )
+    output = await prices.map_asset_prices(
+        exchange="exchange",
+        assets=["usdt"],
+        interval=1,
+        target_asset="eur",
+        start=0,
+        end=2,
+    )
+    assert output["usdt"][0] == Decimal("0.5")
+    assert output["usdt"][1].is_nan()
+    assert output["usdt"][2] == Decimal("0.25")
+
+
+async def test_map_asset_prices_cache_evicts_least_recently_used(mocker: MockerFixture) -> None:
+    candles = [
+        Candle(time=0, open=Decimal("1.0"), close=Decimal("2.0")),
+        Candle(time=1, close=Decimal("3.0")),
+        Candle(time=2, close=Decimal("4.0")),
+    ]
+    chandler = mock_chandler(
+        mocker,
+        candles=candles,
+        first_candle=candles[0],
+        last_candle=candles[-1],
+    )
+    prices = Prices(
+        informant=mock_informant(mocker, symbols=["eth-usdt"]),
+        chandler=chandler,
+        get_time_ms=lambda: 3,
+        cache_size=1,
+    )
+
+    for end in [3, 3, 3, 2, 3]:
+        output = await prices.map_asset_prices(
+            exchange="exchange",
+            assets=["eth"],
+            interval=1,
+            target_asset="usdt",
+            start=0,
+            end=end,
+        )
+        # Modifying the output does not affect the cached prices.
+        assert len(output["eth"]) > 0
+        output["eth"].clear()
+
+    assert chandler.stream_candles_fill_missing_with_none.call_count == 3
+
+
 async def test_map_asset_prices_insufficient_prices(mocker: MockerFixture) -> None:
     prices = Prices(
         informant=mock_informant(mocker, symbols=["btc-usdt"]),
@@ -1,5 +1,6 @@
 import asyncio
 import random
+import sqlite3
 from abc import ABC, abstractmethod
 from dataclasses import dataclass
 from decimal import Decimal
@@ -9,8 +10,11 @@ import pytest
 from asyncstdlib import list as list_async
 
 from juno import AssetInfo, Candle, ExchangeInfo, Fees, Fill, Filters, Ticker, Trade, storages
+from juno.storages.migrations import Migration
+from juno.storages.redis import Redis
 from juno.trading import CloseReason, Position, TradingSummary
 from juno.typing import types_match
+from tests import fakes
 
 DECIMAL_TOO_PRECISE_FOR_FLOAT = Decimal("0.1234567890123456789012345678901234567890123456789")
 
@@ -262,3 +266,132 @@ async def test_memory_get_set_dynamic_types(storage: storages.Storage) -> None:
     assert output.optional
     assert isinstance(output.optional, Concrete)
     assert output.optional.value == 1
+
+
+async def test_redis_shares_time_series_between_instances() -> None:
+    client = fakes.RedisClient()
+    candles = [Candle(time=0), Candle(time=1), Candle(time=2)]
+    async with (
+        Redis(storages.Memory(), client=client, time_series_ttl=1000) as first,
+        Redis(storages.Memory(), client=client, time_series_ttl=1000) as second,
+    ):
+        await first.store_time_series_and_span("shard", "key", candles[:2], 0, 2)
+        await second.store_time_series_and_span("shard", "key", candles[2:], 2, 3)
+
+        for storage in [first, second]:
+            output_spans, output_items = await asyncio.gather(
+                list_async(storage.stream_time_series_spans("shard", "key", 0, 3)),
+                list_async(storage.stream_time_series("shard", "key", Candle, 1, 3)),
+            )
+            assert output_spans == [(0, 3)]
+            assert output_items == candles[1:]
+
+        assert all(px == 1000 for _, px in client.set_calls)
+
+
+async def test_redis_expired_time_series_falls_back_to_storage() -> None:
+    client = fakes.RedisClient()
+    candles = [Candle(time=0), Candle(time=1)]
+    async with (
+        Redis(storages.Memory(), client=client) as first,
+        Redis(storages.Memory(), client=client) as second,
+    ):
+        await first.store_time_series_and_span("shard", "key", candles, 0, 2)
+        client.expire(next(iter(client.values.keys())))
+
+        assert await list_async(first.stream_time_series_spans("shard", "key", 0, 2)) == [(0, 2)]
+        assert await list_async(first.stream_time_series("shard", "key", Candle, 0, 2)) == candles
+        assert await list_async(second.stream_time_series_spans("shard", "key", 0, 2)) == []
+        assert await list_async(second.stream_time_series("shard", "key", Candle, 0, 2)) == []
+
+
+async def test_redis_reads_only_uncached_spans_from_storage() -> None:
+    client = fakes.RedisClient()
+    backing = fakes.Storage()
+    candles = [Candle(time=0), Candle(time=1), Candle(time=2)]
+    async with Redis(backing, client=client) as storage:
+        await storage.store_time_series_and_span("shard", "key", candles[:2], 0, 2)
+        # Stored bypassing the cache.
+        await backing.store_time_series_and_span("shard", "key", candles[2:], 2, 3)
+
+        output = await list_async(storage.stream_time_series("shard", "key", Candle, 1, 4))
+
+        assert output == candles[1:]
+        assert backing.stream_time_series_calls == [("shard", "key", Candle, 2, 4)]
+        assert storage.hits == 1
+        assert storage.misses == 1
+
+
+async def test_redis_get_set() -> None:
+    client = fakes.RedisClient()
+    async with (
+        Redis(storages.Memory(), client=client, item_ttl=1000) as first,
+        Redis(storages.Memory(), client=client, item_ttl=1000) as second,
+    ):
+        await first.set("shard", "key", Fees(maker=Decimal("0.1")))
+
+        assert await second.get("shard", "key", Fees) == Fees(maker=Decimal("0.1"))
+
+        # Read through to the backing storage and cache the result.
+        client.expire(next(iter(client.values.keys())))
+        assert await second.get("shard", "key", Fees) is None
+        assert await first.get("shard", "key", Fees) == Fees(maker=Decimal("0.1"))
+        assert await second.get("shard", "key", Fees) == Fees(maker=Decimal("0.1"))
+        assert client.set_calls[-1][1] == 1000
+
+
+async def test_memory_applies_migrations_once() -> None:
+    applied = []
+
+    def create_table(conn: sqlite3.Connection) -> None:
+        applied.append(1)
+        conn.execute("CREATE TABLE note (text TEXT NOT NULL)")
+
+    def insert_row(conn: sqlite3.Connection) -> None:
+        applied.append(2)
+        conn.execute("INSERT INTO note VALUES ('hello')")
+
+    migrations = [
+        Migration(version=1, description="create table", apply=create_table),
+        Migration(version=2, description="insert row", apply=insert_row),
+    ]
+    async with storages.Memory(migrations=migrations) as memory:
+        assert await memory.migrate("shard") == 2
+        await memory.set("shard", "key", Fees())
+
+        assert await memory.get("shard", "key", Fees) == Fees()
+        assert applied == [1, 2]
+        assert memory.version is not None and memory.version.endswith(".2")
+        with memory._connect("shard") as conn:
+            assert list(conn.execute("SELECT * FROM note")) == [("hello",)]
+            assert [r[0] for r in conn.execute("SELECT version FROM migration")] == [1, 2]
+
+
+async def test_memory_failed_migration_rolled_back() -> None:
+    def fail(conn: sqlite3.Connection) -> None:
+        raise ValueError("boom")
+
+    migrations = [
+        Migration(
+            version=1,
+            description="create table",
+            apply=lambda conn: conn.execute("CREATE TABLE a (b TEXT)"),
+        ),
+        Migration(version=2, description="fail", apply=fail),
+    ]
+    async with storages.Memory(migrations=migrations) as memory:
+        with pytest.raises(ValueError):
+            await memory.migrate("shard")
+
+        with memory._connect("shard") as conn:
+            assert list(conn.execute("SELECT version FROM migration")) == []
+
+
+@pytest.mark.parametrize("versions", [[2, 1], [1, 1], [0]])
+def test_invalid_migration_versions(versions: list[int]) -> None:
+    with pytest.raises(ValueError):
+        storages.Memory(
+            migrations=[
+                Migration(version=v, description="", apply=lambda _: None) for v in versions
+            ]
+        )
@@ -1,11 +1,16 @@
 import operator
+from decimal import Decimal
+from random import Random
+from typing import Optional
 
 import pytest
 
-from juno import Advice, Candle, strategies
+from juno import Advice, Candle, Interval_, strategies
 from juno.common import CandleMeta
 from juno.constraints import Int, Pair
 from juno.strategies import MidTrendPolicy, Sig, Strategy
+from juno.strategies.strategy import persistence_level
+from tests import synthetic
 
 
 class DummyStrategy(Strategy):
@@ -190,3 +195,457 @@ def test_sig_extra_maturity_changed_enabled(extra_maturity, advices, expected_ad
     sig.update(Candle(time=1), ("eth-btc", 1, "regular"))
 
     assert sig.advice is expected_advice
+
+
+def test_snapshot() -> None:
+    target = strategies.SigOsc(
+        sig={
+            "type": "doublema",
+            "short_ma": "sma",
+            "long_ma": "sma",
+            "short_period": 1,
+            "long_period": 2,
+        },
+        osc={"type": "rsi", "period": 2},
+    )
+
+    for i, close in enumerate(["1.0", "2.0", "3.0"]):
+        target.update(Candle(time=i, close=Decimal(close)), ("eth-btc", 1, "regular"))
+
+    assert target.snapshot == {
+        "sig.short_ma": Decimal("3.0"),
+        "sig.long_ma": Decimal("2.5"),
+        "osc.rsi": Decimal("100"),
+    }
+    assert strategies.Fixed(advices=[]).snapshot == {}
+
+
+@pytest.mark.parametrize(
+    "persistence_duration,interval,expected_output",
+    [
+        (None, None, 3),
+        (4 * Interval_.HOUR, Interval_.HOUR, 4),
+        (4 * Interval_.HOUR, 4 * Interval_.HOUR, 1),
+        (90 * Interval_.MIN, Interval_.HOUR, 2),
+    ],
+)
+def test_persistence_level(
+    persistence_duration: Optional[int], interval: Optional[int], expected_output: int
+) -> None:
+    assert persistence_level(3, persistence_duration, interval) == expected_output
+
+
+def test_persistence_level_duration_requires_interval() -> None:
+    with pytest.raises(ValueError):
+        persistence_level(0, Interval_.HOUR, None)
+
+
+def test_sig_persistence_duration() -> None:
+    sig = Sig(
+        sig={"type": "fixed", "advices": ["long", "long", "long"]},
+        persistence_duration=2 * Interval_.HOUR,
+        interval=Interval_.HOUR,
+    )
+    assert sig.maturity == Sig(sig={"type": "fixed", "advices": []}, persistence=2).maturity
+
+
+def test_fixed_random_advices() -> None:
+    output = strategies.Fixed.random_advices(20, 3, [Advice.LONG, Advice.SHORT], Random(1))
+
+    assert len(output) == 20
+    assert output.count(Advice.LIQUIDATE) == 3
+    # Every position is opened before it is closed.
+    opens = sum(
+        1
+        for previous, current in zip([Advice.NONE] + output, output)
+        if current in {Advice.LONG, Advice.SHORT} and previous is not current
+    )
+    assert opens == 3
+    with pytest.raises(ValueError):
+        strategies.Fixed.random_advices(5, 3, [Advice.LONG], Random(1))
+
+
+def test_rsi_divergence() -> None:
+    target = strategies.RsiDivergence(period=2, lookback=3, min_divergence=Decimal("5.0"))
+    closes = ["10", "9", "8", "7", "8", "7.5", "6.9", "7", "8", "9", "10", "9.5", "10.1"]
+    advices = []
+    for time, close in enumerate(closes):
+        target.update(Candle(time=time, close=Decimal(close)), ("eth-btc", 1, "regular"))
+        advices.append(target.advice)
+
+    assert target.maturity == 6
+    # Bullish divergence: a lower close (6.9 < 7) with a higher RSI.
+    assert advices[5] is Advice.NONE
+    assert advices[6] is Advice.LONG
+    # Bearish divergence: a higher close (10.1 > 10) with a lower RSI.
+    assert advices[11] is Advice.LONG
+    assert advices[12] is Advice.SHORT
+
+
+def test_regime_filter() -> None:
+    target = strategies.RegimeFilter(
+        sig={
+            "type": "fixed",
+            "advices": ["long"] * 7,
+        },
+        measure="stdev",
+        period=2,
+        lookback=3,
+        min_percentile=Decimal("0.0"),
+        max_percentile=Decimal("0.5"),
+    )
+    closes = ["1", "1", "1", "1", "2", "2", "2"]
+    advices = []
+    for time, close in enumerate(closes):
+        target.update(Candle(time=time, close=Decimal(close)), ("eth-btc", 1, "regular"))
+        advices.append(target.advice)
+
+    assert target.maturity == 5
+    assert advices == [
+        Advice.NONE,
+        Advice.NONE,
+        Advice.NONE,
+        Advice.NONE,
+        Advice.LIQUIDATE,  # Volatility spike.
+        Advice.LIQUIDATE,
+        Advice.LONG,  # Volatility settled.
+    ]
+
+
+@pytest.mark.parametrize(
+    "mode,expected_advices",
+    [
+        ("unanimous", [Advice.LONG, Advice.LIQUIDATE, Advice.NONE]),
+        ("majority", [Advice.LONG, Advice.LONG, Advice.NONE]),
+    ],
+)
+def test_combine(mode: str, expected_advices: list[Advice]) -> None:
+    target = strategies.Combine(
+        sigs=[
+            {"type": "fixed", "advices": ["long", "long", "none"]},
+            {"type": "fixed", "advices": ["long", "short", "none"]},
+            {
+                "type": "combine",
+                "sigs": [{"type": "fixed", "advices": ["long", "long", "long"]}],
+            },
+        ],
+        mode=mode,
+    )
+    advices = []
+    for time in range(3):
+        target.update(Candle(time=time), ("eth-btc", 1, "regular"))
+        advices.append(target.advice)
+
+    assert advices == expected_advices
+
+
+def test_custom_signal() -> None:
+    target = strategies.CustomSignal("sma(close, 2) > sma(close, 3) ? long : short")
+    closes = ["1", "2", "3", "2", "1", "2"]
+    advices = []
+    for time, close in enumerate(closes):
+        target.update(Candle(time=time, close=Decimal(close)), ("eth-btc", 1, "regular"))
+        advices.append(target.advice)
+
+    assert target.maturity == 3
+    assert advices == [
+        Advice.NONE,
+        Advice.NONE,
+        Advice.LONG,  # 2.5 > 2
+        Advice.LONG,  # 2.5 > 2.33
+        Advice.SHORT,  # 1.5 < 2
+        Advice.SHORT,  # 1.5 < 1.67
+    ]
+
+
+@pytest.mark.parametrize(
+    "expression,expected_maturity",
+    [
+        ("long", 1),
+        ("close > 1 and -volume < 0 ? long : none", 1),
+        ("ema(rsi(close, 3), 2) > 50 ? long : (close < 2 ? short : liquidate)", 5),
+    ],
+)
+def test_custom_signal_expressions(expression: str, expected_maturity: int) -> None:
+    assert strategies.CustomSignal(expression).maturity == expected_maturity
+
+
+@pytest.mark.parametrize(
+    "expression",
+    [
+        "close >",
+        "foo",
+        "close ? long",
+        "(long",
+        "bbands(close, 20, 2) > 0 ? long : none",
+        "sma(close, close) > 0 ? long : none",
+    ],
+)
+def test_custom_signal_invalid_expression(expression: str) -> None:
+    with pytest.raises(ValueError):
+        strategies.CustomSignal(expression)
+
+
+def test_custom_signal_division_by_zero() -> None:
+    target = strategies.CustomSignal("close / (high - low) > 1 ? long : short")
+    advices = []
+    for time, high in enumerate(["2", "1", "2"]):
+        target.update(
+            Candle(time=time, high=Decimal(high), low=Decimal("1"), close=Decimal("3")),
+            ("eth-btc", 1, "regular"),
+        )
+        advices.append(target.advice)
+
+    assert advices == [Advice.LONG, Advice.NONE, Advice.LONG]
+
+
+_TREND_FOLLOWERS = [
+    strategies.FourWeekRule,
+    strategies.Macd,
+    strategies.DoubleMA,
+    strategies.TripleMA,
+    lambda: strategies.SingleMA(period=20),
+    strategies.DonchianBreakout,
+    strategies.KeltnerBreakout,
+    strategies.ParabolicSar,
+    strategies.ChandelierExit,
+    strategies.TrendSlope,
+    strategies.Trix,
+]
+
+
+@pytest.mark.parametrize("signal_factory", _TREND_FOLLOWERS)
+def test_trend_follower_follows_uptrend(signal_factory) -> None:
+    synthetic.assert_advices(signal_factory(), synthetic.trending(80), [Advice.LONG])
+
+
+@pytest.mark.parametrize("signal_factory", _TREND_FOLLOWERS)
+def test_trend_follower_follows_downtrend(signal_factory) -> None:
+    synthetic.assert_advices(
+        signal_factory(),
+        synthetic.trending(80, start_price=Decimal("200.0"), step=Decimal("-1.0")),
+        [Advice.SHORT],
+    )
+
+
+@pytest.mark.parametrize(
+    "signal_factory",
+    [
+        strategies.TripleMA,
+        lambda: strategies.SingleMA(period=20),
+        strategies.DonchianBreakout,
+        strategies.KeltnerBreakout,
+        strategies.ChandelierExit,
+        strategies.TrendSlope,
+        strategies.Trix,
+        strategies.AwesomeOscillator,
+    ],
+)
+def test_signal_stays_out_of_flat_market(signal_factory) -> None:
+    synthetic.assert_advices(signal_factory(), synthetic.flat(80), [])
+
+
+@pytest.mark.parametrize(
+    "signal_factory",
+    [
+        strategies.FourWeekRule,
+        strategies.DonchianBreakout,
+        strategies.KeltnerBreakout,
+        strategies.ChandelierExit,
+    ],
+)
+@pytest.mark.parametrize(
+    "gap,expected_advice", [(Decimal("20.0"), Advice.LONG), (Decimal("-20.0"), Advice.SHORT)]
+)
+def test_breakout_on_price_gap(signal_factory, gap, expected_advice) -> None:
+    candles = synthetic.with_gap(
+        synthetic.mean_reverting(60, amplitude=Decimal("2.0")), index=50, gap=gap
+    )
+
+    advices = synthetic.advices(signal_factory(), candles)
+
+    assert advices[49] is not expected_advice
+    assert advices[50] is expected_advice
+
+
+def test_macd_crosses_in_ranging_market() -> None:
+    advices = synthetic.advice_changes(
+        synthetic.advices(strategies.Macd(), synthetic.mean_reverting(120))
+    )
+
+    assert len(advices) >= 4
+    assert all(a is not b for a, b in zip(advices, advices[1:]))
+    assert set(advices) == {Advice.LONG, Advice.SHORT}
+
+
+@pytest.mark.parametrize(
+    "oscillator_factory",
+    [strategies.Rsi, lambda: strategies.Stoch(14, 3, 3), strategies.Cci, strategies.Mfi],
+)
+def test_oscillator_extremes_in_trends(oscillator_factory) -> None:
+    meta: CandleMeta = ("eth-btc", 1, "regular")
+
+    target = oscillator_factory()
+    for candle in synthetic.trending(80):
+        target.update(candle, meta)
+    assert target.overbought
+    assert not target.oversold
+
+    target = oscillator_factory()
+    for candle in synthetic.trending(80, start_price=Decimal("200.0"), step=Decimal("-1.0")):
+        target.update(candle, meta)
+    assert not target.overbought
+    assert target.oversold
+
+
+@pytest.mark.parametrize("osc_type", ["cci", "mfi"])
+def test_sig_osc_with_volume_and_channel_oscillators(osc_type: str) -> None:
+    target = strategies.SigOsc(
+        sig={"type": "singlema", "period": 20},
+        osc={"type": osc_type},
+        osc_filter="prevent",
+    )
+    # A trend follower is kept out of a strong uptrend as the oscillator reports overbought.
+    synthetic.assert_advices(target, synthetic.trending(80), [Advice.LIQUIDATE])
+
+
+@pytest.mark.parametrize(
+    "second_trough,expected_advices",
+    [
+        (Decimal("11.0"), [Advice.LONG]),  # Higher second trough.
+        (Decimal("8.0"), []),  # Lower second trough.
+    ],
+)
+@pytest.mark.parametrize("direction", [1, -1])
+def test_awesome_oscillator_twin_peaks(
+    second_trough: Decimal, expected_advices: list[Advice], direction: int
+) -> None:
+    closes = [Decimal("20.0")] * 6 + [
+        Decimal(c) for c in ["18.0", "16.0", "14.0", "12.0", "13.0", "14.0", "13.0"]
+    ]
+    closes += [second_trough, second_trough + 2]
+    # Mirror the prices around 20 to get twin peaks above the zero line.
+    prices = [20 + direction * (c - 20) for c in closes]
+    candles = [Candle(time=i, high=p, low=p, close=p) for i, p in enumerate(prices)]
+    synthetic.assert_advices(
+        strategies.AwesomeOscillator(2, 6),
+        candles,
+        [a if direction == 1 else Advice.SHORT for a in expected_advices],
+    )
+
+
+def _ohlc(time: int, open_: str, high: str, low: str, close: str) -> Candle:
+    return Candle(
+        time=time, open=Decimal(open_), high=Decimal(high), low=Decimal(low), close=Decimal(close)
+    )
+
+
+@pytest.mark.parametrize(
+    "prev,candle,patterns,expected_advices",
+    [
+        (  # Bullish engulfing.
+            ("10.0", "10.2", "8.8", "9.0"),
+            ("8.9", "10.6", "8.8", "10.5"),
+            None,
+            [Advice.LONG],
+        ),
+        (  # Bearish engulfing.
+            ("9.0", "10.2", "8.8", "10.0"),
+            ("10.1", "10.2", "8.4", "8.5"),
+            None,
+            [Advice.SHORT],
+        ),
+        (  # Hammer.
+            ("10.0", "10.1", "8.9", "9.0"),
+            ("9.0", "9.25", "8.0", "9.2"),
+            None,
+            [Advice.LONG],
+        ),
+        (  # Shooting star.
+            ("9.0", "10.1", "8.9", "10.0"),
+            ("10.0", "10.8", "9.75", "9.8"),
+            None,
+            [Advice.SHORT],
+        ),
+        (  # Doji.
+            ("9.0", "10.1", "8.9", "10.0"),
+            ("10.0", "10.5", "9.5", "10.01"),
+            None,
+            [Advice.LIQUIDATE],
+        ),
+        (  # Bullish engulfing with the pattern disabled.
+            ("10.0", "10.2", "8.8", "9.0"),
+            ("8.9", "10.6", "8.8", "10.5"),
+            ["doji", "hammer"],
+            [],
+        ),
+    ],
+)
+def test_candle_pattern(
+    prev: tuple[str, str, str, str],
+    candle: tuple[str, str, str, str],
+    patterns: Optional[list[str]],
+    expected_advices: list[Advice],
+) -> None:
+    synthetic.assert_advices(
+        strategies.CandlePattern(patterns=patterns),
+        [_ohlc(0, *prev), _ohlc(1, *candle)],
+        expected_advices,
+    )
+
+
+@pytest.mark.parametrize(
+    "ma_period,expected_advices",
+    [
+        (0, [Advice.LONG]),
+        (3, []),  # Close below the average.
+    ],
+)
+def test_candle_pattern_ma_confirmation(ma_period: int, expected_advices: list[Advice]) -> None:
+    candles = [
+        _ohlc(0, "20.0", "20.0", "20.0", "20.0"),
+        _ohlc(1, "10.0", "10.2", "8.8", "9.0"),
+        _ohlc(2, "8.9", "10.6", "8.8", "10.5"),  # Bullish engulfing.
+    ]
+    synthetic.assert_advices(
+        strategies.CandlePattern(ma="sma", ma_period=ma_period), candles, expected_advices
+    )
+
+
+def test_export_params() -> None:
+    assert strategies.export_params(strategies.FourWeekRule, {"period": 20}) == {
+        "period": 20,
+        "ma": "ema",
+        "ma_period": 14,
+    }
+
+
+def test_export_params_missing_required() -> None:
+    with pytest.raises(ValueError):
+        strategies.export_params(strategies.Stoch, {"k_period": 14})
+
+
+def test_export_pine() -> None:
+    output = strategies.export_pine(
+        strategies.FourWeekRule, {"period": 20, "ma": "sma", "ma_period": 10}
+    )
+
+    assert output == (
+        "//@version=5\n"
+        'strategy("Four Week Rule", overlay=true)\n'
+        "\n"
+        'period = input.int(20, title="Breakout period", minval=2, maxval=99)\n'
+        'ma = input.string("sma", title="Exit moving average", options=["alma", "dema", "ema", '
+        '"ema2", "kama", "sma", "smma", "wma"])\n'
+        'ma_period = input.int(10, title="Exit moving average period", minval=2, maxval=99)\n'
+    )
+
+
+def test_export_pine_unsupported_parameter_as_comment() -> None:
+    output = strategies.export_pine(
+        strategies.Sig, {"sig": {"type": "macd"}, "persistence": 0, "changed_enabled": False}
+    )
+
+    assert '// sig = {"type": "macd"}\n' in output
+    assert 'persistence = input.int(0, title="Persistence")\n' in output
+    assert 'changed_enabled = input.bool(false, title="Changed enabled")\n' in output
@@ -1,10 +1,35 @@
+from dataclasses import replace
 from decimal import Decimal
+from random import Random
 
+import pandas as pd
 import pytest
 
-from juno import AssetInfo, Fill
-from juno.statistics import CoreStatistics
-from juno.trading import CloseReason, Position, TradingSummary
+from juno import AssetInfo, Candle, Fill, Interval_
+from juno.statistics import (
+    WORST_FITNESS,
+    BreakdownBucket,
+    Breakdowns,
+    CoreStatistics,
+    CorrelationMethod,
+    EvaluationAggregation,
+    StreamingStatistics,
+    aggregate,
+    block_shuffle,
+    correlation_matrix,
+    p_value,
+    period_returns,
+)
+from juno.statistics.extended import _calculate_statistics
+from juno.trading import (
+    CloseReason,
+    FillModel,
+    Liquidity,
+    Position,
+    PriceImpact,
+    TradingSession,
+    TradingSummary,
+)
 
 
 def test_long_position() -> None:
@@ -110,6 +135,201 @@ def test_trading_summary() -> None:
     assert stats.gain == Decimal("102.91")
     assert stats.profit == Decimal("2.91")
     assert stats.max_drawdown == pytest.approx(Decimal("0.1495"), Decimal("0.001"))
+    assert stats.max_consecutive_wins == 1
+    assert stats.max_consecutive_losses == 1
+    assert stats.profit_factor == Decimal("24.31") / Decimal("21.4")
+    assert stats.payoff_ratio == (Decimal("24.31") / 3) / (Decimal("21.4") / 2)
+    assert stats.win_loss_ratio == Decimal("1.5")
+    assert stats.expectancy == Decimal("0.582") / Decimal("10.7")
+    payoff_ratio = (Decimal("24.31") / 3) / (Decimal("21.4") / 2)
+    assert stats.kelly_fraction == Decimal("0.6") - Decimal("0.4") / payoff_ratio
+    assert stats.half_kelly_fraction == stats.kelly_fraction / 2
+    assert stats.risk_of_ruin == ((1 - stats.expectancy) / (1 + stats.expectancy)) ** (
+        Decimal("100.0") / Decimal("10.7")
+    )
+
+
+def test_trading_summary_fees_and_volume() -> None:
+    open_pos = Position.OpenLong.build(
+        exchange="exchange",
+        symbol="eth-btc",
+        time=0,
+        fills=[
+            Fill(
+                price=Decimal("2.0"),
+                size=Decimal("5.0"),
+                quote=Decimal("10.0"),
+                fee=Decimal("0.5"),
+                fee_asset="eth",
+            )
+        ],
+        base_asset_info=AssetInfo(),
+        quote_asset_info=AssetInfo(),
+    )
+    pos = open_pos.close(
+        time=1,
+        fills=[
+            Fill(
+                price=Decimal("3.0"),
+                size=Decimal("4.5"),
+                quote=Decimal("13.5"),
+                fee=Decimal("1.0"),
+                fee_asset="btc",
+            )
+        ],
+        reason=CloseReason.STRATEGY,
+        base_asset_info=AssetInfo(),
+        quote_asset_info=AssetInfo(),
+    )
+    summary = TradingSummary(
+        start=0,
+        end=1,
+        starting_assets={
+            "btc": Decimal("10.0"),
+        },
+        positions=[pos],
+    )
+
+    assert summary.fees == Decimal("2.0")  # 0.5 * 2 + 1
+    assert summary.volume == Decimal("23.5")  # 10 + 13.5
+    assert summary.turnover == Decimal("2.35")
+
+    stats = CoreStatistics.compose(summary)
+    assert stats.fees == summary.fees
+    assert stats.volume == summary.volume
+    assert stats.turnover == summary.turnover
+
+
+@pytest.mark.parametrize(
+    "time,expected_output",
+    [
+        (0, False),  # Thursday 00:00.
+        (9 * Interval_.HOUR, True),  # Thursday 09:00.
+        (Interval_.DAY + 9 * Interval_.HOUR, True),  # Friday 09:00.
+        (2 * Interval_.DAY + 9 * Interval_.HOUR, False),  # Saturday 09:00.
+    ],
+)
+def test_trading_session(time: int, expected_output: bool) -> None:
+    session = TradingSession(hours=(9, 10), weekdays=(3, 4))
+    assert session.allows(time) == expected_output
+
+
+@pytest.mark.parametrize(
+    "size,volume,buy,expected_output",
+    [
+        (Decimal("10.0"), Decimal("100.0"), True, Decimal("101.0")),
+        (Decimal("10.0"), Decimal("100.0"), False, Decimal("99.0")),
+        (Decimal("1000.0"), Decimal("100.0"), True, Decimal("110.0")),  # Capped participation.
+        (Decimal("10.0"), Decimal("0.0"), False, Decimal("90.0")),
+    ],
+)
+def test_price_impact(size: Decimal, volume: Decimal, buy: bool, expected_output: Decimal) -> None:
+    impact = PriceImpact(coefficient=Decimal("0.1"))
+    assert impact.apply(Decimal("100.0"), size, volume, buy) == expected_output
+
+
+@pytest.mark.parametrize("coefficient", [Decimal("-0.1"), Decimal("1.0")])
+def test_price_impact_invalid_coefficient(coefficient: Decimal) -> None:
+    with pytest.raises(AssertionError):
+        PriceImpact(coefficient=coefficient)
+
+
+@pytest.mark.parametrize(
+    "probability,expected_output",
+    [
+        (Decimal("0.0"), 0),
+        (Decimal("1.0"), 100),
+    ],
+)
+def test_fill_model_probability_bounds(probability: Decimal, expected_output: int) -> None:
+    model = FillModel(entry=Liquidity.MAKER, maker_fill_probability=probability)
+    assert sum(model.maker_entry_filled("eth-btc", t) for t in range(100)) == expected_output
+
+
+def test_fill_model_deterministic() -> None:
+    model = FillModel(
+        entry=Liquidity.MAKER, exit=Liquidity.MAKER, maker_fill_probability=Decimal("0.5")
+    )
+    first = [model.maker_entry_filled("eth-btc", t) for t in range(100)]
+    second = [model.maker_entry_filled("eth-btc", t) for t in range(100)]
+    reseeded_model = FillModel(
+        entry=Liquidity.MAKER, maker_fill_probability=Decimal("0.5"), seed=1
+    )
+    reseeded = [reseeded_model.maker_entry_filled("eth-btc", t) for t in range(100)]
+    assert first == second
+    assert first != reseeded
+    assert 0 < sum(first) < 100
+    # Taker orders never fill as maker.
+    assert not FillModel().maker_exit_filled("eth-btc", 0)
+
+
+def test_streaming_statistics() -> None:
+    values = ["1.0", "1.1", "1.05", "0.9", "1.2", "1.3"]
+    target = StreamingStatistics()
+    for value in values:
+        target.update(Decimal(value))
+
+    expected = _calculate_statistics(
+        pd.Series([float(v) for v in values]), pd.Series([float(v) for v in values])
+    )
+    output = target.compose()
+    assert output.total_return == pytest.approx(expected.total_return)
+    assert output.annualized_return == pytest.approx(expected.annualized_return)
+    assert output.annualized_volatility == pytest.approx(expected.annualized_volatility)
+    assert output.annualized_downside_risk == pytest.approx(expected.annualized_downside_risk)
+    assert output.sharpe_ratio == pytest.approx(expected.sharpe_ratio)
+    assert output.sortino_ratio == pytest.approx(expected.sortino_ratio)
+    assert output.cagr == pytest.approx(expected.cagr)
+    assert output.raw_sharpe_ratio == pytest.approx(expected.raw_sharpe_ratio)
+    assert output.raw_sortino_ratio == pytest.approx(expected.raw_sortino_ratio)
+    assert target.max_drawdown == pytest.approx(1 - 0.9 / 1.1)
+
+
+def test_streaming_statistics_annualization() -> None:
+    daily = StreamingStatistics(periods_per_year=365)
+    quarterly = StreamingStatistics(periods_per_year=4)
+    for value in ["1.0", "1.1", "1.05", "0.9", "1.2", "1.3"]:
+        daily.update(Decimal(value))
+        quarterly.update(Decimal(value))
+
+    daily_output = daily.compose()
+    quarterly_output = quarterly.compose()
+
+    assert quarterly_output.periods_per_year == 4
+    # Raw ratios do not depend on annualization.
+    assert quarterly_output.raw_sharpe_ratio == pytest.approx(daily_output.raw_sharpe_ratio)
+    assert quarterly_output.raw_sortino_ratio == pytest.approx(daily_output.raw_sortino_ratio)
+    assert quarterly_output.sharpe_ratio == pytest.approx(quarterly_output.raw_sharpe_ratio * 2)
+
+
+def test_breakdowns() -> None:
+    monday_9 = 4 * Interval_.DAY + 9 * Interval_.HOUR  # 1970-01-05 09:00.
+    summary = TradingSummary(
+        start=0,
+        end=monday_9 + Interval_.DAY,
+        starting_assets={
+            "btc": Decimal("100.0"),
+        },
+        positions=[
+            new_closed_long_position(Decimal("10.0"), time=monday_9),
+            new_closed_long_position(Decimal("-4.0"), time=monday_9 + Interval_.HOUR),
+            new_closed_long_position(Decimal("2.0"), time=monday_9 + Interval_.DAY),
+        ],
+    )
+
+    breakdowns = Breakdowns.compose(summary)
+
+    assert breakdowns.by_month == {
+        1: BreakdownBucket(num_positions=3, profit=Decimal("8.0"), win_rate=Decimal("2") / 3),
+    }
+    assert breakdowns.by_weekday == {
+        0: BreakdownBucket(num_positions=2, profit=Decimal("6.0"), win_rate=Decimal("0.5")),
+        1: BreakdownBucket(num_positions=1, profit=Decimal("2.0"), win_rate=Decimal("1")),
+    }
+    assert breakdowns.by_hour == {
+        9: BreakdownBucket(num_positions=2, profit=Decimal("12.0"), win_rate=Decimal("1")),
+        10: BreakdownBucket(num_positions=1, profit=Decimal("-4.0"), win_rate=Decimal("0")),
+    }
 
 
 def test_empty_trading_summary() -> None:
@@ -126,16 +346,171 @@ def test_empty_trading_summary() -> None:
     assert stats.gain == 100
     assert stats.profit == 0
     assert stats.max_drawdown == 0
+    assert stats.fees == 0
+    assert stats.turnover == 0
+    assert stats.max_consecutive_wins == 0
+    assert stats.profit_factor == 0
+    assert stats.expectancy == 0
+    assert stats.kelly_fraction == 0
+    assert stats.risk_of_ruin == 0
+    assert stats.max_mae == 0
+    assert stats.max_mfe == 0
+
+
+def test_trading_summary_break_even_wins() -> None:
+    summary = TradingSummary(
+        start=0,
+        end=1,
+        starting_assets={
+            "btc": Decimal("100.0"),
+        },
+        positions=[
+            replace(new_closed_long_position(Decimal("1.0")), profit=Decimal("0.0")),
+            new_closed_long_position(Decimal("-1.0")),
+        ],
+    )
+    stats = CoreStatistics.compose(summary)
+    assert stats.kelly_fraction == 0
+    assert stats.half_kelly_fraction == 0
+
+
+def test_excursion_statistics() -> None:
+    summary = TradingSummary(
+        start=0,
+        end=1,
+        starting_assets={
+            "btc": Decimal("100.0"),
+        },
+        positions=[
+            replace(
+                new_closed_long_position(Decimal("1.0")), mae=Decimal("0.1"), mfe=Decimal("0.5")
+            ),
+            replace(
+                new_closed_long_position(Decimal("1.0")), mae=Decimal("0.3"), mfe=Decimal("0.4")
+            ),
+            replace(
+                new_closed_long_position(Decimal("-1.0")), mae=Decimal("0.8"), mfe=Decimal("0.0")
+            ),
+        ],
+    )
+    stats = CoreStatistics.compose(summary)
+    assert stats.mean_mae == Decimal("0.4")
+    assert stats.median_mae == Decimal("0.3")
+    assert stats.max_mae == Decimal("0.8")
+    assert stats.mean_winning_mae == Decimal("0.2")
+    assert stats.mean_mfe == Decimal("0.3")
+    assert stats.median_mfe == Decimal("0.4")
+    assert stats.max_mfe == Decimal("0.5")
+
+
+@pytest.mark.parametrize(
+    "aggregation,expected_output",
+    [
+        [EvaluationAggregation.MEAN, Decimal("3.0")],
+        [EvaluationAggregation.MIN, Decimal("1.0")],
+        [EvaluationAggregation.MEDIAN, Decimal("2.0")],
+        [EvaluationAggregation.HARMONIC_MEAN, Decimal("1.8")],
+        [EvaluationAggregation.WORST_K_MEAN, Decimal("1.5")],
+        # Population standard deviation is sqrt(14 / 3).
+        [EvaluationAggregation.PENALIZED_MEAN, 3 - Decimal("0.5") * (Decimal("14") / 3).sqrt()],
+    ],
+)
+def test_aggregate(aggregation: EvaluationAggregation, expected_output: Decimal) -> None:
+    values = [Decimal("1.0"), Decimal("2.0"), Decimal("6.0")]
+    output = aggregate(values, aggregation, k=2, penalty=Decimal("0.5"))
+    assert output == pytest.approx(expected_output)
+
+
+def test_aggregate_harmonic_mean_requires_positive_values() -> None:
+    with pytest.raises(ValueError):
+        aggregate([Decimal("1.0"), Decimal("-1.0")], EvaluationAggregation.HARMONIC_MEAN)
+
+
+@pytest.mark.parametrize("aggregation", list(EvaluationAggregation))
+@pytest.mark.parametrize("degenerate", [Decimal("NaN"), Decimal("Infinity"), Decimal("-Infinity")])
+def test_aggregate_degenerate_value_maps_to_worst_fitness(
+    aggregation: EvaluationAggregation, degenerate: Decimal
+) -> None:
+    assert aggregate([Decimal("1.0"), degenerate], aggregation) == WORST_FITNESS
+
+
+def test_correlation_matrix() -> None:
+    prices = {
+        "eth-btc": [Decimal("1.0"), Decimal("2.0"), Decimal("3.0"), Decimal("5.0")],
+        "ltc-btc": [Decimal("2.0"), Decimal("4.0"), Decimal("6.0"), Decimal("10.0")],
+        "xmr-btc": [Decimal("10.0"), Decimal("5.0"), Decimal("7.5"), Decimal("9.375")],
+    }
+
+    pearson = correlation_matrix(prices)
+    spearman = correlation_matrix(prices, CorrelationMethod.SPEARMAN)
+
+    assert pearson["eth-btc"]["eth-btc"] == pytest.approx(1.0)
+    assert pearson["eth-btc"]["ltc-btc"] == pytest.approx(1.0)
+    assert pearson["eth-btc"]["xmr-btc"] == pearson["xmr-btc"]["eth-btc"]
+    assert pearson["eth-btc"]["xmr-btc"] < 0
+    assert spearman["eth-btc"]["xmr-btc"] == pytest.approx(-1.0)
 
 
-def new_closed_long_position(profit: Decimal) -> Position.Long:
+def test_correlation_matrix_requires_aligned_prices() -> None:
+    with pytest.raises(ValueError):
+        correlation_matrix({"eth-btc": [Decimal("1.0")], "ltc-btc": []})
+
+
+def test_block_shuffle() -> None:
+    closes = ["1.0", "2.0", "1.0", "3.0", "6.0", "3.0", "4.5"]
+    candles = [
+        Candle(time=i, open=Decimal(c), high=Decimal(c), low=Decimal(c), close=Decimal(c))
+        for i, c in enumerate(closes)
+    ]
+
+    output = block_shuffle(candles, 2, Random(1))
+
+    assert output[0] == candles[0]
+    assert [c.time for c in output] == [c.time for c in candles]
+    # The overall return is preserved as returns are only reordered.
+    assert output[-1].close == candles[-1].close
+    # Returns within a block stay together.
+    returns = [b.close / a.close for a, b in zip(output, output[1:])]
+    pairs = {tuple(returns[i : i + 2]) for i in range(0, len(returns), 2)}
+    assert pairs == {
+        (Decimal("2.0"), Decimal("0.5")),
+        (Decimal("3.0"), Decimal("2.0")),
+        (Decimal("0.5"), Decimal("1.5")),
+    }
+
+
+def test_p_value() -> None:
+    distribution = [Decimal("1.0"), Decimal("2.0"), Decimal("3.0")]
+    assert p_value(Decimal("4.0"), distribution) == Decimal("0.25")
+    assert p_value(Decimal("2.0"), distribution) == Decimal("0.75")
+
+
+def test_period_returns() -> None:
+    equity = [
+        (1, Decimal("110.0")),
+        (2, Decimal("99.0")),  # Closes the first period.
+        (3, Decimal("100.0")),
+        (4, Decimal("198.0")),  # Closes the second period.
+        (7, Decimal("99.0")),  # Third period has no samples.
+    ]
+
+    output = period_returns(Decimal("100.0"), equity, 2)
+
+    assert output == [
+        (0, Decimal("-0.01")),
+        (2, Decimal("1.0")),
+        (6, Decimal("-0.5")),
+    ]
+
+
+def new_closed_long_position(profit: Decimal, time: int = 0) -> Position.Long:
     size = abs(profit)
     open_price = Decimal("2.0")
     close_price = Decimal("3.0") if profit >= 0 else Decimal("1.0")
     open_pos = Position.OpenLong.build(
         exchange="exchange",
         symbol="eth-btc",
-        time=0,
+        time=time,
         fills=[
             Fill.with_computed_quote(price=open_price, size=size, fee_asset="eth"),
         ],
@@ -143,7 +518,7 @@ def new_closed_long_position(profit: Decimal) -> Position.Long:
         quote_asset_info=AssetInfo(),
     )
     return open_pos.close(
-        time=1,
+        time=time + 1,
         fills=[
             Fill.with_computed_quote(price=close_price, size=size, fee_asset="btc"),
         ],
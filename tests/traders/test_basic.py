@@ -1,13 +1,37 @@
 import asyncio
 from decimal import Decimal
+from typing import Optional
 
 import pytest
 
-from juno import Advice, BorrowInfo, Candle, Filters, stop_loss, take_profit, traders
+from juno import (
+    Advice,
+    BorrowInfo,
+    Candle,
+    Fees,
+    FeeTier,
+    Filters,
+    Interval_,
+    stop_loss,
+    strategies,
+    take_profit,
+    traders,
+)
 from juno.asyncio import cancel
+from juno.components import Events
 from juno.inspect import GenericConstructor
 from juno.strategies import Fixed, MidTrendPolicy
-from juno.trading import CloseReason, Position
+from juno.trading import (
+    CloseReason,
+    FeeDiscount,
+    FeeSchedule,
+    FillModel,
+    Liquidity,
+    Position,
+    PriceImpact,
+    TradingSession,
+    TradingSummary,
+)
 from tests import fakes
 
 
@@ -92,6 +116,197 @@ async def test_upside_trailing_stop_loss() -> None:
     assert position.close_reason is CloseReason.STOP_LOSS
 
 
+@pytest.mark.parametrize(
+    "stop_loss_type,candle,expected_profit",
+    [
+        # Low breaches the stop while close does not. Fills at the stop price.
+        (stop_loss.Basic, Candle(time=1, open=Decimal("10.0"), low=Decimal("8.5")), -1),
+        # Gaps past the stop. Fills at open.
+        (stop_loss.Basic, Candle(time=1, open=Decimal("8.0"), low=Decimal("7.0")), -2),
+        (stop_loss.Trailing, Candle(time=1, open=Decimal("10.0"), low=Decimal("8.5")), -1),
+    ],
+)
+async def test_upside_wick_stop_loss(
+    stop_loss_type: type[stop_loss.StopLoss], candle: Candle, expected_profit: Decimal
+) -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, open=Decimal("10.0"), close=Decimal("10.0")),  # Open long.
+                candle._replace(high=Decimal("10.0"), close=Decimal("9.5")),  # Trigger (10%).
+                Candle(time=2, close=Decimal("10.0")),  # Close long (do not act).
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=3,
+        quote=Decimal("10.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LONG, Advice.LIQUIDATE],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+        ),
+        stop_loss=GenericConstructor.from_type(stop_loss_type, Decimal("0.1"), use_close=False),
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    (position,) = summary.positions
+    assert isinstance(position, Position.Long)
+    assert position.profit == expected_profit
+    assert position.close_reason is CloseReason.STOP_LOSS
+
+
+async def test_upside_wick_trailing_stop_loss_trails_high() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, open=Decimal("10.0"), close=Decimal("10.0")),  # Open long.
+                Candle(
+                    time=1,
+                    open=Decimal("10.0"),
+                    high=Decimal("20.0"),
+                    low=Decimal("19.0"),
+                    close=Decimal("19.5"),
+                ),
+                Candle(  # Trigger trailing stop loss (10% of high) while close stays above.
+                    time=2,
+                    open=Decimal("19.0"),
+                    high=Decimal("19.0"),
+                    low=Decimal("17.0"),
+                    close=Decimal("18.5"),
+                ),
+                Candle(time=3, close=Decimal("10.0")),  # Close long (do not act).
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=4,
+        quote=Decimal("10.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LONG, Advice.LONG, Advice.LIQUIDATE],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+        ),
+        stop_loss=GenericConstructor.from_type(
+            stop_loss.Trailing, Decimal("0.1"), use_close=False
+        ),
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    (position,) = summary.positions
+    assert isinstance(position, Position.Long)
+    assert position.profit == 8
+    assert position.close_reason is CloseReason.STOP_LOSS
+
+
+@pytest.mark.parametrize("short", [False, True])
+async def test_duration_stop_loss(short: bool) -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("10.0")),  # Open position.
+                Candle(time=1, close=Decimal("10.0")),
+                Candle(time=2, close=Decimal("10.0")),  # Trigger after 2 intervals.
+                Candle(time=3, close=Decimal("10.0")),  # Close position (do not act).
+            ]
+        }
+    )
+    informant = fakes.Informant(
+        filters=Filters(isolated_margin=True),
+        borrow_info=BorrowInfo(limit=Decimal("1.0")),
+    )
+    trader = traders.Basic(chandler=chandler, informant=informant)
+    advice = Advice.SHORT if short else Advice.LONG
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=4,
+        quote=Decimal("10.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[advice, advice, advice, Advice.LIQUIDATE],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+        ),
+        stop_loss=GenericConstructor.from_type(stop_loss.Duration, 2),
+        long=not short,
+        short=short,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    (position,) = summary.positions
+    assert position.close_reason is CloseReason.STOP_LOSS
+    assert position.close_time == 3
+
+
+@pytest.mark.parametrize(
+    "break_even_threshold,expected_close_reason",
+    [
+        (None, CloseReason.STRATEGY),
+        (Decimal("0.1"), CloseReason.STOP_LOSS),
+    ],
+)
+async def test_upside_trailing_stop_loss_break_even(
+    break_even_threshold: Optional[Decimal], expected_close_reason: CloseReason
+) -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("10.0")),  # Open long.
+                Candle(time=1, close=Decimal("12.0")),  # Gain 20%; move stop to entry.
+                Candle(time=2, close=Decimal("9.5")),  # Trigger break-even stop.
+                Candle(time=3, close=Decimal("9.0")),  # Close long.
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=4,
+        quote=Decimal("10.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LONG, Advice.LONG, Advice.LIQUIDATE],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+        ),
+        stop_loss=GenericConstructor.from_type(
+            stop_loss.Trailing, Decimal("0.5"), break_even_threshold=break_even_threshold
+        ),
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    (position,) = summary.positions
+    assert position.close_reason is expected_close_reason
+
+
 async def test_downside_trailing_stop_loss() -> None:
     chandler = fakes.Chandler(
         candles={
@@ -136,6 +351,50 @@ async def test_downside_trailing_stop_loss() -> None:
     assert short_positions[0].close_reason is CloseReason.STOP_LOSS
 
 
+async def test_upside_parabolic_sar_stop_loss() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                # Open long. SAR starts at the low.
+                Candle(time=0, high=Decimal("11.0"), low=Decimal("9.0"), close=Decimal("10.0")),
+                Candle(time=1, high=Decimal("13.0"), low=Decimal("11.0"), close=Decimal("12.0")),
+                # Low goes below SAR; trigger stop loss.
+                Candle(time=2, high=Decimal("12.0"), low=Decimal("8.0"), close=Decimal("8.5")),
+                # Close long (do not act).
+                Candle(time=3, high=Decimal("11.0"), low=Decimal("9.0"), close=Decimal("10.0")),
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=4,
+        quote=Decimal("10.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LONG, Advice.LONG, Advice.LIQUIDATE],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+        ),
+        stop_loss=GenericConstructor.from_type(stop_loss.ParabolicSar),
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    positions = summary.positions
+    assert len(positions) == 1
+    position = positions[0]
+    assert isinstance(position, Position.Long)
+    assert position.close_time == 3
+    assert position.profit == Decimal("-1.5")
+    assert position.close_reason is CloseReason.STOP_LOSS
+
+
 async def test_upside_take_profit() -> None:
     chandler = fakes.Chandler(
         candles={
@@ -219,51 +478,129 @@ async def test_downside_take_profit() -> None:
     assert short_positions[0].close_reason is CloseReason.TAKE_PROFIT
 
 
-async def test_adjusted_start_ignore_mid_trend() -> None:
+@pytest.mark.parametrize(
+    "margin_call_candle,expected_price",
+    [
+        # High wicks past the liquidation price (~18.18) which is where the position is filled.
+        (
+            Candle(time=2, open=Decimal("15.0"), high=Decimal("19.0"), close=Decimal("16.0")),
+            Decimal("20.0") / Decimal("1.1"),
+        ),
+        # Opens past the liquidation price and is filled at the open.
+        (
+            Candle(time=2, open=Decimal("19.0"), high=Decimal("20.0"), close=Decimal("19.5")),
+            Decimal("19.0"),
+        ),
+    ],
+)
+async def test_short_margin_call(margin_call_candle: Candle, expected_price: Decimal) -> None:
     chandler = fakes.Chandler(
         candles={
             ("dummy", "eth-btc", 1): [
-                Candle(time=0, close=Decimal("1.0")),
-                Candle(time=1, close=Decimal("1.0")),
-                Candle(time=2, close=Decimal("1.0")),
-                Candle(time=3, close=Decimal("1.0")),
+                Candle(time=0, close=Decimal("10.0")),  # Open short.
+                Candle(time=1, open=Decimal("10.0"), high=Decimal("15.0"), close=Decimal("15.0")),
+                margin_call_candle,
+                Candle(time=3, close=Decimal("5.0")),
             ]
         }
     )
-    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    informant = fakes.Informant(
+        filters=Filters(isolated_margin=True),
+        borrow_info=BorrowInfo(limit=Decimal("1.0")),
+        margin_multiplier=2,
+    )
+    trader = traders.Basic(chandler=chandler, informant=informant)
     config = traders.BasicConfig(
         exchange="dummy",
         symbol="eth-btc",
         interval=1,
-        start=2,
+        start=0,
         end=4,
-        quote=Decimal("1.0"),
+        quote=Decimal("10.0"),
         strategy=GenericConstructor.from_type(
             Fixed,
-            advices=[Advice.NONE, Advice.LONG, Advice.LONG, Advice.NONE],
-            maturity=2,
-            mid_trend_policy=MidTrendPolicy.IGNORE,
-            persistence=1,
+            advices=[Advice.SHORT, Advice.SHORT, Advice.SHORT, Advice.SHORT],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+        ),
+        long=False,
+        short=True,
+        maintenance_margin_rate=Decimal("0.1"),
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    assert len(summary.positions) == 1
+    position = summary.positions[0]
+    assert isinstance(position, Position.Short)
+    assert position.close_reason is CloseReason.LIQUIDATED
+    assert position.close_time == 3
+    assert position.close_fills[0].price == expected_price
+    assert position.profit == round(10 - expected_price, 8)
+
+
+async def test_scale_into_position() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("2.0")),  # Same advice; no scaling.
+                Candle(time=2, close=Decimal("2.0")),
+                Candle(time=3, close=Decimal("2.0")),  # Scale into long.
+                Candle(time=4, close=Decimal("4.0")),
+                Candle(time=5, close=Decimal("4.0")),  # Max entries reached.
+                Candle(time=6, close=Decimal("4.0")),  # Close long.
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=7,
+        quote=Decimal("10.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[
+                Advice.LONG,
+                Advice.LONG,
+                Advice.NONE,
+                Advice.LONG,
+                Advice.NONE,
+                Advice.LONG,
+                Advice.LIQUIDATE,
+            ],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
         ),
-        adjusted_start="strategy",
         long=True,
         short=False,
+        max_entries=2,
+        entry_fraction=Decimal("0.5"),
     )
     state = await trader.initialize(config)
 
     summary = await trader.run(state)
 
-    assert len(summary.positions) == 0
+    assert len(summary.positions) == 1
+    position = summary.positions[0]
+    assert isinstance(position, Position.Long)
+    assert len(position.open_fills) == 2
+    assert position.cost == Decimal("7.5")  # 5 + 2.5
+    assert position.gain == Decimal("25.0")  # (5 + 1.25) * 4
+    assert position.open_time == 1
+    assert state.quote == Decimal("27.5")
 
 
-async def test_adjusted_start_persistence() -> None:
+async def test_scale_into_position_not_on_same_advice() -> None:
     chandler = fakes.Chandler(
         candles={
             ("dummy", "eth-btc", 1): [
-                Candle(time=0, close=Decimal("1.0")),
-                Candle(time=1, close=Decimal("1.0")),
-                Candle(time=2, close=Decimal("1.0")),
-                Candle(time=3, close=Decimal("1.0")),
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("2.0")),
+                Candle(time=2, close=Decimal("4.0")),
+                Candle(time=3, close=Decimal("4.0")),  # Close long.
             ]
         }
     )
@@ -272,27 +609,763 @@ async def test_adjusted_start_persistence() -> None:
         exchange="dummy",
         symbol="eth-btc",
         interval=1,
-        start=3,
+        start=0,
         end=4,
-        quote=Decimal("1.0"),
+        quote=Decimal("10.0"),
         strategy=GenericConstructor.from_type(
             Fixed,
-            advices=[Advice.NONE, Advice.LONG, Advice.LONG, Advice.LONG],
-            maturity=2,
+            advices=[Advice.LONG, Advice.LONG, Advice.LONG, Advice.LIQUIDATE],
             mid_trend_policy=MidTrendPolicy.CURRENT,
-            persistence=2,
         ),
-        adjusted_start="strategy",
         long=True,
         short=False,
+        max_entries=2,
+        entry_fraction=Decimal("0.5"),
     )
     state = await trader.initialize(config)
 
     summary = await trader.run(state)
 
-    long_positions = [p for p in summary.positions if isinstance(p, Position.Long)]
-    assert len(long_positions) == 1
-    assert long_positions[0].close_reason is CloseReason.CANCELLED
+    assert len(summary.positions) == 1
+    position = summary.positions[0]
+    assert len(position.open_fills) == 1
+    assert position.cost == Decimal("5.0")
+
+
+async def test_scale_out_take_profit() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("1.5")),  # Close half at first target.
+                Candle(time=2, close=Decimal("1.6")),  # Partial target already taken.
+                Candle(time=3, close=Decimal("2.5")),  # Close remainder at second target.
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=4,
+        quote=Decimal("10.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LONG, Advice.LONG, Advice.LONG],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+        ),
+        take_profit=GenericConstructor.from_type(
+            take_profit.ScaleOut,
+            first_threshold=Decimal("0.4"),
+            second_threshold=Decimal("1.0"),
+            fraction=Decimal("0.5"),
+        ),
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    assert len(summary.positions) == 2
+    first, second = summary.positions
+    assert isinstance(first, Position.Long)
+    assert isinstance(second, Position.Long)
+    assert first.close_reason is CloseReason.TAKE_PROFIT
+    assert first.cost == Decimal("5.0")
+    assert first.gain == Decimal("7.5")
+    assert first.close_time == 2
+    assert second.close_reason is CloseReason.TAKE_PROFIT
+    assert second.cost == Decimal("5.0")
+    assert second.gain == Decimal("12.5")
+    assert second.close_time == 4
+    assert state.quote == Decimal("20.0")
+
+
+async def test_trading_session() -> None:
+    hour = Interval_.HOUR
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", hour): [
+                Candle(time=0, close=Decimal("1.0")),  # Outside session; ignore long.
+                Candle(time=hour, close=Decimal("1.0")),
+                Candle(time=2 * hour, close=Decimal("1.0")),  # Open long.
+                Candle(time=3 * hour, close=Decimal("2.0")),  # Close long outside session.
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=hour,
+        start=0,
+        end=4 * hour,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LIQUIDATE, Advice.LONG, Advice.LIQUIDATE],
+        ),
+        long=True,
+        short=False,
+        session=TradingSession(hours=(3,)),
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    assert len(summary.positions) == 1
+    position = summary.positions[0]
+    assert position.open_time == 3 * hour
+    assert position.close_time == 4 * hour
+    assert position.close_reason is CloseReason.STRATEGY
+
+
+async def test_price_impact() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0"), volume=Decimal("10.0")),  # Open long.
+                Candle(time=1, close=Decimal("2.0"), volume=Decimal("5.0")),  # Close long.
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=2,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, advices=[Advice.LONG, Advice.LIQUIDATE]),
+        long=True,
+        short=False,
+        price_impact=PriceImpact(coefficient=Decimal("0.5")),
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    (position,) = summary.positions
+    assert isinstance(position, Position.Long)
+    # Buying 10% of volume worsens the price by 5%.
+    assert position.open_fills[0].price == Decimal("1.05")
+    # Selling ~19% of volume worsens the price by ~9.5%.
+    assert position.close_fills[0].price == Decimal("2.0") * (
+        1 - Decimal("0.5") * position.close_fills[0].size / Decimal("5.0")
+    )
+
+
+async def test_fill_model_maker_fees() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long as maker.
+                Candle(time=1, close=Decimal("2.0")),  # Close long as taker.
+            ]
+        }
+    )
+    informant = fakes.Informant(fees=Fees(maker=Decimal("0.01"), taker=Decimal("0.1")))
+    trader = traders.Basic(chandler=chandler, informant=informant)
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=2,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, advices=[Advice.LONG, Advice.LIQUIDATE]),
+        long=True,
+        short=False,
+        fill_model=FillModel(entry=Liquidity.MAKER, exit=Liquidity.TAKER),
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    (position,) = summary.positions
+    assert isinstance(position, Position.Long)
+    assert position.open_fills[0].fee == position.open_fills[0].size * Decimal("0.01")
+    assert position.close_fills[0].fee == position.close_fills[0].quote * Decimal("0.1")
+
+
+@pytest.mark.parametrize(
+    "fee_schedule,expected_taker_fee",
+    [
+        (FeeSchedule(), Decimal("0.05")),  # Volume of the first round trip reaches tier 1.
+        (FeeSchedule(window=1), Decimal("0.1")),  # Opening fill has fallen out of the window.
+        (FeeSchedule(level=0), Decimal("0.1")),  # Static base tier.
+    ],
+)
+async def test_fee_schedule(fee_schedule: FeeSchedule, expected_taker_fee: Decimal) -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("1.0")),  # Close long.
+                Candle(time=2, close=Decimal("1.0")),  # Open long.
+                Candle(time=3, close=Decimal("1.0")),  # Close long.
+            ]
+        }
+    )
+    informant = fakes.Informant(
+        fees=Fees(
+            taker=Decimal("0.1"),
+            tiers=[FeeTier(volume=Decimal("1.5"), maker=Decimal("0.0"), taker=Decimal("0.05"))],
+        )
+    )
+    trader = traders.Basic(chandler=chandler, informant=informant)
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=4,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed, advices=[Advice.LONG, Advice.LIQUIDATE, Advice.LONG, Advice.LIQUIDATE]
+        ),
+        long=True,
+        short=False,
+        fee_schedule=fee_schedule,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    first, second = summary.positions
+    assert first.open_fills[0].fee == first.open_fills[0].size * Decimal("0.1")
+    assert first.close_fills[0].fee == first.close_fills[0].quote * Decimal("0.1")
+    assert second.open_fills[0].fee == second.open_fills[0].size * expected_taker_fee
+
+
+async def test_fee_discount() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("2.0")),  # Close long.
+            ]
+        }
+    )
+    informant = fakes.Informant(fees=Fees(taker=Decimal("0.1")))
+    trader = traders.Basic(chandler=chandler, informant=informant)
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=2,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, advices=[Advice.LONG, Advice.LIQUIDATE]),
+        long=True,
+        short=False,
+        fee_discount=FeeDiscount(asset="bnb", price=Decimal("0.5"), rate=Decimal("0.25")),
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    # Fees are not taken from the traded assets.
+    (position,) = summary.positions
+    assert position.profit == Decimal("1.0")
+    assert summary.fees == 0
+    # 0.1 and 0.2 btc taker fees at a 25% discount, paid with bnb at 0.5 btc.
+    assert summary.discount_fees == {"bnb": Decimal("0.45")}
+    # The fees are still a cost to the portfolio.
+    assert summary.discount_fee_cost == Decimal("0.225")
+    assert summary.profit == Decimal("0.775")
+
+
+@pytest.mark.parametrize(
+    "short,expected_mae,expected_mfe",
+    [
+        (False, Decimal("0.2"), Decimal("0.5")),
+        (True, Decimal("0.5"), Decimal("0.2")),
+    ],
+)
+async def test_excursions(short: bool, expected_mae: Decimal, expected_mfe: Decimal) -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, high=Decimal("9.0"), low=Decimal("1.0"), close=Decimal("10.0")),
+                Candle(time=1, high=Decimal("15.0"), low=Decimal("9.0"), close=Decimal("12.0")),
+                Candle(time=2, high=Decimal("13.0"), low=Decimal("8.0"), close=Decimal("11.0")),
+            ]
+        }
+    )
+    informant = fakes.Informant(
+        filters=Filters(isolated_margin=True),
+        borrow_info=BorrowInfo(limit=Decimal("10.0")),
+        margin_multiplier=2,
+    )
+    trader = traders.Basic(chandler=chandler, informant=informant)
+    advice = Advice.SHORT if short else Advice.LONG
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=3,
+        quote=Decimal("100.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[advice, advice, Advice.LIQUIDATE],
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+        ),
+        long=not short,
+        short=short,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    # The range of the opening candle is excluded.
+    (position,) = summary.positions
+    assert position.mae == expected_mae
+    assert position.mfe == expected_mfe
+
+
+async def test_fill_model_unfilled_maker_entry_skipped() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),
+                Candle(time=1, close=Decimal("2.0")),
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=2,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, advices=[Advice.LONG, Advice.LIQUIDATE]),
+        long=True,
+        short=False,
+        fill_model=FillModel(entry=Liquidity.MAKER, maker_fill_probability=Decimal("0.0")),
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    assert len(summary.positions) == 0
+    assert summary.profit == 0
+
+
+@pytest.mark.parametrize(
+    "reinvestment,expected_second_cost,expected_set_aside",
+    [
+        (Decimal("1.0"), Decimal("2.0"), {}),  # Fully compounding.
+        (Decimal("0.5"), Decimal("1.5"), {"btc": Decimal("1.25")}),
+        (Decimal("0.0"), Decimal("1.0"), {"btc": Decimal("2.0")}),  # Fixed capital.
+    ],
+)
+async def test_reinvestment(
+    reinvestment: Decimal, expected_second_cost: Decimal, expected_set_aside: dict[str, Decimal]
+) -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("2.0")),  # Close long.
+                Candle(time=2, close=Decimal("1.0")),  # Open long.
+                Candle(time=3, close=Decimal("2.0")),  # Close long.
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=4,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LIQUIDATE, Advice.LONG, Advice.LIQUIDATE],
+        ),
+        long=True,
+        short=False,
+        reinvestment=reinvestment,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    first, second = summary.positions
+    assert first.cost == Decimal("1.0")
+    assert second.cost == expected_second_cost
+    assert summary.set_aside == expected_set_aside
+    # Set-aside profit is still part of the total.
+    assert summary.profit == first.profit + second.profit
+    assert state.quote + state.set_aside == 1 + summary.profit
+
+
+async def test_advice_trace() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("1.0")),  # Repeated advice is filtered.
+                Candle(time=2, close=Decimal("2.0")),  # Close long and open short.
+            ]
+        }
+    )
+    informant = fakes.Informant(
+        filters=Filters(isolated_margin=True),
+        borrow_info=BorrowInfo(limit=Decimal("1.0")),
+        margin_multiplier=2,
+    )
+    trader = traders.Basic(chandler=chandler, informant=informant)
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=3,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed, advices=[Advice.LONG, Advice.LONG, Advice.SHORT]
+        ),
+        long=True,
+        short=True,
+        trace_advice=True,
+    )
+    state = await trader.initialize(config)
+
+    await trader.run(state)
+
+    assert state.advice_trace == [
+        traders.AdviceTraceEntry(
+            time=0, raw_advice=Advice.LONG, advice=Advice.LONG, actions=("open_long",)
+        ),
+        traders.AdviceTraceEntry(time=1, raw_advice=Advice.LONG, advice=Advice.NONE),
+        traders.AdviceTraceEntry(
+            time=2, raw_advice=Advice.SHORT, advice=Advice.SHORT, actions=("close", "open_short")
+        ),
+    ]
+
+
+async def test_strategy_snapshots() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),
+                Candle(time=1, close=Decimal("2.0")),
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=2,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(
+            strategies.Macd, short_period=1, long_period=2, signal_period=1
+        ),
+        short=False,
+        snapshot_strategy=True,
+    )
+    state = await trader.initialize(config)
+
+    await trader.run(state)
+
+    assert [s.time for s in state.strategy_snapshots] == [0, 1]
+    assert all(
+        s.values.keys() == {"macd", "signal", "histogram"} for s in state.strategy_snapshots
+    )
+
+
+async def test_record_equity() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("2.0")),
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=2,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, advices=[Advice.LONG, Advice.LONG]),
+        short=False,
+        record_equity=True,
+    )
+    state = await trader.initialize(config)
+
+    await trader.run(state)
+
+    assert state.equity == [(1, Decimal("1.0")), (2, Decimal("2.0"))]
+
+
+async def test_trader_hook() -> None:
+    class RecordingHook(traders.TraderHook):
+        def __init__(self) -> None:
+            self.calls: list[tuple] = []
+
+        async def on_candle(self, candle: Candle) -> None:
+            self.calls.append(("candle", candle.time))
+
+        async def on_advice(self, advice: Advice) -> None:
+            self.calls.append(("advice", advice))
+
+        async def on_equity(self, time: int, value: Decimal) -> None:
+            self.calls.append(("equity", time, value))
+
+        async def on_positions_opened(
+            self, positions: list[Position.Open], summary: TradingSummary
+        ) -> None:
+            self.calls.append(("opened", len(positions)))
+
+        async def on_positions_closed(
+            self, positions: list[Position.Closed], summary: TradingSummary
+        ) -> None:
+            self.calls.append(("closed", len(positions)))
+
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),  # Open long.
+                Candle(time=1, close=Decimal("2.0")),  # Close long.
+            ]
+        }
+    )
+    events = Events()
+    hook = RecordingHook()
+    hook.register(events, "default")
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant(), events=events)
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=2,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, advices=[Advice.LONG, Advice.LIQUIDATE]),
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    await trader.run(state)
+
+    assert hook.calls == [
+        ("candle", 0),
+        ("advice", Advice.LONG),
+        ("opened", 1),
+        ("equity", 1, Decimal("1.0")),
+        ("candle", 1),
+        ("advice", Advice.LIQUIDATE),
+        ("closed", 1),
+        ("equity", 2, Decimal("2.0")),
+    ]
+
+
+async def test_adjusted_start_ignore_mid_trend() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),
+                Candle(time=1, close=Decimal("1.0")),
+                Candle(time=2, close=Decimal("1.0")),
+                Candle(time=3, close=Decimal("1.0")),
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=2,
+        end=4,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.NONE, Advice.LONG, Advice.LONG, Advice.NONE],
+            maturity=2,
+            mid_trend_policy=MidTrendPolicy.IGNORE,
+            persistence=1,
+        ),
+        adjusted_start="strategy",
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    assert len(summary.positions) == 0
+
+
+async def test_adjusted_start_persistence() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),
+                Candle(time=1, close=Decimal("1.0")),
+                Candle(time=2, close=Decimal("1.0")),
+                Candle(time=3, close=Decimal("1.0")),
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=3,
+        end=4,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.NONE, Advice.LONG, Advice.LONG, Advice.LONG],
+            maturity=2,
+            mid_trend_policy=MidTrendPolicy.CURRENT,
+            persistence=2,
+        ),
+        adjusted_start="strategy",
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    long_positions = [p for p in summary.positions if isinstance(p, Position.Long)]
+    assert len(long_positions) == 1
+    assert long_positions[0].close_reason is CloseReason.CANCELLED
+
+
+@pytest.mark.parametrize(
+    "exclude_warmup,expected_start,expected_equity_times",
+    [
+        (False, 0, [1, 2, 3, 4]),
+        (True, 2, [3, 4]),
+    ],
+)
+async def test_exclude_warmup(
+    exclude_warmup: bool, expected_start: int, expected_equity_times: list[int]
+) -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),
+                Candle(time=1, close=Decimal("1.0")),
+                Candle(time=2, close=Decimal("1.0")),  # Mature.
+                Candle(time=3, close=Decimal("1.0")),
+            ]
+        }
+    )
+    events = Events()
+    equity_times: list[int] = []
+
+    @events.on("default", "equity")
+    async def on_equity(time: int, value: Decimal) -> None:
+        equity_times.append(time)
+
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant(), events=events)
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=0,
+        end=4,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, maturity=3),
+        exclude_warmup=exclude_warmup,
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    assert state.warmup_end == 2
+    assert summary.start == expected_start
+    assert summary.end == 4
+    assert equity_times == expected_equity_times
+
+
+async def test_exclude_warmup_with_adjusted_start() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=0, close=Decimal("1.0")),
+                Candle(time=1, close=Decimal("1.0")),
+                Candle(time=2, close=Decimal("1.0")),
+                Candle(time=3, close=Decimal("1.0")),
+            ]
+        }
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=2,
+        end=4,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, maturity=3),
+        adjusted_start="strategy",
+        exclude_warmup=True,
+        long=True,
+        short=False,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    assert state.warmup_end == 2
+    assert summary.start == 2
+
+
+async def test_adjusted_start_limited_to_first_candle() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("dummy", "eth-btc", 1): [
+                Candle(time=1, close=Decimal("1.0")),
+                Candle(time=2, close=Decimal("1.0")),
+                Candle(time=3, close=Decimal("1.0")),
+            ]
+        },
+        first_candle=Candle(time=1),
+    )
+    trader = traders.Basic(chandler=chandler, informant=fakes.Informant())
+    config = traders.BasicConfig(
+        exchange="dummy",
+        symbol="eth-btc",
+        interval=1,
+        start=2,
+        end=4,
+        quote=Decimal("1.0"),
+        strategy=GenericConstructor.from_type(Fixed, maturity=3),
+        adjusted_start="strategy",
+        long=True,
+        short=False,
+    )
+
+    state = await trader.initialize(config)
+
+    assert state.next_ == 1
+    assert state.warmup_end == 3
 
 
 async def test_persist_and_resume(storage: fakes.Storage) -> None:
@@ -1,5 +1,6 @@
 import asyncio
 from decimal import Decimal
+from typing import Optional
 
 import pytest
 from pytest_mock import MockerFixture
@@ -24,7 +25,7 @@ from juno.brokers import Market
 from juno.components import Informant, User
 from juno.inspect import GenericConstructor
 from juno.strategies import Fixed
-from juno.trading import CloseReason, Position, TradingMode
+from juno.trading import CloseReason, Conversion, Position, TradingMode
 from tests import fakes
 from tests.mocks import mock_exchange, mock_orderbook
 
@@ -932,6 +933,142 @@ async def test_rebalance_quotes() -> None:
     assert state.quotes == [Decimal("2.0"), Decimal("2.0"), Decimal("2.0")]
 
 
+# Blocked entries are retried while within the allowed age drift but counted only once.
+@pytest.mark.parametrize("allowed_age_drift", [0, 2])
+@pytest.mark.parametrize(
+    "max_open_positions,max_heat,stop_loss_,expected_positions",
+    [
+        (None, None, None, 2),
+        (1, None, None, 1),
+        # Each entry risks 0.1 of quote against the 0.1 limit.
+        (None, Decimal("0.05"), GenericConstructor.from_type(stop_loss.Basic, Decimal("0.1")), 1),
+        # Without a stop loss, an entry risks its whole cost.
+        (None, Decimal("0.6"), None, 1),
+    ],
+)
+async def test_position_limits(
+    max_open_positions: Optional[int],
+    max_heat: Optional[Decimal],
+    stop_loss_: Optional[GenericConstructor],
+    expected_positions: int,
+    allowed_age_drift: int,
+) -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("magicmock", s, 1): [Candle(time=i, close=Decimal("1.0")) for i in range(3)]
+            for s in ["eth-btc", "ltc-btc"]
+        },
+    )
+    informant = fakes.Informant(
+        tickers={
+            "eth-btc": Ticker(
+                volume=Decimal("2.0"),
+                quote_volume=Decimal("2.0"),
+                price=Decimal("1.0"),
+            ),
+            "ltc-btc": Ticker(
+                volume=Decimal("1.0"),
+                quote_volume=Decimal("1.0"),
+                price=Decimal("1.0"),
+            ),
+        }
+    )
+    trader = traders.Multi(chandler=chandler, informant=informant)
+    config = traders.MultiConfig(
+        exchange="magicmock",
+        interval=1,
+        start=0,
+        end=3,
+        quote=Decimal("2.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LONG, Advice.LONG],
+        ),
+        stop_loss=stop_loss_,
+        long=True,
+        track_count=2,
+        position_count=2,
+        max_open_positions=max_open_positions,
+        max_heat=max_heat,
+        allowed_age_drift=allowed_age_drift,
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    assert len(summary.positions) == expected_positions
+    assert summary.skipped_entries == 2 - expected_positions
+
+
+async def test_quote_assets_conversion() -> None:
+    chandler = fakes.Chandler(
+        candles={
+            ("magicmock", s, 1): [Candle(time=i, close=Decimal(f"{i + 1}.0")) for i in range(3)]
+            for s in ["eth-btc", "ada-usdt"]
+        },
+    )
+    informant = fakes.Informant(
+        symbols=["btc-usdt"],
+        tickers={
+            "eth-btc": Ticker(
+                volume=Decimal("2.0"),
+                quote_volume=Decimal("2.0"),
+                price=Decimal("1.0"),
+            ),
+            "ada-usdt": Ticker(
+                volume=Decimal("1.0"),
+                quote_volume=Decimal("1.0"),
+                price=Decimal("1.0"),
+            ),
+        },
+    )
+    prices = fakes.Prices(prices={"usdt": [Decimal("0.5")] * 4})  # 1 usdt = 0.5 btc.
+    trader = traders.Multi(chandler=chandler, informant=informant, prices=prices)
+    config = traders.MultiConfig(
+        exchange="magicmock",
+        interval=1,
+        start=0,
+        end=3,
+        quote=Decimal("2.0"),
+        strategy=GenericConstructor.from_type(
+            Fixed,
+            advices=[Advice.LONG, Advice.LIQUIDATE, Advice.NONE],
+        ),
+        long=True,
+        track_count=2,
+        position_count=2,
+        quote_asset="btc",
+        quote_assets=["usdt"],
+    )
+    state = await trader.initialize(config)
+
+    summary = await trader.run(state)
+
+    positions = {p.symbol: p for p in summary.positions}
+    assert positions["eth-btc"].profit == Decimal("1.0")  # In btc.
+    assert positions["ada-usdt"].profit == Decimal("2.0")  # In usdt.
+    assert summary.conversions == [
+        Conversion(
+            time=1,
+            from_asset="btc",
+            from_amount=Decimal("1.0"),
+            to_asset="usdt",
+            to_amount=Decimal("2.0"),
+            fee=Decimal("0.0"),
+        ),
+        Conversion(
+            time=2,
+            from_asset="usdt",
+            from_amount=Decimal("4.0"),
+            to_asset="btc",
+            to_amount=Decimal("2.0"),
+            fee=Decimal("0.0"),
+        ),
+    ]
+    assert summary.profit == Decimal("2.0")  # In btc.
+    assert sorted(state.quotes) == [Decimal("2.0"), Decimal("2.0")]
+
+
 async def test_allowed_age_drift() -> None:
     chandler = fakes.Chandler(
         candles={